@@ -0,0 +1,71 @@
+//! Compares QuadTree queries against scanning a Vec of shapes.
+//!
+//! Run with `cargo bench --bench index`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rust_programming_language::random::Rng;
+use rust_programming_language::shapes::{BoundingBox, Bounded, Circle, QuadTree, Rectangle, Shape};
+
+const SIZES: [usize; 3] = [1_000, 10_000, 100_000];
+const QUERIES: usize = 1_000;
+const WORLD: f64 = 10_000.0;
+
+// a mixture of small circles and rectangles spread over the world
+fn shapes(n: usize, rng: &mut Rng) -> Vec<Box<dyn Shape<f64>>> {
+    (0..n).map(|i| {
+        let (x, y) = (rng.next_f64() * WORLD, rng.next_f64() * WORLD);
+        let shape: Box<dyn Shape<f64>> = if i % 2 == 0 {
+            Box::new(Circle::new((x, y), 5.0 + rng.next_f64() * 20.0))
+        } else {
+            Box::new(Rectangle::new((x, y), (x + 5.0 + rng.next_f64() * 40.0, y + 5.0 + rng.next_f64() * 40.0)))
+        };
+        shape
+    }).collect()
+}
+
+// the mean time of one call of f, over `runs` calls
+fn time<F: FnMut(usize)>(runs: usize, mut f: F) -> Duration {
+    let start = Instant::now();
+    for i in 0..runs {
+        f(i)
+    }
+    start.elapsed() / runs as u32
+}
+
+fn main() {
+    let mut rng = Rng::new(2024);
+    println!("{:>8} {:>14} {:>12} {:>12} {:>9}", "shapes", "query", "linear scan", "quadtree", "speedup");
+    for &n in SIZES.iter() {
+        let shapes = shapes(n, &mut rng);
+        let points: Vec<(f64, f64)> = (0..QUERIES).map(|_| (rng.next_f64() * WORLD, rng.next_f64() * WORLD)).collect();
+        let boxes: Vec<BoundingBox> = points.iter().map(|&(x, y)| BoundingBox::new((x, y), (x + 200.0, y + 200.0))).collect();
+
+        let build = Instant::now();
+        let tree: QuadTree<&dyn Shape<f64>> = shapes.iter().map(|shape| &**shape).collect();
+        println!("{:>8} {:>14} {:>12} {:>12.2?} {:>9}", n, "build", "-", build.elapsed(), "-");
+
+        let report = |query: &str, linear: Duration, indexed: Duration| {
+            println!("{:>8} {:>14} {:>12.2?} {:>12.2?} {:>8.1}x", n, query, linear, indexed,
+                     linear.as_secs_f64() / indexed.as_secs_f64())
+        };
+
+        report("point",
+            time(QUERIES, |i| { black_box(shapes.iter().filter(|s| s.contains(points[i])).count()); }),
+            time(QUERIES, |i| { black_box(tree.query_point(points[i]).len()); }));
+
+        report("box",
+            time(QUERIES, |i| { black_box(shapes.iter().filter(|s| s.bounding_box().intersects(&boxes[i])).count()); }),
+            time(QUERIES, |i| { black_box(tree.query_box(&boxes[i]).len()); }));
+
+        report("10 nearest",
+            time(QUERIES / 10, |i| {
+                let mut distances: Vec<f64> = shapes.iter().map(|s| s.distance_to(points[i])).collect();
+                distances.sort_by(|a, b| a.total_cmp(b));
+                distances.truncate(10);
+                black_box(distances);
+            }),
+            time(QUERIES / 10, |i| { black_box(tree.nearest(points[i], 10).len()); }));
+    }
+}
@@ -0,0 +1,162 @@
+//! Animals that know their name and how to talk.
+//!
+//! A mixed group of animals can be kept either as trait objects, `Vec<Box<dyn Animal>>`, or as
+//! the closed enum [`AnyAnimal`], which behaves the same but needs no boxing. An
+//! [`AnimalRegistry`] creates trait objects from a species name.
+//!
+//! Humans and cats also have needs ([`State`]) that change as they go about their day, and a
+//! [`Zoo`] runs a reproducible simulation of many animals at once.
+
+pub mod registry;
+pub mod state;
+pub mod zoo;
+
+pub use registry::{AnimalRegistry, UnknownSpeciesError};
+pub use state::{Activity, Mood, State, Temperament};
+pub use zoo::{Event, Resident, Zoo};
+
+use crate::random::Rng;
+
+/// Something with a name that can talk.
+///
+/// The trait is object safe: `new` is only available on sized implementors, so `dyn Animal`
+/// can still be used for the other methods.
+pub trait Animal {
+    fn new(name: &'static str) -> Self where Self: Sized; // static method
+    fn name(&self) -> &'static str;
+    /// What the animal says; by default it can't talk.
+    fn speak(&self) -> String {
+        format!("{} cannot talk", self.name())
+    }
+    /// Prints what the animal says.
+    fn talk(&self) {
+        println!("{}", self.speak())
+    }
+    /// Hunger, energy and happiness; animals that don't keep track are always as at the start.
+    fn state(&self) -> State {
+        State::default()
+    }
+    /// Chooses and carries out what to do for one tick of a simulation, or `None` for animals
+    /// that don't take part.
+    fn act(&mut self, _rng: &mut Rng) -> Option<Activity> {
+        None
+    }
+}
+
+/// A person.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Human {
+    name: &'static str,
+    state: State
+}
+
+/// A cat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cat {
+    name: &'static str,
+    state: State
+}
+
+impl Human {
+    pub const TEMPERAMENT: Temperament = Temperament{appetite: 3, sleepiness: 2, playfulness: 2, sociability: 4};
+}
+
+impl Cat {
+    pub const TEMPERAMENT: Temperament = Temperament{appetite: 3, sleepiness: 5, playfulness: 3, sociability: 1};
+}
+
+impl Animal for Human {
+    fn new(name: &'static str) -> Human {
+        Human{name, state: State::default()}
+    }
+    fn name(&self) -> &'static str { self.name }
+    fn speak(&self) -> String {
+        match self.state.mood() {
+            Mood::Happy => format!("{} says what a lovely day", self.name),
+            Mood::Content => format!("{} says hello", self.name),
+            Mood::Hungry => format!("{} asks when lunch is", self.name),
+            Mood::Tired => format!("{} yawns", self.name),
+            Mood::Grumpy => format!("{} grumbles", self.name)
+        }
+    }
+    fn state(&self) -> State { self.state }
+    fn act(&mut self, rng: &mut Rng) -> Option<Activity> {
+        Some(self.state.step(&Human::TEMPERAMENT, rng))
+    }
+}
+
+impl Animal for Cat {
+    fn new(name: &'static str) -> Cat {
+        Cat{name, state: State::default()}
+    }
+    fn name(&self) -> &'static str { self.name }
+    fn speak(&self) -> String {
+        match self.state.mood() {
+            Mood::Happy => format!("{} purrs", self.name),
+            Mood::Content => format!("{} says meow", self.name),
+            Mood::Hungry => format!("{} meows loudly at the food bowl", self.name),
+            Mood::Tired => format!("{} yawns", self.name),
+            Mood::Grumpy => format!("{} hisses", self.name)
+        }
+    }
+    fn state(&self) -> State { self.state }
+    fn act(&mut self, rng: &mut Rng) -> Option<Activity> {
+        Some(self.state.step(&Cat::TEMPERAMENT, rng))
+    }
+}
+
+/// Any of the animals of this module, as an enum rather than a trait object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnyAnimal {
+    Human(Human),
+    Cat(Cat)
+}
+
+impl AnyAnimal {
+    pub fn name(&self) -> &'static str {
+        self.as_animal().name()
+    }
+
+    pub fn speak(&self) -> String {
+        self.as_animal().speak()
+    }
+
+    pub fn talk(&self) {
+        self.as_animal().talk()
+    }
+
+    pub fn state(&self) -> State {
+        self.as_animal().state()
+    }
+
+    pub fn act(&mut self, rng: &mut Rng) -> Option<Activity> {
+        self.as_animal_mut().act(rng)
+    }
+
+    /// The wrapped animal as a trait object.
+    pub fn as_animal(&self) -> &dyn Animal {
+        match self {
+            AnyAnimal::Human(human) => human,
+            AnyAnimal::Cat(cat) => cat
+        }
+    }
+
+    pub fn as_animal_mut(&mut self) -> &mut dyn Animal {
+        match self {
+            AnyAnimal::Human(human) => human,
+            AnyAnimal::Cat(cat) => cat
+        }
+    }
+}
+
+impl From<Human> for AnyAnimal {
+    fn from(human: Human) -> AnyAnimal {
+        AnyAnimal::Human(human)
+    }
+}
+
+impl From<Cat> for AnyAnimal {
+    fn from(cat: Cat) -> AnyAnimal {
+        AnyAnimal::Cat(cat)
+    }
+}
@@ -0,0 +1,102 @@
+//! Creating animals by species name.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+use super::{Animal, Cat, Human};
+
+type Constructor = Box<dyn Fn(&'static str) -> Box<dyn Animal>>;
+
+/// Constructors for animals, registered under a species name.
+///
+/// Any type implementing [`Animal`] can be registered, so new species need no changes here:
+/// after `registry.register::<Cat>("cat")`, `registry.create("cat", "Fluffy")` returns a boxed
+/// `Cat`. Species names are case-insensitive.
+#[derive(Default)]
+pub struct AnimalRegistry {
+    constructors: BTreeMap<String, Constructor>
+}
+
+impl AnimalRegistry {
+    /// A registry with no species.
+    pub fn new() -> AnimalRegistry {
+        AnimalRegistry::default()
+    }
+
+    /// A registry with the species of this module: `human` and `cat`.
+    pub fn with_builtins() -> AnimalRegistry {
+        let mut registry = AnimalRegistry::new();
+        registry.register::<Human>("human");
+        registry.register::<Cat>("cat");
+        registry
+    }
+
+    /// Registers `A::new` under `species`, replacing any earlier constructor of that name.
+    pub fn register<A: Animal + 'static>(&mut self, species: &str) {
+        self.register_with(species, |name| Box::new(A::new(name)))
+    }
+
+    /// Registers an arbitrary constructor under `species`, replacing any earlier one.
+    pub fn register_with<F>(&mut self, species: &str, constructor: F)
+        where F: Fn(&'static str) -> Box<dyn Animal> + 'static {
+        self.constructors.insert(species.to_lowercase(), Box::new(constructor));
+    }
+
+    /// A new animal of the given species called `name`.
+    pub fn create(&self, species: &str, name: &'static str) -> Result<Box<dyn Animal>, UnknownSpeciesError> {
+        match self.constructors.get(&species.to_lowercase()) {
+            Some(constructor) => Ok(constructor(name)),
+            None => Err(UnknownSpeciesError{
+                species: species.to_string(),
+                known: self.species().map(String::from).collect()
+            })
+        }
+    }
+
+    pub fn contains(&self, species: &str) -> bool {
+        self.constructors.contains_key(&species.to_lowercase())
+    }
+
+    /// The registered species names, in alphabetical order.
+    pub fn species(&self) -> impl Iterator<Item = &str> {
+        self.constructors.keys().map(String::as_str)
+    }
+}
+
+impl fmt::Debug for AnimalRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.species()).finish()
+    }
+}
+
+/// A species was asked for that has not been registered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownSpeciesError {
+    species: String,
+    known: Vec<String>
+}
+
+impl UnknownSpeciesError {
+    /// The species that was asked for.
+    pub fn species(&self) -> &str {
+        &self.species
+    }
+
+    /// The species that were registered, in alphabetical order.
+    pub fn known(&self) -> &[String] {
+        &self.known
+    }
+}
+
+impl fmt::Display for UnknownSpeciesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.known.is_empty() {
+            write!(f, "unknown species '{}': no species are registered", self.species)
+        } else {
+            write!(f, "unknown species '{}', expected one of: {}", self.species, self.known.join(", "))
+        }
+    }
+}
+
+impl Error for UnknownSpeciesError {}
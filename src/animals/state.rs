@@ -0,0 +1,153 @@
+//! Needs and moods of simulated animals.
+
+use std::fmt;
+
+use crate::random::Rng;
+
+/// How an animal is doing. Every level runs from 0 to 100.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct State {
+    /// 0 is full, 100 is starving.
+    pub hunger: u8,
+    /// 0 is exhausted, 100 is fully rested.
+    pub energy: u8,
+    /// 0 is miserable, 100 is delighted.
+    pub happiness: u8
+}
+
+/// How an animal feels, as decided by its [`State`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mood {
+    Happy,
+    Content,
+    Hungry,
+    Tired,
+    Grumpy
+}
+
+/// Something an animal does during one tick of a simulation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Activity {
+    Eat,
+    Sleep,
+    Play,
+    Talk,
+    Wander
+}
+
+/// How strongly a species is drawn to each activity; only the ratios matter, and any `u32`
+/// weights are allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Temperament {
+    pub appetite: u32,
+    pub sleepiness: u32,
+    pub playfulness: u32,
+    pub sociability: u32
+}
+
+const MAX: u8 = 100;
+
+impl Default for State {
+    fn default() -> State {
+        State{hunger: 20, energy: 80, happiness: 60}
+    }
+}
+
+impl State {
+    /// The mood, with the most pressing need first: hunger, then tiredness, then unhappiness.
+    pub fn mood(&self) -> Mood {
+        if self.hunger >= 70 { Mood::Hungry }
+        else if self.energy <= 20 { Mood::Tired }
+        else if self.happiness <= 25 { Mood::Grumpy }
+        else if self.happiness >= 75 { Mood::Happy }
+        else { Mood::Content }
+    }
+
+    /// Advances one tick: needs grow, then an activity is chosen at random, weighted by the
+    /// needs and the temperament, and carried out.
+    pub fn step(&mut self, temperament: &Temperament, rng: &mut Rng) -> Activity {
+        self.hunger = raise(self.hunger, rng.range(4..9) as u8);
+        self.energy = lower(self.energy, rng.range(3..7) as u8);
+        self.happiness = lower(self.happiness, rng.range(3..8) as u8);
+
+        // in u64, where even u32::MAX times a squared level of 100, five times over, can't overflow
+        let (hunger, energy, happiness) = (self.hunger as u64, self.energy as u64, self.happiness as u64);
+        let (appetite, sleepiness) = (temperament.appetite as u64, temperament.sleepiness as u64);
+        let (playfulness, sociability) = (temperament.playfulness as u64, temperament.sociability as u64);
+        // needs only become pressing once they are large, hence the squares
+        let weights = [
+            (Activity::Eat, appetite * hunger * hunger / 50),
+            (Activity::Sleep, sleepiness * (100 - energy) * (100 - energy) / 50),
+            (Activity::Play, playfulness * energy / 2),
+            (Activity::Talk, sociability * happiness / 2),
+            (Activity::Wander, 20)
+        ];
+        let total: u64 = weights.iter().map(|&(_, w)| w).sum();
+        let mut pick = rng.below(total);
+        let activity = weights.iter()
+            .find(|&&(_, w)| if pick < w { true } else { pick -= w; false })
+            .map_or(Activity::Wander, |&(activity, _)| activity);
+
+        self.apply(activity);
+        activity
+    }
+
+    /// The effect of doing `activity` on the needs.
+    pub fn apply(&mut self, activity: Activity) {
+        match activity {
+            Activity::Eat => {
+                self.hunger = lower(self.hunger, 40);
+                self.happiness = raise(self.happiness, 5)
+            }
+            Activity::Sleep => self.energy = raise(self.energy, 35),
+            Activity::Play => {
+                self.energy = lower(self.energy, 10);
+                self.hunger = raise(self.hunger, 5);
+                self.happiness = raise(self.happiness, 12)
+            }
+            Activity::Talk => self.happiness = raise(self.happiness, 6),
+            Activity::Wander => self.happiness = raise(self.happiness, 3)
+        }
+    }
+}
+
+fn raise(level: u8, by: u8) -> u8 {
+    level.saturating_add(by).min(MAX)
+}
+
+fn lower(level: u8, by: u8) -> u8 {
+    level.saturating_sub(by)
+}
+
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "hunger {}, energy {}, happiness {}, {}", self.hunger, self.energy, self.happiness, self.mood())
+    }
+}
+
+impl fmt::Display for Mood {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mood = match self {
+            Mood::Happy => "happy",
+            Mood::Content => "content",
+            Mood::Hungry => "hungry",
+            Mood::Tired => "tired",
+            Mood::Grumpy => "grumpy"
+        };
+        write!(f, "{}", mood)
+    }
+}
+
+// past tense, for event logs
+impl fmt::Display for Activity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let activity = match self {
+            Activity::Eat => "ate",
+            Activity::Sleep => "slept",
+            Activity::Play => "played",
+            Activity::Talk => "talked",
+            Activity::Wander => "wandered about"
+        };
+        write!(f, "{}", activity)
+    }
+}
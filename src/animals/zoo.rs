@@ -0,0 +1,97 @@
+//! A zoo of animals living through a simple turn-based simulation.
+
+use std::fmt;
+
+use super::{Activity, Animal, State};
+use crate::random::Rng;
+
+/// Animals advanced together over discrete ticks, each keeping a log of what it did.
+///
+/// All randomness comes from one [`Rng`] seeded at creation, so a zoo with the same seed and the
+/// same residents always plays out the same way.
+pub struct Zoo {
+    rng: Rng,
+    ticks: u32,
+    residents: Vec<Resident>
+}
+
+/// A resident of a [`Zoo`] and everything it has done there.
+pub struct Resident {
+    pub animal: Box<dyn Animal>,
+    pub log: Vec<Event>
+}
+
+/// One entry of a resident's log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    /// The tick it happened on, counting from 1.
+    pub tick: u32,
+    pub activity: Activity,
+    /// What was said, for [`Activity::Talk`].
+    pub speech: Option<String>,
+    /// The animal's state afterwards.
+    pub state: State
+}
+
+impl Zoo {
+    /// An empty zoo whose simulation is driven by `seed`.
+    pub fn new(seed: u64) -> Zoo {
+        Zoo{rng: Rng::new(seed), ticks: 0, residents: Vec::new()}
+    }
+
+    pub fn add(&mut self, animal: Box<dyn Animal>) {
+        self.residents.push(Resident{animal, log: Vec::new()})
+    }
+
+    pub fn residents(&self) -> &[Resident] {
+        &self.residents
+    }
+
+    /// How many ticks have passed.
+    pub fn ticks(&self) -> u32 {
+        self.ticks
+    }
+
+    /// Advances every animal by one tick, in the order they were added. Animals that don't
+    /// take part in simulations (see [`Animal::act`]) are left out of the log.
+    pub fn tick(&mut self) {
+        self.ticks += 1;
+        for resident in self.residents.iter_mut() {
+            if let Some(activity) = resident.animal.act(&mut self.rng) {
+                let speech = if activity == Activity::Talk { Some(resident.animal.speak()) } else { None };
+                resident.log.push(Event{tick: self.ticks, activity, speech, state: resident.animal.state()})
+            }
+        }
+    }
+
+    /// Advances the zoo by `ticks` ticks.
+    pub fn run(&mut self, ticks: u32) {
+        for _ in 0..ticks {
+            self.tick()
+        }
+    }
+}
+
+// the log of every resident in turn
+impl fmt::Display for Zoo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, resident) in self.residents.iter().enumerate() {
+            if i > 0 { writeln!(f)? }
+            writeln!(f, "{} ({}):", resident.animal.name(), resident.animal.state())?;
+            if resident.log.is_empty() { writeln!(f, "  nothing happened")? }
+            for event in &resident.log {
+                writeln!(f, "  {}", event)?
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.speech {
+            Some(speech) => write!(f, "tick {:>3}: \"{}\" ({})", self.tick, speech, self.state),
+            None => write!(f, "tick {:>3}: {} ({})", self.tick, self.activity, self.state)
+        }
+    }
+}
@@ -0,0 +1,318 @@
+//! Colours as named values, RGB triples or CMYK quadruples.
+//!
+//! Every colour converts to RGB, CMYK, [`Hsl`], [`Hsv`] and hex strings, and back. Colours are
+//! equal when they look the same, that is when they have the same RGB value, whichever way
+//! they were written: `Color::RGB(0, 0, 0)` equals every CMYK colour with full black.
+//!
+//! Colours parse from CSS syntax with [`str::parse`]: names such as `rebeccapurple`, hex
+//! colours, and the functions `rgb()`, `rgba()`, `hsl()`, `hsla()` and `cmyk()`.
+//!
+//! For comparing colours by eye, [`Lab`] measures how different they look, and a [`Palette`]
+//! finds the nearest of a set of colours or picks a few to stand in for many.
+
+use std::error::Error;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+mod css;
+pub mod lab;
+pub mod palette;
+
+pub use lab::Lab;
+pub use palette::Palette;
+
+/// A colour.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy)]
+pub enum Color {
+    Red,
+    Blue,
+    Green,
+    RGB(u8, u8, u8), // tuple-style
+    CMYK{cyan: u8, magenta: u8, yellow: u8, black: u8} // struct-style
+}
+
+impl Color {
+    /// The colour as red, green and blue components.
+    ///
+    /// The named colours are the pure primaries, and CMYK is converted naively, without a
+    /// colour profile.
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        match *self {
+            Color::Red => (255, 0, 0),
+            Color::Green => (0, 255, 0),
+            Color::Blue => (0, 0, 255),
+            Color::RGB(r, g, b) => (r, g, b),
+            Color::CMYK{cyan, magenta, yellow, black} => {
+                let channel = |c: u8| div_round((255 - c as u32) * (255 - black as u32), 255) as u8;
+                (channel(cyan), channel(magenta), channel(yellow))
+            }
+        }
+    }
+
+    /// The colour as cyan, magenta, yellow and black components, using as much black as
+    /// possible. Converting back with [`to_rgb`](Color::to_rgb) gives the same RGB value.
+    pub fn to_cmyk(&self) -> (u8, u8, u8, u8) {
+        if let Color::CMYK{cyan, magenta, yellow, black} = *self {
+            return (cyan, magenta, yellow, black)
+        }
+        let (r, g, b) = self.to_rgb();
+        let max = r.max(g).max(b) as u32;
+        if max == 0 { return (0, 0, 0, 255) }
+        let ink = |c: u8| div_round((max - c as u32) * 255, max) as u8;
+        (ink(r), ink(g), ink(b), 255 - max as u8)
+    }
+
+    pub fn to_hsl(&self) -> Hsl {
+        Hsl::from(*self)
+    }
+
+    pub fn to_hsv(&self) -> Hsv {
+        Hsv::from(*self)
+    }
+
+    /// The colour as a lowercase hex string, `#rrggbb`.
+    pub fn to_hex(&self) -> String {
+        let (r, g, b) = self.to_rgb();
+        format!("#{:02x}{:02x}{:02x}", r, g, b)
+    }
+
+    /// Parses a hex colour, `#rrggbb` or the shorthand `#rgb` for `#rrggbb`, in either case.
+    /// The `#` may be left out. An alpha channel, `#rrggbbaa` or `#rgba`, is accepted but
+    /// ignored; [`from_css`](Color::from_css) keeps it.
+    pub fn from_hex(input: &str) -> Result<Color, ParseColorError> {
+        let start = if input.starts_with('#') { 1 } else { 0 };
+        parse_hex(&input[start..], start).map(|(color, _)| color)
+    }
+}
+
+// colours are equal when they look the same
+impl PartialEq for Color {
+    fn eq(&self, other: &Color) -> bool {
+        self.to_rgb() == other.to_rgb()
+    }
+}
+
+impl Eq for Color {}
+
+impl Hash for Color {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_rgb().hash(state)
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((r, g, b): (u8, u8, u8)) -> Color {
+        Color::RGB(r, g, b)
+    }
+}
+
+impl From<Color> for (u8, u8, u8) {
+    fn from(c: Color) -> (u8, u8, u8) {
+        c.to_rgb()
+    }
+}
+
+/// A colour as hue, saturation and lightness: the hue in degrees from 0 up to 360, starting
+/// at red, and the others from 0 to 1. Lightness 0 is black and 1 is white, whatever the hue.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsl {
+    pub hue: f64,
+    pub saturation: f64,
+    pub lightness: f64
+}
+
+/// A colour as hue, saturation and value: the hue in degrees from 0 up to 360, starting at
+/// red, and the others from 0 to 1. Value 0 is black, and value 1 with saturation 0 is white.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsv {
+    pub hue: f64,
+    pub saturation: f64,
+    pub value: f64
+}
+
+impl Hsl {
+    /// The colour with the given components, the hue taken round the circle and the others
+    /// clamped to 0 to 1.
+    pub fn new(hue: f64, saturation: f64, lightness: f64) -> Hsl {
+        Hsl{hue: hue.rem_euclid(360.0), saturation: saturation.clamp(0.0, 1.0), lightness: lightness.clamp(0.0, 1.0)}
+    }
+}
+
+impl Hsv {
+    /// The colour with the given components, the hue taken round the circle and the others
+    /// clamped to 0 to 1.
+    pub fn new(hue: f64, saturation: f64, value: f64) -> Hsv {
+        Hsv{hue: hue.rem_euclid(360.0), saturation: saturation.clamp(0.0, 1.0), value: value.clamp(0.0, 1.0)}
+    }
+}
+
+// the hue, the largest channel and the spread of channels of an RGB colour, all from 0 to 1
+// but the hue; a grey has hue 0
+fn hue_max_spread(c: Color) -> (f64, f64, f64) {
+    let (r, g, b) = c.to_rgb();
+    let (r, g, b) = (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0);
+    let max = r.max(g).max(b);
+    let spread = max - r.min(g).min(b);
+    let hue = if spread == 0.0 { 0.0 }
+        else if max == r { 60.0 * ((g - b) / spread).rem_euclid(6.0) }
+        else if max == g { 60.0 * ((b - r) / spread + 2.0) }
+        else { 60.0 * ((r - g) / spread + 4.0) };
+    (hue, max, spread)
+}
+
+// the nearest RGB colour to channels from 0 to 1
+fn from_unit(r: f64, g: f64, b: f64) -> Color {
+    let channel = |x: f64| (x.clamp(0.0, 1.0) * 255.0).round() as u8;
+    Color::RGB(channel(r), channel(g), channel(b))
+}
+
+impl From<Color> for Hsl {
+    fn from(c: Color) -> Hsl {
+        let (hue, max, spread) = hue_max_spread(c);
+        let lightness = max - spread / 2.0;
+        let saturation = if spread == 0.0 { 0.0 } else { spread / (1.0 - (2.0 * lightness - 1.0).abs()) };
+        Hsl{hue, saturation, lightness}
+    }
+}
+
+impl From<Color> for Hsv {
+    fn from(c: Color) -> Hsv {
+        let (hue, max, spread) = hue_max_spread(c);
+        let saturation = if max == 0.0 { 0.0 } else { spread / max };
+        Hsv{hue, saturation, value: max}
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(Hsl{hue, saturation, lightness}: Hsl) -> Color {
+        let a = saturation * lightness.min(1.0 - lightness);
+        let f = |n: f64| {
+            let k = (n + hue / 30.0).rem_euclid(12.0);
+            lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+        from_unit(f(0.0), f(8.0), f(4.0))
+    }
+}
+
+impl From<Hsv> for Color {
+    fn from(Hsv{hue, saturation, value}: Hsv) -> Color {
+        let f = |n: f64| {
+            let k = (n + hue / 60.0).rem_euclid(6.0);
+            value - value * saturation * k.min(4.0 - k).clamp(0.0, 1.0)
+        };
+        from_unit(f(5.0), f(3.0), f(1.0))
+    }
+}
+
+// between the two cylinders directly, without rounding to RGB on the way
+impl From<Hsl> for Hsv {
+    fn from(Hsl{hue, saturation, lightness}: Hsl) -> Hsv {
+        let value = lightness + saturation * lightness.min(1.0 - lightness);
+        let saturation = if value == 0.0 { 0.0 } else { 2.0 * (1.0 - lightness / value) };
+        Hsv{hue, saturation, value}
+    }
+}
+
+impl From<Hsv> for Hsl {
+    fn from(Hsv{hue, saturation, value}: Hsv) -> Hsl {
+        let lightness = value * (1.0 - saturation / 2.0);
+        let saturation = if lightness == 0.0 || lightness == 1.0 { 0.0 }
+            else { (value - lightness) / lightness.min(1.0 - lightness) };
+        Hsl{hue, saturation, lightness}
+    }
+}
+
+// prints as CSS: hsl(30, 100%, 50%), honouring a precision such as {:.1} on every component
+impl fmt::Display for Hsl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let p = f.precision().unwrap_or(0);
+        write!(f, "hsl({:.*}, {:.*}%, {:.*}%)", p, self.hue, p, self.saturation * 100.0, p, self.lightness * 100.0)
+    }
+}
+
+impl fmt::Display for Hsv {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let p = f.precision().unwrap_or(0);
+        write!(f, "hsv({:.*}, {:.*}%, {:.*}%)", p, self.hue, p, self.saturation * 100.0, p, self.value * 100.0)
+    }
+}
+
+/// Error returned when a string is not a valid colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseColorError {
+    kind: ParseColorErrorKind,
+    offset: usize
+}
+
+/// What went wrong while parsing a colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseColorErrorKind {
+    /// Something else was found where the given thing was expected, e.g. `"a percentage"`.
+    Expected(&'static str),
+    /// A word that is not one of the CSS named colours.
+    UnknownName,
+    /// A function other than `rgb()`, `rgba()`, `hsl()`, `hsla()` or `cmyk()`.
+    UnknownFunction,
+    /// A character that is not a hex digit.
+    InvalidDigit,
+    /// A hex colour with a number of digits other than 3, 4, 6 or 8.
+    WrongLength,
+    /// Something followed the end of the colour.
+    TrailingInput
+}
+
+impl ParseColorError {
+    fn new(kind: ParseColorErrorKind, offset: usize) -> ParseColorError {
+        ParseColorError{kind, offset}
+    }
+
+    pub fn kind(&self) -> ParseColorErrorKind {
+        self.kind
+    }
+
+    /// Byte offset into the input at which parsing failed.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseColorErrorKind::Expected(what) => write!(f, "expected {}", what)?,
+            ParseColorErrorKind::UnknownName => write!(f, "expected a CSS colour name")?,
+            ParseColorErrorKind::UnknownFunction => write!(f, "expected rgb(), rgba(), hsl(), hsla() or cmyk()")?,
+            ParseColorErrorKind::InvalidDigit => write!(f, "expected a hex digit")?,
+            ParseColorErrorKind::WrongLength => write!(f, "expected 3, 4, 6 or 8 hex digits")?,
+            ParseColorErrorKind::TrailingInput => write!(f, "expected the end of the colour")?
+        }
+        write!(f, " at byte {}", self.offset)
+    }
+}
+
+impl Error for ParseColorError {}
+
+// the colour and opacity given by hex digits, which start at byte offset in the input
+fn parse_hex(digits: &str, offset: usize) -> Result<(Color, f64), ParseColorError> {
+    if let Some(at) = digits.find(|c: char| !c.is_ascii_hexdigit()) {
+        return Err(ParseColorError::new(ParseColorErrorKind::InvalidDigit, offset + at))
+    }
+    // each channel is one digit repeated, or two
+    let width = match digits.len() {
+        3 | 4 => 1,
+        6 | 8 => 2,
+        _ => return Err(ParseColorError::new(ParseColorErrorKind::WrongLength, offset))
+    };
+    let channel = |i: usize| {
+        let value = u8::from_str_radix(&digits[i * width..(i + 1) * width], 16).unwrap();
+        if width == 1 { value * 17 } else { value }
+    };
+    let alpha = if digits.len() == 3 * width { 1.0 } else { channel(3) as f64 / 255.0 };
+    Ok((Color::RGB(channel(0), channel(1), channel(2)), alpha))
+}
+
+// n / d, rounded to nearest
+fn div_round(n: u32, d: u32) -> u32 {
+    (n + d / 2) / d
+}
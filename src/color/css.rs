@@ -0,0 +1,251 @@
+//! Parsing colours from CSS syntax.
+//!
+//! Accepts the 148 CSS named colours, hex colours with or without alpha, and the functions
+//! `rgb()`, `rgba()`, `hsl()`, `hsla()` and `cmyk()` (also spelt `device-cmyk()`), each either
+//! with commas, as in `rgb(255, 136, 0)`, or with spaces and an optional `/` before the alpha,
+//! as in `rgb(255 136 0 / 50%)`. Names, functions and units are case-insensitive, and values
+//! out of range are clamped, as browsers do.
+
+use std::str::FromStr;
+
+use super::{from_unit, parse_hex, Color, Hsl, ParseColorError, ParseColorErrorKind};
+
+// the CSS named colours, in alphabetical order for binary search
+const NAMED: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff), ("antiquewhite", 0xfaebd7), ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4), ("azure", 0xf0ffff), ("beige", 0xf5f5dc), ("bisque", 0xffe4c4),
+    ("black", 0x000000), ("blanchedalmond", 0xffebcd), ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2), ("brown", 0xa52a2a), ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0), ("chartreuse", 0x7fff00), ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50), ("cornflowerblue", 0x6495ed), ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c), ("cyan", 0x00ffff), ("darkblue", 0x00008b), ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b), ("darkgray", 0xa9a9a9), ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9), ("darkkhaki", 0xbdb76b), ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f), ("darkorange", 0xff8c00), ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000), ("darksalmon", 0xe9967a), ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b), ("darkslategray", 0x2f4f4f), ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1), ("darkviolet", 0x9400d3), ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff), ("dimgray", 0x696969), ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff), ("firebrick", 0xb22222), ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22), ("fuchsia", 0xff00ff), ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff), ("gold", 0xffd700), ("goldenrod", 0xdaa520), ("gray", 0x808080),
+    ("green", 0x008000), ("greenyellow", 0xadff2f), ("grey", 0x808080), ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4), ("indianred", 0xcd5c5c), ("indigo", 0x4b0082), ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c), ("lavender", 0xe6e6fa), ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00), ("lemonchiffon", 0xfffacd), ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080), ("lightcyan", 0xe0ffff), ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3), ("lightgreen", 0x90ee90), ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1), ("lightsalmon", 0xffa07a), ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa), ("lightslategray", 0x778899), ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de), ("lightyellow", 0xffffe0), ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32), ("linen", 0xfaf0e6), ("magenta", 0xff00ff), ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa), ("mediumblue", 0x0000cd), ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db), ("mediumseagreen", 0x3cb371), ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a), ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585), ("midnightblue", 0x191970), ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1), ("moccasin", 0xffe4b5), ("navajowhite", 0xffdead),
+    ("navy", 0x000080), ("oldlace", 0xfdf5e6), ("olive", 0x808000), ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500), ("orangered", 0xff4500), ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa), ("palegreen", 0x98fb98), ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093), ("papayawhip", 0xffefd5), ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f), ("pink", 0xffc0cb), ("plum", 0xdda0dd), ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080), ("rebeccapurple", 0x663399), ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f), ("royalblue", 0x4169e1), ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072), ("sandybrown", 0xf4a460), ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee), ("sienna", 0xa0522d), ("silver", 0xc0c0c0), ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd), ("slategray", 0x708090), ("slategrey", 0x708090),
+    ("snow", 0xfffafa), ("springgreen", 0x00ff7f), ("steelblue", 0x4682b4), ("tan", 0xd2b48c),
+    ("teal", 0x008080), ("thistle", 0xd8bfd8), ("tomato", 0xff6347), ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee), ("wheat", 0xf5deb3), ("white", 0xffffff), ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00), ("yellowgreen", 0x9acd32)
+];
+
+impl Color {
+    /// The names of the CSS named colours, in alphabetical order.
+    pub fn css_names() -> impl Iterator<Item = &'static str> {
+        NAMED.iter().map(|&(name, _)| name)
+    }
+
+    /// The CSS named colour, in any case. Note that CSS `green` is `#008000`, darker than
+    /// [`Color::Green`], which is CSS `lime`.
+    pub fn from_name(name: &str) -> Option<Color> {
+        let name = name.to_ascii_lowercase();
+        let i = NAMED.binary_search_by(|&(n, _)| n.cmp(name.as_str())).ok()?;
+        let rgb = NAMED[i].1;
+        Some(Color::RGB((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8))
+    }
+
+    /// Parses a CSS colour along with its opacity, from 0 for transparent to 1 for opaque,
+    /// which [`str::parse`] leaves out.
+    pub fn from_css(input: &str) -> Result<(Color, f64), ParseColorError> {
+        let mut parser = Parser{input, pos: 0};
+        parser.skip_whitespace();
+        let color = parser.color()?;
+        parser.skip_whitespace();
+        if parser.pos < input.len() { return parser.error(ParseColorErrorKind::TrailingInput) }
+        Ok(color)
+    }
+}
+
+// parses CSS colours, dropping the opacity, which Color has no room for
+impl FromStr for Color {
+    type Err = ParseColorError;
+    fn from_str(input: &str) -> Result<Color, ParseColorError> {
+        Color::from_css(input).map(|(color, _)| color)
+    }
+}
+
+// what a function argument holds, deciding which units it takes and how they scale
+#[derive(Clone, Copy)]
+enum Slot {
+    Channel,
+    Hue,
+    Percentage,
+    Fraction
+}
+
+impl Slot {
+    fn expected(self) -> &'static str {
+        match self {
+            Slot::Channel => "a number from 0 to 255 or a percentage",
+            Slot::Hue => "a hue in degrees, or an angle such as 0.5turn",
+            Slot::Percentage => "a percentage",
+            Slot::Fraction => "a number from 0 to 1 or a percentage"
+        }
+    }
+
+    // hues in degrees, anything else clamped to 0 to 1; None if the unit doesn't belong here
+    fn scale(self, number: f64, unit: &str) -> Option<f64> {
+        let fraction = match (self, unit.to_ascii_lowercase().as_str()) {
+            (Slot::Hue, "") | (Slot::Hue, "deg") => return Some(number),
+            (Slot::Hue, "rad") => return Some(number.to_degrees()),
+            (Slot::Hue, "grad") => return Some(number * 0.9),
+            (Slot::Hue, "turn") => return Some(number * 360.0),
+            (Slot::Channel, "") => number / 255.0,
+            (Slot::Fraction, "") => number,
+            // bare numbers count as percentages for saturation and lightness, as in CSS 4
+            (Slot::Channel, "%") | (Slot::Percentage, "%") | (Slot::Percentage, "") | (Slot::Fraction, "%") => number / 100.0,
+            _ => return None
+        };
+        Some(fraction.clamp(0.0, 1.0))
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn error<T>(&self, kind: ParseColorErrorKind) -> Result<T, ParseColorError> {
+        Err(ParseColorError::new(kind, self.pos))
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len()
+    }
+
+    // takes c after any whitespace, if it's there
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        let found = self.rest().starts_with(c);
+        if found { self.pos += c.len_utf8() }
+        found
+    }
+
+    fn expect(&mut self, c: char, what: &'static str) -> Result<(), ParseColorError> {
+        if self.eat(c) { Ok(()) } else { self.error(ParseColorErrorKind::Expected(what)) }
+    }
+
+    // takes the longest prefix of the rest whose characters pass the test
+    fn take_while(&mut self, test: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c: char| !test(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    fn color(&mut self) -> Result<(Color, f64), ParseColorError> {
+        if self.eat('#') {
+            let start = self.pos;
+            let digits = self.take_while(|c| c.is_ascii_alphanumeric());
+            return parse_hex(digits, start)
+        }
+        let start = self.pos;
+        let word = self.take_while(|c| c.is_ascii_alphanumeric() || c == '-');
+        if word.is_empty() {
+            return self.error(ParseColorErrorKind::Expected("a colour name, '#' or a function such as rgb()"))
+        }
+        if !self.rest().starts_with('(') {
+            return Color::from_name(word).map(|color| (color, 1.0))
+                .ok_or_else(|| ParseColorError::new(ParseColorErrorKind::UnknownName, start))
+        }
+        self.pos += 1;
+        let byte = |x: f64| (x * 255.0).round() as u8;
+        match word.to_ascii_lowercase().as_str() {
+            "rgb" | "rgba" => {
+                let ([r, g, b], alpha) = self.arguments([Slot::Channel; 3])?;
+                Ok((from_unit(r, g, b), alpha))
+            }
+            "hsl" | "hsla" => {
+                let ([h, s, l], alpha) = self.arguments([Slot::Hue, Slot::Percentage, Slot::Percentage])?;
+                Ok((Hsl::new(h, s, l).into(), alpha))
+            }
+            "cmyk" | "device-cmyk" => {
+                let ([c, m, y, k], alpha) = self.arguments([Slot::Fraction; 4])?;
+                Ok((Color::CMYK{cyan: byte(c), magenta: byte(m), yellow: byte(y), black: byte(k)}, alpha))
+            }
+            _ => Err(ParseColorError::new(ParseColorErrorKind::UnknownFunction, start))
+        }
+    }
+
+    // the arguments of a function up to the closing ')', and the alpha, 1 if not given; either
+    // all separated by commas, or by spaces with '/' before the alpha
+    fn arguments<const N: usize>(&mut self, slots: [Slot; N]) -> Result<([f64; N], f64), ParseColorError> {
+        let mut values = [0.0; N];
+        let mut commas = false;
+        for (i, &slot) in slots.iter().enumerate() {
+            if i == 1 { commas = self.eat(',') } else if i > 1 && commas { self.expect(',', "','")? }
+            values[i] = self.argument(slot)?;
+        }
+        let alpha = if self.eat(if commas { ',' } else { '/' }) { self.argument(Slot::Fraction)? } else { 1.0 };
+        self.expect(')', if commas { "',' or ')'" } else { "'/' or ')'" })?;
+        Ok((values, alpha))
+    }
+
+    fn argument(&mut self, slot: Slot) -> Result<f64, ParseColorError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let number = self.number().ok_or_else(|| ParseColorError::new(ParseColorErrorKind::Expected(slot.expected()), start))?;
+        let unit = if self.rest().starts_with('%') { self.pos += 1; "%" } else { self.take_while(|c| c.is_ascii_alphabetic()) };
+        slot.scale(number, unit).ok_or_else(|| ParseColorError::new(ParseColorErrorKind::Expected(slot.expected()), start))
+    }
+
+    // a CSS number: an optional sign, digits with an optional fraction, and an optional exponent
+    fn number(&mut self) -> Option<f64> {
+        let digits = |s: &str| s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let rest = self.rest();
+        let mut len = if rest.starts_with(['+', '-']) { 1 } else { 0 };
+        let whole = digits(&rest[len..]);
+        len += whole;
+        let mut fraction = 0;
+        if rest[len..].starts_with('.') {
+            fraction = digits(&rest[len + 1..]);
+            if fraction > 0 { len += 1 + fraction }
+        }
+        if whole == 0 && fraction == 0 { return None }
+        // an exponent only counts if digits follow it, so that "1e" reads as 1 with unit e
+        if rest[len..].starts_with(['e', 'E']) {
+            let sign = if rest[len + 1..].starts_with(['+', '-']) { 1 } else { 0 };
+            let exponent = digits(&rest[len + 1 + sign..]);
+            if exponent > 0 { len += 1 + sign + exponent }
+        }
+        self.pos += len;
+        rest[..len].parse().ok()
+    }
+}
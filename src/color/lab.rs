@@ -0,0 +1,147 @@
+//! CIE L*a*b*, in which distances approximate how different colours look.
+
+use std::fmt;
+
+use super::{from_unit, Color};
+use crate::matrix::Mat3;
+use crate::vector::Vec3;
+
+/// A colour in CIE L*a*b* under the D65 white point: lightness `l` from 0 for black to 100
+/// for white, `a` from green (negative) to red, and `b` from blue (negative) to yellow. The
+/// colours of RGB have `a` and `b` within about ±130.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64
+}
+
+impl Lab {
+    pub fn new(l: f64, a: f64, b: f64) -> Lab {
+        Lab{l, a, b}
+    }
+
+    /// The CIE 1976 colour difference: straight-line distance in L*a*b*. A difference of about
+    /// 2.3 is just noticeable, but it overstates differences between saturated colours.
+    pub fn delta_e76(&self, other: &Lab) -> f64 {
+        Vec3::from(*self).distance(Vec3::from(*other))
+    }
+
+    /// The CIEDE2000 colour difference, which corrects [`delta_e76`](Lab::delta_e76) for how
+    /// the eye weighs lightness, chroma and hue. A difference of 1 is about the smallest
+    /// anyone notices.
+    pub fn delta_e2000(&self, other: &Lab) -> f64 {
+        // following Sharma, Wu and Dalal (2005), with angles in degrees
+        let (l1, l2) = (self.l, other.l);
+        let c_mean = (self.a.hypot(self.b) + other.a.hypot(other.b)) / 2.0;
+        let g = (1.0 - (c_mean.powi(7) / (c_mean.powi(7) + 25f64.powi(7))).sqrt()) / 2.0;
+        // a is stretched so that neutral colours have chroma closer to 0
+        let (a1, a2) = ((1.0 + g) * self.a, (1.0 + g) * other.a);
+        let (c1, c2) = (a1.hypot(self.b), a2.hypot(other.b));
+        let hue = |a: f64, b: f64| if a == 0.0 && b == 0.0 { 0.0 } else { b.atan2(a).to_degrees().rem_euclid(360.0) };
+        let (h1, h2) = (hue(a1, self.b), hue(a2, other.b));
+
+        let delta_l = l2 - l1;
+        let delta_c = c2 - c1;
+        let delta_h = match h2 - h1 {
+            _ if c1 * c2 == 0.0 => 0.0,
+            d if d > 180.0 => d - 360.0,
+            d if d < -180.0 => d + 360.0,
+            d => d
+        };
+        let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h / 2.0).to_radians().sin();
+
+        let l_mean = (l1 + l2) / 2.0;
+        let c_mean = (c1 + c2) / 2.0;
+        let h_mean = if c1 * c2 == 0.0 { h1 + h2 }
+            else if (h1 - h2).abs() <= 180.0 { (h1 + h2) / 2.0 }
+            else if h1 + h2 < 360.0 { (h1 + h2 + 360.0) / 2.0 }
+            else { (h1 + h2 - 360.0) / 2.0 };
+        let cos = |degrees: f64| degrees.to_radians().cos();
+        let t = 1.0 - 0.17 * cos(h_mean - 30.0) + 0.24 * cos(2.0 * h_mean) + 0.32 * cos(3.0 * h_mean + 6.0)
+            - 0.20 * cos(4.0 * h_mean - 63.0);
+        let rotation = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+        let r_c = 2.0 * (c_mean.powi(7) / (c_mean.powi(7) + 25f64.powi(7))).sqrt();
+        let s_l = 1.0 + 0.015 * (l_mean - 50.0).powi(2) / (20.0 + (l_mean - 50.0).powi(2)).sqrt();
+        let s_c = 1.0 + 0.045 * c_mean;
+        let s_h = 1.0 + 0.015 * c_mean * t;
+        let r_t = -(2.0 * rotation).to_radians().sin() * r_c;
+
+        let (l, c, h) = (delta_l / s_l, delta_c / s_c, delta_h / s_h);
+        (l * l + c * c + h * h + r_t * c * h).sqrt()
+    }
+}
+
+impl Color {
+    pub fn to_lab(&self) -> Lab {
+        Lab::from(*self)
+    }
+
+    /// How different the colours look, as the CIEDE2000 difference between them.
+    pub fn delta_e(&self, other: &Color) -> f64 {
+        self.to_lab().delta_e2000(&other.to_lab())
+    }
+}
+
+// linear sRGB to CIE XYZ, for the D65 white point
+fn rgb_to_xyz() -> Mat3 {
+    Mat3::new([
+        [0.4124564, 0.3575761, 0.1804375],
+        [0.2126729, 0.7151522, 0.0721750],
+        [0.0193339, 0.1191920, 0.9503041]
+    ])
+}
+
+// the threshold below which L*a*b* is linear rather than a cube root
+const EPSILON: f64 = 6.0 / 29.0;
+
+// through linear sRGB and XYZ, relative to the XYZ of white so that greys have a = b = 0
+impl From<Color> for Lab {
+    fn from(c: Color) -> Lab {
+        let linear = |c: u8| {
+            let c = c as f64 / 255.0;
+            if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+        };
+        let (r, g, b) = c.to_rgb();
+        let m = rgb_to_xyz();
+        let xyz = m * Vec3::new(linear(r), linear(g), linear(b));
+        let white = m * Vec3::new(1.0, 1.0, 1.0);
+        let f = |t: f64| if t > EPSILON.powi(3) { t.cbrt() } else { t / (3.0 * EPSILON * EPSILON) + 4.0 / 29.0 };
+        let (fx, fy, fz) = (f(xyz.x / white.x), f(xyz.y / white.y), f(xyz.z / white.z));
+        Lab{l: 116.0 * fy - 16.0, a: 500.0 * (fx - fy), b: 200.0 * (fy - fz)}
+    }
+}
+
+// the nearest RGB colour, clamping colours that RGB can't show
+impl From<Lab> for Color {
+    fn from(Lab{l, a, b}: Lab) -> Color {
+        let f = |t: f64| if t > EPSILON { t * t * t } else { 3.0 * EPSILON * EPSILON * (t - 4.0 / 29.0) };
+        let fy = (l + 16.0) / 116.0;
+        let m = rgb_to_xyz();
+        let white = m * Vec3::new(1.0, 1.0, 1.0);
+        let xyz = Vec3::new(f(fy + a / 500.0) * white.x, f(fy) * white.y, f(fy - b / 200.0) * white.z);
+        let rgb = m.inverse().unwrap() * xyz;
+        let gamma = |c: f64| if c <= 0.0031308 { 12.92 * c } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 };
+        from_unit(gamma(rgb.x), gamma(rgb.y), gamma(rgb.z))
+    }
+}
+
+impl From<Lab> for Vec3 {
+    fn from(Lab{l, a, b}: Lab) -> Vec3 {
+        Vec3::new(l, a, b)
+    }
+}
+
+impl From<Vec3> for Lab {
+    fn from(v: Vec3) -> Lab {
+        Lab{l: v.x, a: v.y, b: v.z}
+    }
+}
+
+// prints as CSS: lab(53.24 80.09 67.20), honouring a precision such as {:.1}
+impl fmt::Display for Lab {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let p = f.precision().unwrap_or(2);
+        write!(f, "lab({:.*} {:.*} {:.*})", p, self.l, p, self.a, p, self.b)
+    }
+}
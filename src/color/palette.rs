@@ -0,0 +1,176 @@
+//! Palettes: finding the nearest of a fixed set of colours, and choosing a few colours to stand
+//! in for many.
+//!
+//! Everything is measured in [`Lab`], so that the colours chosen and matched are the ones that
+//! look alike rather than the ones with similar RGB values.
+
+use std::collections::HashMap;
+use std::iter::FromIterator;
+
+use super::{Color, Lab};
+use crate::vector::Vec3;
+
+/// A list of colours to match other colours against.
+#[derive(Debug, Clone)]
+pub struct Palette {
+    colors: Vec<Color>,
+    labs: Vec<Lab>
+}
+
+impl Palette {
+    pub fn new(colors: Vec<Color>) -> Palette {
+        let labs = colors.iter().map(Color::to_lab).collect();
+        Palette{colors, labs}
+    }
+
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// The index of the palette colour that looks most like `color`, by
+    /// [`delta_e2000`](Lab::delta_e2000), or `None` for an empty palette. Ties go to the
+    /// earlier colour.
+    pub fn nearest_index(&self, color: Color) -> Option<usize> {
+        let lab = color.to_lab();
+        self.labs.iter().map(|p| lab.delta_e2000(p)).enumerate()
+            .fold(None, |best: Option<(usize, f64)>, (i, d)| match best {
+                Some((_, nearest)) if nearest <= d => best,
+                _ => Some((i, d))
+            })
+            .map(|(i, _)| i)
+    }
+
+    /// The palette colour that looks most like `color`, or `None` for an empty palette.
+    pub fn nearest(&self, color: Color) -> Option<Color> {
+        self.nearest_index(color).map(|i| self.colors[i])
+    }
+
+    /// Every pixel replaced by its nearest palette colour, or left alone if the palette is
+    /// empty. Each distinct colour is looked up only once.
+    pub fn remap(&self, pixels: &[Color]) -> Vec<Color> {
+        let mut cache = HashMap::new();
+        pixels.iter().map(|&pixel| *cache.entry(pixel).or_insert_with(|| self.nearest(pixel).unwrap_or(pixel))).collect()
+    }
+
+    /// At most `n` colours standing in for `pixels`, by median cut: starting from a box around
+    /// every colour, repeatedly split the box that is widest along any axis of L*a*b* at the
+    /// median along that axis, then take the mean colour of each box. Fewer than `n` colours
+    /// come back only if there are fewer distinct pixels.
+    pub fn median_cut(pixels: &[Color], n: usize) -> Palette {
+        Palette::from_labs(median_cut(&histogram(pixels), n))
+    }
+
+    /// At most `n` colours standing in for `pixels`, by k-means clustering: starting from
+    /// [`median_cut`](Palette::median_cut), repeatedly move each colour to the mean of the
+    /// pixels nearest to it, for up to `iterations` rounds or until nothing moves. This
+    /// usually matches the pixels more closely than median cut alone, at the cost of time.
+    pub fn k_means(pixels: &[Color], n: usize, iterations: usize) -> Palette {
+        let histogram = histogram(pixels);
+        let mut centres = median_cut(&histogram, n);
+        if centres.is_empty() { return Palette::new(Vec::new()) }
+        let mut assignment = vec![usize::MAX; histogram.len()];
+        for _ in 0..iterations {
+            let mut changed = false;
+            for (&(lab, _), assigned) in histogram.iter().zip(assignment.iter_mut()) {
+                let nearest = nearest_centre(&centres, lab);
+                changed |= *assigned != nearest;
+                *assigned = nearest
+            }
+            if !changed { break }
+            // a centre that no pixel is nearest to stays where it is
+            let mut sums = vec![(Vec3::zero(), 0); centres.len()];
+            for (&(lab, count), &i) in histogram.iter().zip(assignment.iter()) {
+                sums[i].0 += lab * count as f64;
+                sums[i].1 += count
+            }
+            for (centre, (sum, count)) in centres.iter_mut().zip(sums) {
+                if count > 0 { *centre = sum / count as f64 }
+            }
+        }
+        Palette::from_labs(centres)
+    }
+
+    fn from_labs(labs: Vec<Vec3>) -> Palette {
+        Palette::new(labs.into_iter().map(|lab| Color::from(Lab::from(lab))).collect())
+    }
+}
+
+impl FromIterator<Color> for Palette {
+    fn from_iter<I: IntoIterator<Item = Color>>(iter: I) -> Palette {
+        Palette::new(iter.into_iter().collect())
+    }
+}
+
+// each distinct pixel in L*a*b*, with how many times it appears, in order of first appearance
+fn histogram(pixels: &[Color]) -> Vec<(Vec3, usize)> {
+    let mut index = HashMap::new();
+    let mut histogram: Vec<(Vec3, usize)> = Vec::new();
+    for &pixel in pixels {
+        let i = *index.entry(pixel).or_insert_with(|| {
+            histogram.push((pixel.to_lab().into(), 0));
+            histogram.len() - 1
+        });
+        histogram[i].1 += 1
+    }
+    histogram
+}
+
+// the index of the centre nearest to lab in L*a*b*, which is what k-means minimises
+fn nearest_centre(centres: &[Vec3], lab: Vec3) -> usize {
+    (0..centres.len()).min_by(|&i, &j| centres[i].distance(lab).total_cmp(&centres[j].distance(lab))).unwrap()
+}
+
+// the mean colours of at most n boxes split from the histogram
+fn median_cut(histogram: &[(Vec3, usize)], n: usize) -> Vec<Vec3> {
+    if n == 0 || histogram.is_empty() { return Vec::new() }
+    let mut boxes = vec![histogram.to_vec()];
+    while boxes.len() < n {
+        // the widest box with more than one colour, and the axis along which it is widest
+        let widest = boxes.iter().enumerate()
+            .filter(|(_, b)| b.len() > 1)
+            .map(|(i, b)| {
+                let (axis, width) = (0..3).map(|axis| (axis, extent(b, axis))).max_by(|x, y| x.1.total_cmp(&y.1)).unwrap();
+                (i, axis, width)
+            })
+            .max_by(|x, y| x.2.total_cmp(&y.2));
+        let (i, axis, _) = match widest {
+            Some(widest) => widest,
+            None => break
+        };
+        let mut split = boxes.swap_remove(i);
+        split.sort_by(|p, q| coordinate(p.0, axis).total_cmp(&coordinate(q.0, axis)));
+        // the median pixel, counting repeats, but leaving at least one colour on each side
+        let total: usize = split.iter().map(|&(_, count)| count).sum();
+        let mut seen = 0;
+        let median = split.iter().position(|&(_, count)| { seen += count; 2 * seen >= total }).unwrap();
+        let cut = (median + 1).min(split.len() - 1);
+        let upper = split.split_off(cut);
+        boxes.push(split);
+        boxes.push(upper)
+    }
+    boxes.iter().map(|b| {
+        let (sum, count) = b.iter().fold((Vec3::zero(), 0), |(sum, total), &(lab, count)| (sum + lab * count as f64, total + count));
+        sum / count as f64
+    }).collect()
+}
+
+fn coordinate(v: Vec3, axis: usize) -> f64 {
+    match axis {
+        0 => v.x,
+        1 => v.y,
+        _ => v.z
+    }
+}
+
+fn extent(colors: &[(Vec3, usize)], axis: usize) -> f64 {
+    let values = colors.iter().map(|&(lab, _)| coordinate(lab, axis));
+    values.clone().fold(f64::NEG_INFINITY, f64::max) - values.fold(f64::INFINITY, f64::min)
+}
@@ -0,0 +1,327 @@
+//! Complex numbers with operator overloading.
+//!
+//! `Complex<T>` supports the arithmetic operators (and their `*Assign` forms) between two complex
+//! numbers and between a complex number and a scalar `T`, on either side; dividing by a complex
+//! number, or subtracting one from a scalar, needs signed parts. Polar form and the
+//! transcendental functions are available when `T` is a [`Float`], and such numbers can also be
+//! parsed from strings (see [`parse`]).
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::num::{Float, Num, Zero};
+
+pub mod parse;
+pub use parse::{ParseComplexError, ParseComplexErrorKind};
+
+/// A complex number `re + im·i` with parts of type `T`.
+///
+/// The default value is `0 + 0i` for numeric `T`.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T
+}
+
+impl<T> Complex<T> {
+    pub fn new(re: T, im: T) -> Complex<T> {
+        Complex{re, im}
+    }
+}
+
+impl<T: Neg<Output = T>> Complex<T> {
+    /// The complex conjugate `re - im·i`.
+    pub fn conj(self) -> Complex<T> {
+        Complex{re: self.re, im: -self.im}
+    }
+}
+
+impl<T: Num> Complex<T> {
+    /// The imaginary unit `i`.
+    pub fn i() -> Complex<T> {
+        Complex{re: T::zero(), im: T::one()}
+    }
+
+    /// The squared modulus `re² + im²`, which needs no square root.
+    pub fn norm_sqr(&self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+// prints as `re + imi` or `re - imi`, honouring a precision such as {:.2} on both parts
+impl<T: fmt::Display> fmt::Display for Complex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (re, im) = match f.precision() {
+            Some(p) => (format!("{:.*}", p, self.re), format!("{:.*}", p, self.im)),
+            None => (self.re.to_string(), self.im.to_string())
+        };
+        match im.strip_prefix('-') {
+            Some(magnitude) => write!(f, "{} - {}i", re, magnitude),
+            None => write!(f, "{} + {}i", re, im)
+        }
+    }
+}
+
+// complex op complex
+
+impl<T: Add<Output = T>> Add for Complex<T> {
+    type Output = Complex<T>;
+    fn add(self, rhs: Self) -> Self::Output {
+        Complex{
+            re: self.re + rhs.re,
+            im: self.im + rhs.im
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Complex<T> {
+    type Output = Complex<T>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Complex{
+            re: self.re - rhs.re,
+            im: self.im - rhs.im
+        }
+    }
+}
+
+// Num includes Copy, which is necessary because we use real and imaginary parts multiple times
+impl<T: Num> Mul for Complex<T> {
+    type Output = Complex<T>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Complex{
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re
+        }
+    }
+}
+
+// Smith's algorithm: dividing through by the larger of c and d first means neither c² + d²
+// nor the products in the numerator overflow unless the quotient itself does
+impl<T: Float> Div for Complex<T> {
+    type Output = Complex<T>;
+    fn div(self, rhs: Self) -> Self::Output {
+        let (a, b, c, d) = (self.re, self.im, rhs.re, rhs.im);
+        if c.abs() >= d.abs() {
+            let r = d / c;
+            let denom = c + d * r;
+            Complex{re: (a + b * r) / denom, im: (b - a * r) / denom}
+        } else {
+            let r = c / d;
+            let denom = c * r + d;
+            Complex{re: (a * r + b) / denom, im: (b * r - a) / denom}
+        }
+    }
+}
+
+// (a + bi) / (c + di) = ((ac + bd) + (bc - ad)i) / (c² + d²), truncating like integer division;
+// bc - ad is negative as often as not, so like subtraction from a scalar this needs a signed type
+macro_rules! integer_div {
+    ($($t:ty)*) => {$(
+        impl Div for Complex<$t> {
+            type Output = Complex<$t>;
+            fn div(self, rhs: Self) -> Self::Output {
+                let denom = rhs.norm_sqr();
+                Complex{
+                    re: (self.re * rhs.re + self.im * rhs.im) / denom,
+                    im: (self.im * rhs.re - self.re * rhs.im) / denom
+                }
+            }
+        }
+    )*}
+}
+
+integer_div!(i8 i16 i32 i64 i128 isize);
+
+impl<T: Neg<Output = T>> Neg for Complex<T> {
+    type Output = Complex<T>;
+    fn neg(self) -> Self::Output {
+        Complex{re: -self.re, im: -self.im}
+    }
+}
+
+impl<T: AddAssign<T>> AddAssign for Complex<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.re += rhs.re;
+        self.im += rhs.im
+    }
+}
+
+impl<T: SubAssign<T>> SubAssign for Complex<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.re -= rhs.re;
+        self.im -= rhs.im
+    }
+}
+
+impl<T: Num> MulAssign for Complex<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl<T: Num> DivAssign for Complex<T> where Complex<T>: Div<Output = Complex<T>> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+// complex op scalar
+
+impl<T: Add<Output = T>> Add<T> for Complex<T> {
+    type Output = Complex<T>;
+    fn add(self, rhs: T) -> Self::Output {
+        Complex{re: self.re + rhs, im: self.im}
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<T> for Complex<T> {
+    type Output = Complex<T>;
+    fn sub(self, rhs: T) -> Self::Output {
+        Complex{re: self.re - rhs, im: self.im}
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Complex<T> {
+    type Output = Complex<T>;
+    fn mul(self, rhs: T) -> Self::Output {
+        Complex{re: self.re * rhs, im: self.im * rhs}
+    }
+}
+
+impl<T: Div<Output = T> + Copy> Div<T> for Complex<T> {
+    type Output = Complex<T>;
+    fn div(self, rhs: T) -> Self::Output {
+        Complex{re: self.re / rhs, im: self.im / rhs}
+    }
+}
+
+impl<T: AddAssign<T>> AddAssign<T> for Complex<T> {
+    fn add_assign(&mut self, rhs: T) {
+        self.re += rhs
+    }
+}
+
+impl<T: SubAssign<T>> SubAssign<T> for Complex<T> {
+    fn sub_assign(&mut self, rhs: T) {
+        self.re -= rhs
+    }
+}
+
+impl<T: MulAssign<T> + Copy> MulAssign<T> for Complex<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.re *= rhs;
+        self.im *= rhs
+    }
+}
+
+impl<T: DivAssign<T> + Copy> DivAssign<T> for Complex<T> {
+    fn div_assign(&mut self, rhs: T) {
+        self.re /= rhs;
+        self.im /= rhs
+    }
+}
+
+// scalar op complex
+// coherence rules only allow these for concrete scalar types, hence the macros
+
+macro_rules! scalar_lhs_ops {
+    ($($t:ty)*) => {$(
+        impl Add<Complex<$t>> for $t {
+            type Output = Complex<$t>;
+            fn add(self, rhs: Complex<$t>) -> Complex<$t> {
+                Complex{re: self + rhs.re, im: rhs.im}
+            }
+        }
+
+        impl Mul<Complex<$t>> for $t {
+            type Output = Complex<$t>;
+            fn mul(self, rhs: Complex<$t>) -> Complex<$t> {
+                Complex{re: self * rhs.re, im: self * rhs.im}
+            }
+        }
+    )*}
+}
+
+// subtraction and division negate the imaginary part, so they need a signed scalar
+macro_rules! signed_scalar_lhs_ops {
+    ($($t:ty)*) => {$(
+        impl Sub<Complex<$t>> for $t {
+            type Output = Complex<$t>;
+            fn sub(self, rhs: Complex<$t>) -> Complex<$t> {
+                Complex{re: self - rhs.re, im: -rhs.im}
+            }
+        }
+
+        impl Div<Complex<$t>> for $t {
+            type Output = Complex<$t>;
+            fn div(self, rhs: Complex<$t>) -> Complex<$t> {
+                Complex::new(self, 0 as $t) / rhs
+            }
+        }
+    )*}
+}
+
+scalar_lhs_ops!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+signed_scalar_lhs_ops!(i8 i16 i32 i64 i128 isize f32 f64);
+
+impl<T: PartialEq> PartialEq for Complex<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.re == other.re && self.im == other.im
+    }
+}
+
+impl<T: Eq> Eq for Complex<T> {}
+
+// polar form and transcendental functions, which only make sense for floating point parts
+
+impl<T: Float> Complex<T> {
+    /// The complex number with modulus `r` and argument `theta` (in radians).
+    pub fn from_polar(r: T, theta: T) -> Complex<T> {
+        Complex{re: r * theta.cos(), im: r * theta.sin()}
+    }
+
+    /// The modulus and argument `(|z|, arg z)`.
+    pub fn to_polar(self) -> (T, T) {
+        (self.norm(), self.arg())
+    }
+
+    /// The modulus `|z|`.
+    pub fn norm(self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    /// The argument in `(-π, π]`.
+    pub fn arg(self) -> T {
+        self.im.atan2(self.re)
+    }
+
+    /// `e^z`.
+    pub fn exp(self) -> Complex<T> {
+        Complex::from_polar(self.re.exp(), self.im)
+    }
+
+    /// The principal natural logarithm; the imaginary part is in `(-π, π]`.
+    pub fn ln(self) -> Complex<T> {
+        Complex{re: self.norm().ln(), im: self.arg()}
+    }
+
+    /// `z` raised to a real power, using the principal branch.
+    pub fn powf(self, n: T) -> Complex<T> {
+        // 0ⁿ is 0 for positive n, 1 for n = 0 and infinite for negative n
+        if self.is_zero() {
+            return if n > T::zero() { self } else { Complex::new(T::zero().powf(n), T::zero()) }
+        }
+        let (r, theta) = self.to_polar();
+        Complex::from_polar(r.powf(n), theta * n)
+    }
+
+    /// The principal square root, whose real part is non-negative.
+    pub fn sqrt(self) -> Complex<T> {
+        // avoids the cancellation in from_polar(√r, θ/2) near the negative real axis
+        let two = T::one() + T::one();
+        let r = self.norm();
+        let re = ((r + self.re) / two).sqrt();
+        let im = ((r - self.re) / two).sqrt().copysign(self.im);
+        Complex{re, im}
+    }
+}
@@ -0,0 +1,263 @@
+//! Parsing complex numbers from text.
+//!
+//! Accepted forms, with optional whitespace between the parts:
+//!
+//! * rectangular: `3+4i`, `1 - 2i`, `-2.5i`, `7`, `1e-3-2j`, `-i`, `NaN - 0i`
+//! * polar: `5∠53.13°` (angle in degrees) or `5∠0.9273` (angle in radians)
+//!
+//! Both `i` and `j` are accepted as the imaginary unit.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use super::Complex;
+use crate::num::Float;
+
+/// Error returned when a string is not a valid complex number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseComplexError {
+    kind: ParseComplexErrorKind,
+    offset: usize
+}
+
+/// What went wrong while parsing a complex number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseComplexErrorKind {
+    /// The input was empty or only whitespace.
+    Empty,
+    /// A number, sign or imaginary unit was expected.
+    ExpectedNumber,
+    /// A number was well-formed but could not be represented, e.g. in the target type.
+    InvalidNumber,
+    /// Something other than a sign or the end of input followed a term, e.g. the `k` in `4k`.
+    UnexpectedInput,
+    /// Two real or two imaginary terms, e.g. `1 + 2`.
+    DuplicatePart
+}
+
+impl ParseComplexError {
+    fn new(kind: ParseComplexErrorKind, offset: usize) -> ParseComplexError {
+        ParseComplexError{kind, offset}
+    }
+
+    pub fn kind(&self) -> ParseComplexErrorKind {
+        self.kind
+    }
+
+    /// Byte offset into the input at which parsing failed.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for ParseComplexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let what = match self.kind {
+            ParseComplexErrorKind::Empty => "empty input",
+            ParseComplexErrorKind::ExpectedNumber => "expected a number or imaginary unit",
+            ParseComplexErrorKind::InvalidNumber => "invalid number",
+            ParseComplexErrorKind::UnexpectedInput => "unexpected input",
+            ParseComplexErrorKind::DuplicatePart => "real or imaginary part given twice"
+        };
+        write!(f, "{} at byte {}", what, self.offset)
+    }
+}
+
+impl Error for ParseComplexError {}
+
+// what the input described, before conversion to a Complex
+enum Parsed<T> {
+    Rectangular { re: Option<T>, im: Option<T> },
+    Polar { r: T, theta: T, degrees: bool }
+}
+
+// one signed term of a rectangular form, e.g. "-2.5i"
+struct Term<T> {
+    value: Option<T>, // None for a bare unit such as "-i"
+    negative: bool,
+    imaginary: bool,
+    offset: usize
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let trimmed = self.rest().trim_start();
+        self.pos = self.input.len() - trimmed.len();
+    }
+
+    // consumes c if it is next, skipping whitespace first
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    // any run of + and - signs, e.g. the "+ -" in the old `1 + -2i` display format
+    // returns whether the signs make the term negative
+    fn signs(&mut self) -> bool {
+        let mut negative = false;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('+') => self.pos += 1,
+                Some('-') => { negative = !negative; self.pos += 1 },
+                _ => return negative
+            }
+        }
+    }
+
+    // length of the unsigned number at the start of the remaining input, or 0 if there is none
+    fn number_len(&self) -> usize {
+        let rest = self.rest();
+        let lower = rest.get(..8).unwrap_or(rest).to_ascii_lowercase();
+        for word in ["infinity", "inf", "nan"].iter() {
+            if lower.starts_with(word) { return word.len() }
+        }
+
+        let bytes = rest.as_bytes();
+        let digits = |mut i: usize| {
+            let start = i;
+            while i < bytes.len() && bytes[i].is_ascii_digit() { i += 1 }
+            (i, i - start)
+        };
+        let (mut end, int_digits) = digits(0);
+        let mut frac_digits = 0;
+        if end < bytes.len() && bytes[end] == b'.' {
+            let (e, n) = digits(end + 1);
+            end = e;
+            frac_digits = n
+        }
+        if int_digits + frac_digits == 0 { return 0 }
+
+        // exponent, only if it has digits: "2e" is a number followed by junk
+        if end < bytes.len() && (bytes[end] == b'e' || bytes[end] == b'E') {
+            let mut i = end + 1;
+            if i < bytes.len() && (bytes[i] == b'+' || bytes[i] == b'-') { i += 1 }
+            let (e, n) = digits(i);
+            if n > 0 { end = e }
+        }
+        end
+    }
+
+    fn number<T: FromStr>(&mut self, negative: bool) -> Result<Option<T>, ParseComplexError> {
+        let start = self.pos;
+        let len = self.number_len();
+        if len == 0 { return Ok(None) }
+        self.pos += len;
+        let text = &self.input[start..self.pos];
+        let value = if negative { format!("-{}", text).parse() } else { text.parse() };
+        value.map(Some).map_err(|_| ParseComplexError::new(ParseComplexErrorKind::InvalidNumber, start))
+    }
+
+    fn unit(&mut self) -> bool {
+        match self.peek() {
+            Some('i') | Some('j') => { self.pos += 1; true },
+            _ => false
+        }
+    }
+
+    fn term<T: FromStr>(&mut self) -> Result<Term<T>, ParseComplexError> {
+        let negative = self.signs();
+        let offset = self.pos;
+        let value = self.number(negative)?;
+        if value.is_some() { self.skip_whitespace() }
+        let imaginary = self.unit();
+        if value.is_none() && !imaginary {
+            return Err(ParseComplexError::new(ParseComplexErrorKind::ExpectedNumber, self.pos))
+        }
+        if let Some(c) = self.peek() {
+            if c.is_alphanumeric() || c == '.' {
+                return Err(ParseComplexError::new(ParseComplexErrorKind::UnexpectedInput, self.pos))
+            }
+        }
+        Ok(Term{value, negative, imaginary, offset})
+    }
+
+    fn parse<T: FromStr>(mut self) -> Result<Parsed<T>, ParseComplexError> {
+        self.skip_whitespace();
+        if self.rest().is_empty() {
+            return Err(ParseComplexError::new(ParseComplexErrorKind::Empty, self.pos))
+        }
+
+        let first: Term<T> = self.term()?;
+        if !first.imaginary && self.eat('∠') {
+            let negative = self.signs();
+            let at = self.pos;
+            let theta = self.number(negative)?
+                .ok_or_else(|| ParseComplexError::new(ParseComplexErrorKind::ExpectedNumber, at))?;
+            let degrees = self.eat('°');
+            self.finish()?;
+            let r = first.value.expect("a real term always has a value");
+            return Ok(Parsed::Polar{r, theta, degrees})
+        }
+
+        let mut re = None;
+        let mut im = None;
+        let mut term = first;
+        loop {
+            let slot = if term.imaginary { &mut im } else { &mut re };
+            if slot.is_some() {
+                return Err(ParseComplexError::new(ParseComplexErrorKind::DuplicatePart, term.offset))
+            }
+            *slot = Some(term);
+
+            self.skip_whitespace();
+            if self.rest().is_empty() { break }
+            // a second term must be joined to the first with a sign
+            let at = self.pos;
+            match self.peek() {
+                Some('+') | Some('-') if re.is_none() || im.is_none() => term = self.term()?,
+                _ => return Err(ParseComplexError::new(ParseComplexErrorKind::UnexpectedInput, at))
+            }
+        }
+
+        let im = match im {
+            None => None,
+            Some(Term{value: Some(v), ..}) => Some(v),
+            // a bare unit stands for one
+            Some(Term{value: None, negative, offset, ..}) => {
+                let one = if negative { "-1" } else { "1" };
+                Some(one.parse().map_err(|_| ParseComplexError::new(ParseComplexErrorKind::InvalidNumber, offset))?)
+            }
+        };
+        Ok(Parsed::Rectangular{re: re.and_then(|t| t.value), im})
+    }
+
+    fn finish(&mut self) -> Result<(), ParseComplexError> {
+        self.skip_whitespace();
+        if self.rest().is_empty() { Ok(()) }
+        else { Err(ParseComplexError::new(ParseComplexErrorKind::UnexpectedInput, self.pos)) }
+    }
+}
+
+impl<T: Float + FromStr> FromStr for Complex<T> {
+    type Err = ParseComplexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match (Parser{input: s, pos: 0}).parse::<T>()? {
+            Parsed::Rectangular{re, im} => Ok(Complex::new(re.unwrap_or_else(T::zero), im.unwrap_or_else(T::zero))),
+            Parsed::Polar{r, theta, degrees} => {
+                let theta = if degrees { theta.to_radians() } else { theta };
+                Ok(Complex::from_polar(r, theta))
+            }
+        }
+    }
+}
@@ -1,5 +1,6 @@
 // Control Flow
-#![allow(overlapping_patterns)]
+#![allow(overlapping_range_endpoints)]
+#![allow(clippy::match_overlapping_arm)]
 
 // If statement
 // if expression syntax:
@@ -75,10 +76,25 @@ fn match_stmt(country_code:i32) {
         });
 }
 
+fn match_statements() {
+    match_stmt(666)
+}
+
+// Chapter registry
+use crate::registry::{Chapter, Section};
+
+pub const CHAPTER: Chapter = Chapter {
+    number: 3,
+    key: "control_flow",
+    title: "Control Flow",
+    sections: &[
+        Section { number: 1, name: "If statement", run: if_statement },
+        Section { number: 2, name: "While and loop", run: while_and_loop },
+        Section { number: 3, name: "For loops", run: for_loop },
+        Section { number: 4, name: "Match statements", run: match_statements }
+    ]
+};
+
 pub fn main() {
-    println!("----- 3. Control Flow -----");
-    if_statement();
-    while_and_loop();
-    for_loop();
-    match_stmt(666);
-}
\ No newline at end of file
+    CHAPTER.run()
+}
@@ -1,28 +1,20 @@
 // Data Structures
 
-#![allow(illegal_floating_point_literal_pattern)]
 #![allow(unused_variables)]
+#![allow(unused_assignments)]
+// the examples spell things out long-hand on purpose
+#![allow(clippy::approx_constant, clippy::needless_range_loop, clippy::ptr_arg, clippy::print_with_newline,
+         clippy::iter_nth_zero, clippy::char_lit_as_u8, clippy::cmp_owned, clippy::print_literal,
+         clippy::useless_conversion, clippy::manual_is_multiple_of)]
 
 // Structs
-use std::fmt;
+use rust_programming_language::geometry::{Line, Point};
 
 fn structs() {
     println!("----- 4.1. Structs -----");
 
     // struct = record
-    #[derive(Clone, Copy)] // allows reuse of Point variables
-    struct Point {
-        x: f64,
-        y: f64,
-        z: f64
-    }
-
-    // implementing display trait for Point struct
-    impl fmt::Display for Point {
-        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            write!(f, "({}, {}, {})", self.x, self.y, self.z)
-        }
-    }
+    // Point derives Clone and Copy (see geometry.rs), which allows reuse of Point variables
 
     let p1 = Point {x: 3.14, y: 2.71, z: 1.41};
     println!("p1 has coordinates {}", p1);
@@ -30,35 +22,19 @@ fn structs() {
     let p2 = Point {x: -3.14, y: 27.1, z: -14.3};
     println!("p2 has coordinates {}", p2);
 
-    struct Line {
-        start: Point,
-        end: Point
-    }
-
-    // implementing display trait for Line struct
-    impl fmt::Display for Line {
-        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            write!(f, "[{}, {}]", self.start, self.end)
-        }
-    }
-
     let _myline = Line {start: p1, end: p2}; // prefix with _ if not used elsewhere
     let myline = Line {start: p2, end: p1};
     println!("My line is {}", myline);
 }
 
 // Enumerations
+use rust_programming_language::color::{Color, Hsl, Palette};
+
 fn enums() {
     println!("----- 4.2. Enumerations -----");
 
+    // Color (color.rs) has unit, tuple-style and struct-style variants
     //struct CMYK{cyan:u8, magenta:u8, yellow:u8, black:u8}
-    enum Color {
-        Red,
-        Blue,
-        Green,
-        RGB(u8, u8, u8), // tuple-style
-        CMYK{cyan:u8, magenta:u8, yellow:u8, black:u8} // struct-style
-    }
 
     let color = Color::RGB(0,0,0);
     match color {
@@ -72,6 +48,37 @@ fn enums() {
         Color::CMYK{cyan:c, magenta:m, yellow:y, black:k}
           => println!("CMYK[cyan: {}, magenta: {}, yellow: {}, black: {}]", c, m, y, k),
     }
+
+    // colours compare by how they look, so the arm above was right to call both black
+    println!("{}", Color::RGB(0,0,0) == Color::CMYK{cyan:0, magenta:0, yellow:0, black:255});
+
+    // and convert between models
+    let orange = Color::from_hex("#f80").unwrap();
+    println!("{} = {:?} = CMYK{:?} = {} = {}", orange.to_hex(), orange.to_rgb(), orange.to_cmyk(),
+             orange.to_hsl(), orange.to_hsv());
+    println!("{} is {}", Color::Green.to_hsl(), Color::from(Hsl::new(120.0, 1.0, 0.5)).to_hex());
+
+    // FromStr parses CSS colours, so strings from config files become Colors directly
+    for css in ["rebeccapurple", "#ff880080", "rgb(255 136 0 / 50%)", "hsl(120, 100%, 25%)", "cmyk(0%, 47%, 100%, 0%)"] {
+        let color: Color = css.parse().unwrap();
+        println!("{} parses to {}", css, color.to_hex());
+    }
+    if let Err(err) = "rgb(255, 136)".parse::<Color>() {
+        println!("\"rgb(255, 136)\" does not parse: {}", err)
+    }
+
+    // in L*a*b*, distance measures how different colours look
+    let brand: Palette = ["#1a73e8", "#34a853", "#fbbc05", "#ea4335", "white"].iter().map(|css| css.parse().unwrap()).collect();
+    for css in ["orange", "navy", "lime", "pink"] {
+        let color: Color = css.parse().unwrap();
+        let nearest = brand.nearest(color).unwrap();
+        println!("{} {} is nearest to {}, delta E {:.1}", css, color.to_lab(), nearest.to_hex(), color.delta_e(&nearest));
+    }
+
+    // and a few colours can stand in for many
+    let gradient: Vec<Color> = (0..=255).map(|i| Color::RGB(i, 255 - i, 128)).collect();
+    let palette = Palette::k_means(&gradient, 4, 10);
+    println!("a 256 colour gradient in 4 colours: {:?}", palette.colors().iter().map(Color::to_hex).collect::<Vec<_>>());
 }
 
 // Unions
@@ -109,14 +116,11 @@ fn unions() {
 }
 
 // Option<T> and if let/while let
+use rust_programming_language::math::safe_div; // safe_div(x, y) is None when y == 0
+
 fn optionT() {
     println!("----- 4.4. Option<T> -----");
 
-    fn safe_div(x:f32, y:f32) -> Option<f32> {
-        if y == 0.0 {None}
-        else {Some(x/ y)}
-    }
-
     fn print_option(op: Option<f32>) {
         match op {
             None => println!("None"),
@@ -126,7 +130,7 @@ fn optionT() {
 
     // debug output
     // equivalent to print_option(safe_div(...))
-    println!("{:?}", safe_div(3.14, 1.01));
+    println!("{:?}", safe_div(3.14f32, 1.01));
 
     print_option(safe_div(2.01, 0.0));
     print_option(safe_div(2.01, 0.1));
@@ -135,7 +139,7 @@ fn optionT() {
     // if-let
     // if match is successful, the first branch is executed with appropriate binding
     // else the second branch is executed
-    if let Some(res) = safe_div(2.01, 10.0) { println!("result = {}", res) }
+    if let Some(res) = safe_div(2.01f32, 10.0) { println!("result = {}", res) }
     else { println!("cannot divide by 0") }
 
     // while-let
@@ -357,14 +361,6 @@ fn pattern_matching() {
         println!("{}: I have {} oranges", x, how_many(x))
     }
 
-    enum Color {
-        Red,
-        Blue,
-        Green,
-        RGB(u8, u8, u8), // tuple-style
-        CMYK{cyan:u8, magenta:u8, yellow:u8, black:u8} // struct-style
-    }
-
     let color = Color::CMYK{cyan:0,magenta:0,yellow:0,black:123};
     match color {
         Color::Red => println!("red"),
@@ -385,35 +381,54 @@ fn pattern_matching() {
 fn generics() {
     println!("----- 4.12. Generics -----");
 
-    // parametric polymorphism
+    // parametric polymorphism: one Point for every coordinate type and every dimension
 
-    struct Point<A,B> {
-        x: A,
-        y: B
-    }
+    use rust_programming_language::geometry::generic;
 
-    struct Line<A,B,C,D> {
-        start: Point<A,B>,
-        end: Point<C,D>
-    }
+    let a: generic::Point<u16, 2> = generic::Point::new([0, 3]);
+    let b: generic::Point<u16, 2> = generic::Point::new([4, 0]);
+    // squared distances are exact for integers, even unsigned ones
+    println!("|{} - {}|^2 = {}", a, b, a.distance_sqr(&b));
+
+    // widening f32 coordinates to f64 loses nothing, so it's a plain From
+    let c: generic::Point<f32, 3> = generic::Point::new([1.5, 0.25, 2.0]);
+    let d: generic::Point<f64, 3> = c.into();
+    // narrowing goes through map, which says how to convert each coordinate
+    let e: generic::Point<f32, 3> = d.map(|x| x as f32);
+    println!("{} as f64 is {}, and back as f32 is {}; equal: {}", c, d, e, c == e);
 
-    let a:Point<u16,f32> = Point {x:0, y:0.01};
-    let b:Point<f32,f64> = Point {x:3.14, y:0.00001};
-    let my_line = Line{start:a, end:b};
+    // dimensions change by dropping coordinates or padding with zeros
+    let flat: generic::Point<f64, 2> = d.resize();
+    let tall: generic::Point<f64, 4> = d.resize();
+    println!("{} in 2D is {}, in 4D is {}", d, flat, tall);
+
+    let my_line = generic::Line::new(d, generic::Point::origin());
+    println!("{} has length {:.4} and midpoint {}", my_line, my_line.len(), my_line.midpoint());
 }
 
+// Chapter registry
+use crate::registry::{Chapter, Section};
+
+pub const CHAPTER: Chapter = Chapter {
+    number: 4,
+    key: "data_structures",
+    title: "Data structures",
+    sections: &[
+        Section { number: 1, name: "Structs", run: structs },
+        Section { number: 2, name: "Enumerations", run: enums },
+        Section { number: 3, name: "Unions", run: unions },
+        Section { number: 4, name: "Option<T>", run: optionT },
+        Section { number: 5, name: "Arrays", run: arrays },
+        Section { number: 6, name: "Vectors", run: vectors },
+        Section { number: 7, name: "Slices", run: slices },
+        Section { number: 8, name: "Strings", run: strings },
+        Section { number: 9, name: "Tuples", run: tuples },
+        Section { number: 10, name: "Hashmap", run: hashmap },
+        Section { number: 11, name: "Pattern matching", run: pattern_matching },
+        Section { number: 12, name: "Generics", run: generics }
+    ]
+};
+
 pub fn main() {
-    println!("----- 4. Data structures -----");
-    structs();
-    enums();
-    unions();
-    optionT();
-    arrays();
-    vectors();
-    slices();
-    strings();
-    tuples();
-    hashmap();
-    pattern_matching();
-    generics();
-}
\ No newline at end of file
+    CHAPTER.run()
+}
@@ -0,0 +1,181 @@
+//! Fast Fourier transforms over `Complex<T>` for any [`Float`] `T`.
+//!
+//! [`fft`] and [`ifft`] accept any length: powers of two use the iterative radix-2 Cooley–Tukey
+//! algorithm, and other lengths are reduced to a power-of-two convolution with Bluestein's
+//! algorithm. Both run in `O(n log n)`. [`dft`] is the direct `O(n²)` definition, kept as a
+//! reference.
+//!
+//! The forward transform is `X[k] = Σ x[j]·e^(-2πi·jk/n)` and the inverse divides by `n`, so
+//! `ifft(fft(x)) == x` up to rounding. Twiddle factors are computed in `f64` and rounded to `T`,
+//! so `f32` transforms lose no accuracy to the angles.
+
+use std::f64::consts::PI;
+
+use crate::complex::Complex;
+use crate::num::{Float, Zero};
+
+/// The discrete Fourier transform of `input`, of any length.
+pub fn fft<T: Float>(input: &[Complex<T>]) -> Vec<Complex<T>> {
+    let mut data = input.to_vec();
+    if data.len().is_power_of_two() {
+        fft_in_place(&mut data);
+        data
+    } else {
+        bluestein(&data)
+    }
+}
+
+/// The inverse discrete Fourier transform of `input`, of any length.
+pub fn ifft<T: Float>(input: &[Complex<T>]) -> Vec<Complex<T>> {
+    // ifft(x) = conj(fft(conj(x))) / n
+    let n = T::from_f64(input.len() as f64);
+    let conjugated: Vec<_> = input.iter().map(|z| z.conj()).collect();
+    fft(&conjugated).into_iter().map(|z| z.conj() / n).collect()
+}
+
+/// Radix-2 FFT in place.
+///
+/// # Panics
+///
+/// If the length of `data` is not a power of two (zero is not).
+pub fn fft_in_place<T: Float>(data: &mut [Complex<T>]) {
+    radix2(data, -1.0)
+}
+
+/// Radix-2 inverse FFT in place, including the division by the length.
+///
+/// # Panics
+///
+/// If the length of `data` is not a power of two (zero is not).
+pub fn ifft_in_place<T: Float>(data: &mut [Complex<T>]) {
+    radix2(data, 1.0);
+    let n = T::from_f64(data.len() as f64);
+    for z in data.iter_mut() {
+        *z = *z / n
+    }
+}
+
+/// The discrete Fourier transform computed straight from its definition, in `O(n²)`.
+pub fn dft<T: Float>(input: &[Complex<T>]) -> Vec<Complex<T>> {
+    let n = input.len();
+    (0..n).map(|k| {
+        input.iter().enumerate().fold(Complex::zero(), |acc, (j, &x)| {
+            // reduce jk mod n first so the angle stays small and accurate
+            let angle = -2.0 * PI * ((j * k) % n) as f64 / n as f64;
+            acc + x * unit(angle)
+        })
+    }).collect()
+}
+
+/// Linear convolution `(a * b)[k] = Σ a[j]·b[k - j]`, of length `a.len() + b.len() - 1`.
+///
+/// Empty if either input is empty.
+pub fn convolve<T: Float>(a: &[Complex<T>], b: &[Complex<T>]) -> Vec<Complex<T>> {
+    if a.is_empty() || b.is_empty() { return Vec::new() }
+    let len = a.len() + b.len() - 1;
+    let size = len.next_power_of_two();
+    let mut fa = padded(a, size);
+    let mut fb = padded(b, size);
+    fft_in_place(&mut fa);
+    fft_in_place(&mut fb);
+    for (x, y) in fa.iter_mut().zip(fb) {
+        *x *= y
+    }
+    ifft_in_place(&mut fa);
+    fa.truncate(len);
+    fa
+}
+
+/// Linear convolution of two real sequences.
+pub fn convolve_real<T: Float>(a: &[T], b: &[T]) -> Vec<T> {
+    let to_complex = |xs: &[T]| xs.iter().map(|&x| Complex::new(x, T::zero())).collect::<Vec<_>>();
+    convolve(&to_complex(a), &to_complex(b)).into_iter().map(|z| z.re).collect()
+}
+
+/// Circular convolution of two sequences of the same length `n`, indices taken mod `n`.
+///
+/// # Panics
+///
+/// If the lengths differ.
+pub fn circular_convolve<T: Float>(a: &[Complex<T>], b: &[Complex<T>]) -> Vec<Complex<T>> {
+    assert_eq!(a.len(), b.len(), "circular convolution needs sequences of the same length");
+    let fa = fft(a);
+    let fb = fft(b);
+    let product: Vec<_> = fa.into_iter().zip(fb).map(|(x, y)| x * y).collect();
+    ifft(&product)
+}
+
+// iterative Cooley–Tukey; sign is -1 for the forward transform and +1 for the (unscaled) inverse
+fn radix2<T: Float>(data: &mut [Complex<T>], sign: f64) {
+    let n = data.len();
+    assert!(n.is_power_of_two(), "radix-2 FFT needs a power-of-two length, got {}", n);
+
+    // bit-reversal permutation
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits().checked_shr(usize::BITS - bits).unwrap_or(0);
+        if i < j { data.swap(i, j) }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        // twiddles computed directly rather than by repeated multiplication, to limit rounding
+        let twiddles: Vec<_> = (0..half)
+            .map(|j| unit(sign * 2.0 * PI * j as f64 / len as f64))
+            .collect();
+        for chunk in data.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(half);
+            for ((x, y), &w) in lo.iter_mut().zip(hi.iter_mut()).zip(&twiddles) {
+                let t = *y * w;
+                *y = *x - t;
+                *x = *x + t
+            }
+        }
+        len *= 2
+    }
+}
+
+// Bluestein's algorithm: jk = (j² + k² - (k - j)²) / 2 turns the DFT into a convolution with a
+// chirp, which is done with power-of-two FFTs
+fn bluestein<T: Float>(input: &[Complex<T>]) -> Vec<Complex<T>> {
+    let n = input.len();
+    if n <= 1 { return input.to_vec() }
+
+    // chirp[k] = e^(-πi·k²/n), with k² reduced mod 2n to keep the angle accurate
+    let chirp: Vec<Complex<T>> = (0..n)
+        .map(|k| unit(-PI * ((k * k) % (2 * n)) as f64 / n as f64))
+        .collect();
+
+    let size = (2 * n - 1).next_power_of_two();
+    let mut a = vec![Complex::zero(); size];
+    for k in 0..n {
+        a[k] = input[k] * chirp[k]
+    }
+    let mut b = vec![Complex::zero(); size];
+    b[0] = chirp[0].conj();
+    for k in 1..n {
+        b[k] = chirp[k].conj();
+        b[size - k] = chirp[k].conj()
+    }
+
+    fft_in_place(&mut a);
+    fft_in_place(&mut b);
+    for (x, y) in a.iter_mut().zip(b) {
+        *x *= y
+    }
+    ifft_in_place(&mut a);
+
+    (0..n).map(|k| a[k] * chirp[k]).collect()
+}
+
+// e^(i·angle), rounded to T only once its parts are known
+fn unit<T: Float>(angle: f64) -> Complex<T> {
+    Complex::new(T::from_f64(angle.cos()), T::from_f64(angle.sin()))
+}
+
+fn padded<T: Float>(xs: &[Complex<T>], size: usize) -> Vec<Complex<T>> {
+    let mut out = xs.to_vec();
+    out.resize(size, Complex::zero());
+    out
+}
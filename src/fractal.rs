@@ -0,0 +1,192 @@
+//! Mandelbrot and Julia set rendering on top of `Complex<f64>`.
+//!
+//! Rows are shared out between worker threads through an `Arc<Mutex<..>>` row counter, and each
+//! finished row is written into an `Arc<Mutex<..>>` image buffer, as in the Mutex section of the
+//! lifetime and memory chapter.
+
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::complex::Complex;
+
+/// Which set to render.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Fractal {
+    /// Iterates `z = z² + c` from `z = 0`, with `c` the pixel.
+    Mandelbrot,
+    /// Iterates `z = z² + c` from `z` = the pixel, with the given `c`.
+    Julia(Complex<f64>)
+}
+
+/// The rectangle of the complex plane that is rendered.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    pub center: Complex<f64>,
+    /// Width of the rectangle; the height follows from the image's aspect ratio.
+    pub width: f64
+}
+
+/// How escape times are turned into pixel colours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Palette {
+    Grayscale,
+    Fire,
+    Ocean
+}
+
+/// Everything needed to render an image.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Options {
+    pub fractal: Fractal,
+    pub viewport: Viewport,
+    /// Image width in pixels.
+    pub width: usize,
+    /// Image height in pixels.
+    pub height: usize,
+    pub max_iterations: u32,
+    pub palette: Palette,
+    pub threads: usize
+}
+
+/// Escape times of every pixel, in row-major order.
+///
+/// A pixel whose orbit never escaped has `max_iterations`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub max_iterations: u32,
+    pub iterations: Vec<u32>
+}
+
+impl Default for Viewport {
+    // the whole Mandelbrot set
+    fn default() -> Viewport {
+        Viewport{center: Complex::new(-0.5, 0.0), width: 3.5}
+    }
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options{
+            fractal: Fractal::Mandelbrot,
+            viewport: Viewport::default(),
+            width: 800,
+            height: 600,
+            max_iterations: 256,
+            palette: Palette::Fire,
+            threads: thread::available_parallelism().map(|n| n.get()).unwrap_or(4)
+        }
+    }
+}
+
+impl Fractal {
+    /// Number of iterations before the orbit of `point` leaves the radius 2 disc,
+    /// or `max_iterations` if it never does.
+    pub fn escape_time(&self, point: Complex<f64>, max_iterations: u32) -> u32 {
+        let (mut z, c) = match *self {
+            Fractal::Mandelbrot => (Complex::new(0.0, 0.0), point),
+            Fractal::Julia(c) => (point, c)
+        };
+        for n in 0..max_iterations {
+            if z.norm_sqr() > 4.0 { return n }
+            z = z * z + c
+        }
+        max_iterations
+    }
+}
+
+impl Palette {
+    /// RGB colour for a pixel that escaped after `n` of `max` iterations; points in the set are black.
+    pub fn rgb(&self, n: u32, max: u32) -> [u8; 3] {
+        if n >= max { return [0, 0, 0] }
+        let t = n as f64 / max as f64;
+        // sqrt spreads out the many pixels that escape early
+        let t = t.sqrt();
+        let channel = |x: f64| (x.clamp(0.0, 1.0) * 255.0).round() as u8;
+        match self {
+            Palette::Grayscale => { let g = channel(t); [g, g, g] },
+            Palette::Fire => [channel(3.0 * t), channel(3.0 * t - 1.0), channel(3.0 * t - 2.0)],
+            Palette::Ocean => [channel(3.0 * t - 2.0), channel(2.0 * t - 0.5), channel(1.5 * t)]
+        }
+    }
+
+    /// Grey level for a pixel, used for PGM output.
+    pub fn gray(&self, n: u32, max: u32) -> u8 {
+        let [r, g, b] = self.rgb(n, max);
+        ((r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000) as u8
+    }
+}
+
+impl Options {
+    /// The point of the complex plane at the center of pixel `(col, row)`.
+    pub fn pixel_to_point(&self, col: usize, row: usize) -> Complex<f64> {
+        let scale = self.viewport.width / self.width as f64;
+        let height = scale * self.height as f64;
+        let re = self.viewport.center.re - self.viewport.width / 2.0 + (col as f64 + 0.5) * scale;
+        // rows go down the image but up the imaginary axis
+        let im = self.viewport.center.im + height / 2.0 - (row as f64 + 0.5) * scale;
+        Complex::new(re, im)
+    }
+
+    fn render_row(&self, row: usize) -> Vec<u32> {
+        (0..self.width)
+            .map(|col| self.fractal.escape_time(self.pixel_to_point(col, row), self.max_iterations))
+            .collect()
+    }
+}
+
+/// Renders the image, computing rows in parallel on `options.threads` threads.
+pub fn render(options: &Options) -> Image {
+    let options = *options;
+    let next_row = Arc::new(Mutex::new(0usize));
+    let pixels = Arc::new(Mutex::new(vec![0u32; options.width * options.height]));
+
+    let workers: Vec<_> = (0..options.threads.max(1)).map(|_| {
+        let next_row = next_row.clone();
+        let pixels = pixels.clone();
+        thread::spawn(move || loop {
+            // claim a row, releasing the lock before the expensive part
+            let row = {
+                let mut next = next_row.lock().unwrap();
+                if *next >= options.height { break }
+                *next += 1;
+                *next - 1
+            };
+            let values = options.render_row(row);
+            let start = row * options.width;
+            pixels.lock().unwrap()[start..start + options.width].copy_from_slice(&values);
+        })
+    }).collect();
+
+    for worker in workers {
+        worker.join().unwrap();
+    }
+
+    let iterations = Arc::try_unwrap(pixels)
+        .expect("all workers have finished")
+        .into_inner()
+        .unwrap();
+    Image{width: options.width, height: options.height, max_iterations: options.max_iterations, iterations}
+}
+
+impl Image {
+    /// Writes a binary PPM (P6) image coloured with `palette`.
+    pub fn write_ppm<W: Write>(&self, out: &mut W, palette: Palette) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        let bytes: Vec<u8> = self.iterations.iter()
+            .flat_map(|&n| palette.rgb(n, self.max_iterations).to_vec())
+            .collect();
+        out.write_all(&bytes)
+    }
+
+    /// Writes a binary PGM (P5) image, using the brightness of `palette`.
+    pub fn write_pgm<W: Write>(&self, out: &mut W, palette: Palette) -> io::Result<()> {
+        write!(out, "P5\n{} {}\n255\n", self.width, self.height)?;
+        let bytes: Vec<u8> = self.iterations.iter()
+            .map(|&n| palette.gray(n, self.max_iterations))
+            .collect();
+        out.write_all(&bytes)
+    }
+}
@@ -1,5 +1,6 @@
 // Functions
 #![allow(unused_variables)]
+#![allow(clippy::approx_constant, clippy::unnecessary_fold)]
 
 // Functions and Function Arguments
 fn functions_and_args() {
@@ -20,25 +21,14 @@ fn functions_and_args() {
 }
 
 // Methods
-use std::fmt;
+use rust_programming_language::geometry::{Line, Point};
+use rust_programming_language::vector::Vec3;
 
 fn methods() {
     println!("----- 5.2. Methods -----");
 
     // method = function attached to a specific data type
-
-    struct Point {
-        x: f64,
-        y: f64,
-        z: f64
-    }
-
-    // implementing display trait for Point struct
-    impl fmt::Display for Point {
-        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            write!(f, "({}, {}, {})", self.x, self.y, self.z)
-        }
-    }
+    // Point and Line live in the library (geometry.rs) along with their Display impls
 
     let p1 = Point {x: 3.14, y: 2.71, z: 1.41};
     println!("p1 has coordinates {}", p1);
@@ -46,29 +36,19 @@ fn methods() {
     let p2 = Point {x: -3.14, y: 27.1, z: -14.3};
     println!("p2 has coordinates {}", p2);
 
-    struct Line {
-        start: Point,
-        end: Point
-    }
-
-    // implementing display trait for Line struct
-    impl fmt::Display for Line {
-        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            write!(f, "[{}, {}]", self.start, self.end)
-        }
-    }
-
-    impl Line {
-        fn len(&self) -> f64 {
-            let dx = self.start.x - self.end.x;
-            let dy = self.start.y - self.end.y;
-            let dz = self.start.z - self.end.z;
-            (dx*dx + dy*dy + dz*dz).sqrt()
-        }
-    }
-
+    // Line::len is a method: it takes &self
     let my_line = Line{start:p1, end:p2};
     println!("My line has length {}", my_line.len());
+
+    // p2 - p1 is a Vec3 (vector.rs), with the usual operators and products as methods
+    let v: Vec3 = p2 - p1;
+    let up = Vec3::new(0.0, 0.0, 1.0);
+    println!("p2 - p1 = {}, twice that = {}, length {}", v, 2.0 * v, v.norm());
+    println!("v . up = {}, v x up = {}, angle = {:.1} degrees", v.dot(up), v.cross(up), v.angle(up).to_degrees());
+    println!("direction = {:?}, midpoint = {}", my_line.direction(), my_line.midpoint());
+    println!("nearest point to the origin = {}", my_line.closest_point(Point::origin()));
+    let other = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0));
+    println!("distance to {} = {}", other, my_line.distance(&other));
 }
 
 // Closures
@@ -121,10 +101,21 @@ fn higher_order_fns() {
     println!("hof sum = {}", sum2);
 }
 
+// Chapter registry
+use crate::registry::{Chapter, Section};
+
+pub const CHAPTER: Chapter = Chapter {
+    number: 5,
+    key: "functions",
+    title: "Functions",
+    sections: &[
+        Section { number: 1, name: "Functions and arguments", run: functions_and_args },
+        Section { number: 2, name: "Methods", run: methods },
+        Section { number: 3, name: "Closures", run: closures },
+        Section { number: 4, name: "Higher-order functions", run: higher_order_fns }
+    ]
+};
+
 pub fn main() {
-    println!("----- 5. Functions -----");
-    functions_and_args();
-    methods();
-    closures();
-    higher_order_fns();
-}
\ No newline at end of file
+    CHAPTER.run()
+}
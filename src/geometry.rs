@@ -0,0 +1,388 @@
+//! Points and line segments in 3D space.
+//!
+//! Coordinates default to `f64`, but any [`Num`] works for construction and display, and any
+//! [`Float`] for measurements. The difference of two points is a [`Vec3`], and every
+//! measurement is made through vector operations.
+//!
+//! [`Geometry`] is the common model for exchanging points, lines and polygons with GIS tools,
+//! as Well-Known Text ([`wkt`]) or GeoJSON ([`geojson`]).
+//!
+//! For points of any dimension and coordinate type, see [`generic`].
+
+pub mod generic;
+pub mod geojson;
+pub mod wkt;
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use crate::num::{Float, Num, Zero};
+use crate::shapes::{Polygon, Rectangle, RegularPolygon, Square, Triangle};
+use crate::vector::Vec3;
+
+/// A point in 3D space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point<T = f64> {
+    pub x: T,
+    pub y: T,
+    pub z: T
+}
+
+impl<T> Point<T> {
+    pub fn new(x: T, y: T, z: T) -> Point<T> {
+        Point{x, y, z}
+    }
+}
+
+impl<T: Zero> Point<T> {
+    /// The point `(0, 0, 0)`.
+    pub fn origin() -> Point<T> {
+        Point{x: T::zero(), y: T::zero(), z: T::zero()}
+    }
+}
+
+impl<T: Float> Point<T> {
+    /// Euclidean distance to `other`.
+    pub fn distance(self, other: Point<T>) -> T {
+        (other - self).norm()
+    }
+
+    /// The point `t` of the way from `self` to `other`: `self` at 0, `other` at 1.
+    pub fn lerp(self, other: Point<T>, t: T) -> Point<T> {
+        self + (other - self) * t
+    }
+}
+
+// the position vector of a point, and back
+impl<T> From<Point<T>> for Vec3<T> {
+    fn from(p: Point<T>) -> Vec3<T> {
+        Vec3{x: p.x, y: p.y, z: p.z}
+    }
+}
+
+impl<T> From<Vec3<T>> for Point<T> {
+    fn from(v: Vec3<T>) -> Point<T> {
+        Point{x: v.x, y: v.y, z: v.z}
+    }
+}
+
+// point - point is the vector between them
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Vec3<T>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vec3{x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z}
+    }
+}
+
+// point ± vector is another point
+impl<T: Add<Output = T>> Add<Vec3<T>> for Point<T> {
+    type Output = Point<T>;
+    fn add(self, rhs: Vec3<T>) -> Self::Output {
+        Point{x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z}
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<Vec3<T>> for Point<T> {
+    type Output = Point<T>;
+    fn sub(self, rhs: Vec3<T>) -> Self::Output {
+        Point{x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z}
+    }
+}
+
+impl<T: AddAssign<T>> AddAssign<Vec3<T>> for Point<T> {
+    fn add_assign(&mut self, rhs: Vec3<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z
+    }
+}
+
+impl<T: SubAssign<T>> SubAssign<Vec3<T>> for Point<T> {
+    fn sub_assign(&mut self, rhs: Vec3<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z
+    }
+}
+
+// prints as (x, y, z)
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// A line segment from `start` to `end`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line<T = f64> {
+    pub start: Point<T>,
+    pub end: Point<T>
+}
+
+impl<T> Line<T> {
+    pub fn new(start: Point<T>, end: Point<T>) -> Line<T> {
+        Line{start, end}
+    }
+}
+
+impl<T: Num> Line<T> {
+    /// The vector from `start` to `end`.
+    pub fn vector(&self) -> Vec3<T> {
+        self.end - self.start
+    }
+
+    /// Squared length of the segment, exact for integer coordinates.
+    pub fn len_sqr(&self) -> T {
+        self.vector().norm_sqr()
+    }
+}
+
+impl<T: Float> Line<T> {
+    /// Euclidean length of the segment.
+    pub fn len(&self) -> T {
+        self.vector().norm()
+    }
+
+    /// The unit vector from `start` towards `end`, or `None` if they coincide.
+    pub fn direction(&self) -> Option<Vec3<T>> {
+        self.vector().normalize()
+    }
+
+    /// The point halfway between `start` and `end`.
+    pub fn midpoint(&self) -> Point<T> {
+        self.start.lerp(self.end, T::from_f64(0.5))
+    }
+
+    /// The point of the segment nearest to `point`.
+    pub fn closest_point(&self, point: Point<T>) -> Point<T> {
+        let d = self.vector();
+        let length_sqr = d.norm_sqr();
+        if length_sqr == T::zero() { return self.start }
+        self.start + d * clamp_unit((point - self.start).dot(d) / length_sqr)
+    }
+
+    /// The nearest pair of points, the first on `self` and the second on `other`. For parallel
+    /// segments the pair is one of many.
+    pub fn closest_points(&self, other: &Line<T>) -> (Point<T>, Point<T>) {
+        // self.start + s·d1 and other.start + t·d2 for s, t in [0, 1], after Ericson's
+        // Real-Time Collision Detection, 5.1.9
+        let (d1, d2, r) = (self.vector(), other.vector(), self.start - other.start);
+        let (a, e, f) = (d1.norm_sqr(), d2.norm_sqr(), d2.dot(r));
+        let zero = T::zero();
+        let (s, t) = if a == zero && e == zero {
+            (zero, zero)
+        } else if a == zero {
+            (zero, clamp_unit(f / e))
+        } else {
+            let c = d1.dot(r);
+            if e == zero {
+                (clamp_unit(-c / a), zero)
+            } else {
+                let b = d1.dot(d2);
+                let denominator = a * e - b * b;
+                // nearest point of the infinite lines, or any point if they are parallel
+                let s = if denominator == zero { zero } else { clamp_unit((b * f - c * e) / denominator) };
+                let t = (b * s + f) / e;
+                if t < zero {
+                    (clamp_unit(-c / a), zero)
+                } else if t > T::one() {
+                    (clamp_unit((b - c) / a), T::one())
+                } else {
+                    (s, t)
+                }
+            }
+        };
+        (self.start + d1 * s, other.start + d2 * t)
+    }
+
+    /// The shortest distance between the two segments; 0 if they cross.
+    pub fn distance(&self, other: &Line<T>) -> T {
+        let (p, q) = self.closest_points(other);
+        p.distance(q)
+    }
+}
+
+fn clamp_unit<T: Float>(t: T) -> T {
+    t.max(T::zero()).min(T::one())
+}
+
+// prints as [start, end]
+impl<T: fmt::Display> fmt::Display for Line<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}]", self.start, self.end)
+    }
+}
+
+/// A point, line string or polygon, as exchanged in WKT and GeoJSON.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry {
+    Point(Point),
+    /// Two or more points joined in order.
+    LineString(Vec<Point>),
+    /// The outer boundary followed by any holes. Every ring is closed, its last point repeating
+    /// its first, so has at least four points.
+    Polygon(Vec<Vec<Point>>)
+}
+
+/// A [`Geometry`] that can't be converted to the requested type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeometryError {
+    /// A different kind of geometry, e.g. a polygon where a point was wanted.
+    WrongKind { expected: &'static str, found: &'static str },
+    /// A line string with other than two points, where a [`Line`] was wanted.
+    PointCount { expected: usize, found: usize },
+    /// A polygon ring with fewer than `min` points, counting the closing point.
+    TooFewPoints { min: usize, found: usize },
+    /// A polygon without even an outer ring.
+    NoRings,
+    /// A polygon with holes, which [`Polygon`] can't represent.
+    Holes,
+    /// A point off the xy-plane, where a plane shape was wanted.
+    NotPlanar,
+    /// An infinite or NaN coordinate, which neither WKT nor GeoJSON can write.
+    NotFinite
+}
+
+impl Geometry {
+    /// The name of the kind of geometry, as written in GeoJSON: `"Point"`, `"LineString"` or
+    /// `"Polygon"`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Geometry::Point(_) => "Point",
+            Geometry::LineString(_) => "LineString",
+            Geometry::Polygon(_) => "Polygon"
+        }
+    }
+
+    /// Whether every point lies on the xy-plane, so the geometry can be written in 2D.
+    pub fn is_planar(&self) -> bool {
+        match self {
+            Geometry::Point(p) => p.z == 0.0,
+            Geometry::LineString(points) => points.iter().all(|p| p.z == 0.0),
+            Geometry::Polygon(rings) => rings.iter().flatten().all(|p| p.z == 0.0)
+        }
+    }
+
+    /// Whether every coordinate is finite, so the geometry can be written.
+    pub fn is_finite(&self) -> bool {
+        let finite = |p: &Point| p.x.is_finite() && p.y.is_finite() && p.z.is_finite();
+        match self {
+            Geometry::Point(p) => finite(p),
+            Geometry::LineString(points) => points.iter().all(finite),
+            Geometry::Polygon(rings) => rings.iter().flatten().all(finite)
+        }
+    }
+}
+
+// the shortest text that parses back to exactly `x`, in exponent form only when very large or small
+fn number(x: f64) -> String {
+    let debug = format!("{:?}", x);
+    if debug.contains('e') { debug } else { x.to_string() }
+}
+
+impl From<Point> for Geometry {
+    fn from(point: Point) -> Geometry {
+        Geometry::Point(point)
+    }
+}
+
+impl From<Line> for Geometry {
+    fn from(line: Line) -> Geometry {
+        Geometry::LineString(vec![line.start, line.end])
+    }
+}
+
+// plane shapes become polygons, closing the ring
+fn ring<I: IntoIterator<Item = (f64, f64)>>(vertices: I) -> Geometry {
+    let mut ring: Vec<Point> = vertices.into_iter().map(|(x, y)| Point::new(x, y, 0.0)).collect();
+    ring.push(ring[0]);
+    Geometry::Polygon(vec![ring])
+}
+
+impl From<Polygon> for Geometry {
+    fn from(polygon: Polygon) -> Geometry {
+        ring(polygon.vertices)
+    }
+}
+
+impl From<Triangle> for Geometry {
+    fn from(triangle: Triangle) -> Geometry {
+        ring(triangle.vertices.to_vec())
+    }
+}
+
+impl From<Rectangle> for Geometry {
+    fn from(rectangle: Rectangle) -> Geometry {
+        ring(rectangle.vertices().to_vec())
+    }
+}
+
+impl From<Square> for Geometry {
+    fn from(square: Square) -> Geometry {
+        ring(square.vertices().to_vec())
+    }
+}
+
+impl From<RegularPolygon> for Geometry {
+    fn from(polygon: RegularPolygon) -> Geometry {
+        ring(polygon.vertices())
+    }
+}
+
+impl TryFrom<Geometry> for Point {
+    type Error = GeometryError;
+    fn try_from(geometry: Geometry) -> Result<Point, GeometryError> {
+        match geometry {
+            Geometry::Point(point) => Ok(point),
+            other => Err(GeometryError::WrongKind{expected: "Point", found: other.kind()})
+        }
+    }
+}
+
+impl TryFrom<Geometry> for Line {
+    type Error = GeometryError;
+    fn try_from(geometry: Geometry) -> Result<Line, GeometryError> {
+        match geometry {
+            Geometry::LineString(points) if points.len() == 2 => Ok(Line::new(points[0], points[1])),
+            Geometry::LineString(points) => Err(GeometryError::PointCount{expected: 2, found: points.len()}),
+            other => Err(GeometryError::WrongKind{expected: "LineString", found: other.kind()})
+        }
+    }
+}
+
+impl TryFrom<Geometry> for Polygon {
+    type Error = GeometryError;
+    fn try_from(geometry: Geometry) -> Result<Polygon, GeometryError> {
+        if !geometry.is_planar() { return Err(GeometryError::NotPlanar) }
+        match geometry {
+            Geometry::Polygon(mut rings) if rings.len() == 1 => {
+                let mut ring = rings.pop().unwrap();
+                // three corners and the closing point, as WKT and GeoJSON require
+                if ring.len() < 4 { return Err(GeometryError::TooFewPoints{min: 4, found: ring.len()}) }
+                if ring.first() == ring.last() { ring.pop(); }
+                Ok(Polygon::new(ring.into_iter().map(|p| (p.x, p.y)).collect()))
+            }
+            Geometry::Polygon(rings) if rings.is_empty() => Err(GeometryError::NoRings),
+            Geometry::Polygon(_) => Err(GeometryError::Holes),
+            other => Err(GeometryError::WrongKind{expected: "Polygon", found: other.kind()})
+        }
+    }
+}
+
+impl fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeometryError::WrongKind{expected, found} => write!(f, "expected a {}, found a {}", expected, found),
+            GeometryError::PointCount{expected, found} => write!(f, "expected {} points, found {}", expected, found),
+            GeometryError::TooFewPoints{min, found} => write!(f, "expected at least {} points, found {}", min, found),
+            GeometryError::NoRings => write!(f, "expected a polygon with at least one ring"),
+            GeometryError::Holes => write!(f, "polygons with holes are not supported"),
+            GeometryError::NotPlanar => write!(f, "expected every z coordinate to be 0"),
+            GeometryError::NotFinite => write!(f, "expected every coordinate to be finite")
+        }
+    }
+}
+
+impl Error for GeometryError {}
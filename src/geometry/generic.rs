@@ -0,0 +1,249 @@
+//! Points and line segments with any coordinate type, in any number of dimensions.
+//!
+//! A [`Point<T, N>`] holds `N` coordinates of type `T`, and what it can do depends on what `T`
+//! can do: any [`Num`] gives exact squared distances, so integer points compare lengths without
+//! rounding, and any [`Float`] gives distances and midpoints. Points change precision with
+//! `From` where no value can be lost (`Point<f32, 3>` into `Point<f64, 3>`), with
+//! [`Point::convert`] for any other lossless `From`, or with [`Point::map`] for everything else,
+//! and change dimension with [`Point::resize`].
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::num::{Float, Num, Zero};
+
+/// A point with `N` coordinates of type `T`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point<T, const N: usize> {
+    pub coords: [T; N]
+}
+
+impl<T, const N: usize> Point<T, N> {
+    pub fn new(coords: [T; N]) -> Point<T, N> {
+        Point{coords}
+    }
+
+    /// The number of coordinates, `N`.
+    pub fn dimension(&self) -> usize {
+        N
+    }
+
+    /// The point with `f` applied to every coordinate, such as `|x| x as f32` to lower the
+    /// precision.
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> Point<U, N> {
+        Point{coords: self.coords.map(f)}
+    }
+
+    /// The same point with coordinates of type `U`, which must hold every value of `T`.
+    pub fn convert<U: From<T>>(self) -> Point<U, N> {
+        self.map(U::from)
+    }
+}
+
+impl<T: Zero, const N: usize> Point<T, N> {
+    /// The point with every coordinate 0.
+    pub fn origin() -> Point<T, N> {
+        Point{coords: [(); N].map(|_| T::zero())}
+    }
+
+    /// The same point in `M` dimensions: dropping the last coordinates when `M` is smaller, and
+    /// adding zeros when it is larger.
+    pub fn resize<const M: usize>(self) -> Point<T, M> {
+        let mut coords = IntoIterator::into_iter(self.coords);
+        Point{coords: [(); M].map(|_| coords.next().unwrap_or_else(T::zero))}
+    }
+}
+
+impl<T: Num + PartialOrd, const N: usize> Point<T, N> {
+    /// Squared Euclidean distance to `other`, exact for integer coordinates, unsigned included.
+    pub fn distance_sqr(&self, other: &Point<T, N>) -> T {
+        self.coords.iter().zip(other.coords.iter()).fold(T::zero(), |sum, (&a, &b)| {
+            // the larger minus the smaller, so unsigned coordinates never go below zero
+            let d = if a > b { a - b } else { b - a };
+            sum + d * d
+        })
+    }
+}
+
+impl<T: Float, const N: usize> Point<T, N> {
+    /// Euclidean distance to `other`.
+    pub fn distance(&self, other: &Point<T, N>) -> T {
+        self.distance_sqr(other).sqrt()
+    }
+
+    /// The point `t` of the way from `self` to `other`: `self` at 0, `other` at 1.
+    pub fn lerp(&self, other: &Point<T, N>, t: T) -> Point<T, N> {
+        let mut coords = self.coords;
+        for (c, &o) in coords.iter_mut().zip(other.coords.iter()) {
+            *c = *c + (o - *c) * t
+        }
+        Point{coords}
+    }
+
+    /// The point halfway between `self` and `other`.
+    pub fn midpoint(&self, other: &Point<T, N>) -> Point<T, N> {
+        self.lerp(other, T::from_f64(0.5))
+    }
+
+    /// Whether no coordinate differs from `other`'s by more than `tolerance`, for comparing
+    /// points that went through different rounding.
+    pub fn approx_eq(&self, other: &Point<T, N>, tolerance: T) -> bool {
+        self.coords.iter().zip(other.coords.iter()).all(|(&a, &b)| (a - b).abs() <= tolerance)
+    }
+}
+
+impl<T, const N: usize> Index<usize> for Point<T, N> {
+    type Output = T;
+    fn index(&self, i: usize) -> &T {
+        &self.coords[i]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for Point<T, N> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.coords[i]
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Point<T, N> {
+    fn from(coords: [T; N]) -> Point<T, N> {
+        Point{coords}
+    }
+}
+
+impl<T, const N: usize> From<Point<T, N>> for [T; N] {
+    fn from(p: Point<T, N>) -> [T; N] {
+        p.coords
+    }
+}
+
+// the 2D points of the shapes module, and back
+impl<T> From<(T, T)> for Point<T, 2> {
+    fn from((x, y): (T, T)) -> Point<T, 2> {
+        Point{coords: [x, y]}
+    }
+}
+
+impl<T> From<Point<T, 2>> for (T, T) {
+    fn from(p: Point<T, 2>) -> (T, T) {
+        let [x, y] = p.coords;
+        (x, y)
+    }
+}
+
+// the 3D points of the geometry module, and back
+impl<T> From<super::Point<T>> for Point<T, 3> {
+    fn from(p: super::Point<T>) -> Point<T, 3> {
+        Point{coords: [p.x, p.y, p.z]}
+    }
+}
+
+impl<T> From<Point<T, 3>> for super::Point<T> {
+    fn from(p: Point<T, 3>) -> super::Point<T> {
+        let [x, y, z] = p.coords;
+        super::Point{x, y, z}
+    }
+}
+
+// widening the coordinates, wherever std converts every value exactly
+macro_rules! widen_impls {
+    ($($from:ty => $to:ty),*) => {$(
+        impl<const N: usize> From<Point<$from, N>> for Point<$to, N> {
+            fn from(p: Point<$from, N>) -> Point<$to, N> {
+                p.convert()
+            }
+        }
+
+        impl<const N: usize> From<Line<$from, N>> for Line<$to, N> {
+            fn from(l: Line<$from, N>) -> Line<$to, N> {
+                l.convert()
+            }
+        }
+    )*}
+}
+
+widen_impls!(f32 => f64, i8 => f64, i16 => f64, i32 => f64, u8 => f64, u16 => f64, u32 => f64,
+             i8 => f32, i16 => f32, u8 => f32, u16 => f32);
+
+// prints as (x, y, ...), honouring a precision such as {:.2} on every coordinate
+impl<T: fmt::Display, const N: usize> fmt::Display for Point<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, c) in self.coords.iter().enumerate() {
+            if i > 0 { write!(f, ", ")? }
+            fmt::Display::fmt(c, f)?
+        }
+        write!(f, ")")
+    }
+}
+
+/// A line segment from `start` to `end` in `N` dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line<T, const N: usize> {
+    pub start: Point<T, N>,
+    pub end: Point<T, N>
+}
+
+impl<T, const N: usize> Line<T, N> {
+    pub fn new(start: Point<T, N>, end: Point<T, N>) -> Line<T, N> {
+        Line{start, end}
+    }
+
+    /// The segment with `f` applied to every coordinate of both ends.
+    pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> Line<U, N> {
+        Line{start: self.start.map(&mut f), end: self.end.map(f)}
+    }
+
+    /// The same segment with coordinates of type `U`, which must hold every value of `T`.
+    pub fn convert<U: From<T>>(self) -> Line<U, N> {
+        self.map(U::from)
+    }
+}
+
+impl<T: Zero, const N: usize> Line<T, N> {
+    /// The same segment in `M` dimensions, resizing both ends as [`Point::resize`] does.
+    pub fn resize<const M: usize>(self) -> Line<T, M> {
+        Line{start: self.start.resize(), end: self.end.resize()}
+    }
+}
+
+impl<T: Num + PartialOrd, const N: usize> Line<T, N> {
+    /// Squared length of the segment, exact for integer coordinates.
+    pub fn len_sqr(&self) -> T {
+        self.start.distance_sqr(&self.end)
+    }
+}
+
+impl<T: Float, const N: usize> Line<T, N> {
+    /// Euclidean length of the segment.
+    pub fn len(&self) -> T {
+        self.start.distance(&self.end)
+    }
+
+    pub fn midpoint(&self) -> Point<T, N> {
+        self.start.midpoint(&self.end)
+    }
+}
+
+impl<T> From<super::Line<T>> for Line<T, 3> {
+    fn from(l: super::Line<T>) -> Line<T, 3> {
+        Line{start: l.start.into(), end: l.end.into()}
+    }
+}
+
+impl<T> From<Line<T, 3>> for super::Line<T> {
+    fn from(l: Line<T, 3>) -> super::Line<T> {
+        super::Line{start: l.start.into(), end: l.end.into()}
+    }
+}
+
+// prints as [start, end]
+impl<T: fmt::Display, const N: usize> fmt::Display for Line<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        fmt::Display::fmt(&self.start, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.end, f)?;
+        write!(f, "]")
+    }
+}
@@ -0,0 +1,414 @@
+//! GeoJSON: `{"type":"Point","coordinates":[1,2]}` and likewise `LineString` and `Polygon`.
+//!
+//! Output is compact, with positions in 2D unless some z coordinate is nonzero. Input may be
+//! any JSON formatting, may give each position two or three coordinates, and may be a
+//! `Feature`, whose `geometry` is read. Coordinates must be finite, as JSON has no way of writing
+//! infinities or NaN.
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+use super::{number, Geometry, GeometryError, Line, Point};
+use crate::shapes::{Polygon, Rectangle, RegularPolygon, Square, Triangle};
+
+/// How deeply arrays and objects may nest. A polygon's coordinates are only three arrays deep
+/// inside a feature's object, but a limit keeps hostile input from exhausting the stack.
+pub const MAX_DEPTH: usize = 64;
+
+/// Conversion to GeoJSON.
+pub trait ToGeoJson {
+    /// Fails with [`GeometryError::NotFinite`] for an infinite or NaN coordinate.
+    fn to_geojson(&self) -> Result<String, GeometryError>;
+}
+
+/// Parsing from GeoJSON.
+pub trait FromGeoJson: Sized {
+    fn from_geojson(input: &str) -> Result<Self, GeoJsonError>;
+}
+
+/// Error returned when a string is not valid GeoJSON for the requested type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeoJsonError {
+    kind: GeoJsonErrorKind,
+    offset: usize
+}
+
+/// What went wrong while parsing GeoJSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeoJsonErrorKind {
+    /// The input ended in the middle of a value.
+    UnexpectedEnd,
+    /// Something else was found where the given token or value was expected, e.g. `"':'"`.
+    Expected(&'static str),
+    /// A number that isn't valid JSON.
+    InvalidNumber,
+    /// A backslash escape that isn't valid JSON.
+    InvalidEscape,
+    /// An object without the named member.
+    MissingMember(&'static str),
+    /// Arrays and objects nested more than [`MAX_DEPTH`] deep.
+    TooDeep,
+    /// A `type` other than `Point`, `LineString`, `Polygon` or `Feature`.
+    UnknownGeometry,
+    /// A position with other than two or three coordinates.
+    InvalidPosition,
+    /// A line string with fewer than two positions, or a ring with fewer than four.
+    TooFewPoints,
+    /// A ring whose last position is not its first.
+    RingNotClosed,
+    /// Something followed the end of the JSON value.
+    TrailingInput,
+    /// The geometry is valid but doesn't fit the requested type.
+    Geometry(GeometryError)
+}
+
+impl GeoJsonError {
+    fn new(kind: GeoJsonErrorKind, offset: usize) -> GeoJsonError {
+        GeoJsonError{kind, offset}
+    }
+
+    pub fn kind(&self) -> GeoJsonErrorKind {
+        self.kind
+    }
+
+    /// Byte offset into the input at which parsing failed.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for GeoJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            GeoJsonErrorKind::UnexpectedEnd => write!(f, "unexpected end of input")?,
+            GeoJsonErrorKind::Expected(what) => write!(f, "expected {}", what)?,
+            GeoJsonErrorKind::InvalidNumber => write!(f, "invalid number")?,
+            GeoJsonErrorKind::InvalidEscape => write!(f, "invalid escape sequence")?,
+            GeoJsonErrorKind::MissingMember(name) => write!(f, "object has no \"{}\" member", name)?,
+            GeoJsonErrorKind::TooDeep => write!(f, "arrays and objects nested more than {} deep", MAX_DEPTH)?,
+            GeoJsonErrorKind::UnknownGeometry => write!(f, "unknown geometry type")?,
+            GeoJsonErrorKind::InvalidPosition => write!(f, "a position must have two or three coordinates")?,
+            GeoJsonErrorKind::TooFewPoints => write!(f, "too few positions")?,
+            GeoJsonErrorKind::RingNotClosed => write!(f, "ring does not end at its first position")?,
+            GeoJsonErrorKind::TrailingInput => write!(f, "unexpected input after the value")?,
+            GeoJsonErrorKind::Geometry(e) => write!(f, "{}", e)?
+        }
+        write!(f, " at byte {}", self.offset)
+    }
+}
+
+impl Error for GeoJsonError {}
+
+// writing
+
+impl ToGeoJson for Geometry {
+    fn to_geojson(&self) -> Result<String, GeometryError> {
+        if !self.is_finite() { return Err(GeometryError::NotFinite) }
+        let z = !self.is_planar();
+        let position = |p: &Point| if z {
+            format!("[{},{},{}]", number(p.x), number(p.y), number(p.z))
+        } else {
+            format!("[{},{}]", number(p.x), number(p.y))
+        };
+        let list = |points: &[Point]| format!("[{}]", points.iter().map(position).collect::<Vec<_>>().join(","));
+        let coordinates = match self {
+            Geometry::Point(p) => position(p),
+            Geometry::LineString(points) => list(points),
+            Geometry::Polygon(rings) => format!("[{}]", rings.iter().map(|ring| list(ring)).collect::<Vec<_>>().join(","))
+        };
+        Ok(format!("{{\"type\":\"{}\",\"coordinates\":{}}}", self.kind(), coordinates))
+    }
+}
+
+// everything else is written through its Geometry
+macro_rules! to_geojson_impls {
+    ($($t:ty)*) => {$(
+        impl ToGeoJson for $t {
+            fn to_geojson(&self) -> Result<String, GeometryError> {
+                Geometry::from(self.clone()).to_geojson()
+            }
+        }
+    )*}
+}
+
+to_geojson_impls!(Point Line Polygon Triangle Rectangle Square RegularPolygon);
+
+// reading
+
+impl FromGeoJson for Geometry {
+    fn from_geojson(input: &str) -> Result<Geometry, GeoJsonError> {
+        geometry(&Parser{input, pos: 0, depth: 0}.parse()?).map(|(geometry, _)| geometry)
+    }
+}
+
+// the concrete types are read as a Geometry, then converted
+macro_rules! from_geojson_impls {
+    ($($t:ty)*) => {$(
+        impl FromGeoJson for $t {
+            fn from_geojson(input: &str) -> Result<$t, GeoJsonError> {
+                let (geometry, start) = geometry(&Parser{input, pos: 0, depth: 0}.parse()?)?;
+                <$t>::try_from(geometry).map_err(|e| GeoJsonError::new(GeoJsonErrorKind::Geometry(e), start))
+            }
+        }
+    )*}
+}
+
+from_geojson_impls!(Point Line Polygon);
+
+// a JSON value and the offset at which it starts
+struct Json {
+    offset: usize,
+    value: Value
+}
+
+enum Value {
+    // null, true or false, none of which a geometry needs
+    Literal,
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>)
+}
+
+impl Json {
+    fn error<T>(&self, kind: GeoJsonErrorKind) -> Result<T, GeoJsonError> {
+        Err(GeoJsonError::new(kind, self.offset))
+    }
+
+    fn member(&self, name: &'static str) -> Result<&Json, GeoJsonError> {
+        match &self.value {
+            Value::Object(members) => members.iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value)
+                .map_or_else(|| self.error(GeoJsonErrorKind::MissingMember(name)), Ok),
+            _ => self.error(GeoJsonErrorKind::Expected("an object"))
+        }
+    }
+
+    fn string(&self) -> Result<&str, GeoJsonError> {
+        match &self.value {
+            Value::String(s) => Ok(s),
+            _ => self.error(GeoJsonErrorKind::Expected("a string"))
+        }
+    }
+
+    fn array(&self) -> Result<&[Json], GeoJsonError> {
+        match &self.value {
+            Value::Array(items) => Ok(items),
+            _ => self.error(GeoJsonErrorKind::Expected("an array"))
+        }
+    }
+
+    fn number(&self) -> Result<f64, GeoJsonError> {
+        match self.value {
+            Value::Number(n) => Ok(n),
+            _ => self.error(GeoJsonErrorKind::Expected("a number"))
+        }
+    }
+
+    fn position(&self) -> Result<Point, GeoJsonError> {
+        let coordinates = self.array()?;
+        if coordinates.len() < 2 || coordinates.len() > 3 { return self.error(GeoJsonErrorKind::InvalidPosition) }
+        let z = match coordinates.get(2) { Some(z) => z.number()?, None => 0.0 };
+        Ok(Point::new(coordinates[0].number()?, coordinates[1].number()?, z))
+    }
+
+    fn positions(&self, min: usize) -> Result<Vec<Point>, GeoJsonError> {
+        let positions = self.array()?.iter().map(Json::position).collect::<Result<Vec<_>, _>>()?;
+        if positions.len() < min { return self.error(GeoJsonErrorKind::TooFewPoints) }
+        Ok(positions)
+    }
+}
+
+// the geometry of a geometry object or feature, and the offset of its object
+fn geometry(json: &Json) -> Result<(Geometry, usize), GeoJsonError> {
+    let kind = json.member("type")?;
+    if kind.string()? == "Feature" {
+        return geometry(json.member("geometry")?)
+    }
+    let coordinates = json.member("coordinates")?;
+    let geometry = match kind.string()? {
+        "Point" => Geometry::Point(coordinates.position()?),
+        "LineString" => Geometry::LineString(coordinates.positions(2)?),
+        "Polygon" => {
+            let mut rings = Vec::new();
+            for ring in coordinates.array()? {
+                let points = ring.positions(4)?;
+                if points.first() != points.last() { return ring.error(GeoJsonErrorKind::RingNotClosed) }
+                rings.push(points)
+            }
+            if rings.is_empty() { return coordinates.error(GeoJsonErrorKind::TooFewPoints) }
+            Geometry::Polygon(rings)
+        }
+        _ => return kind.error(GeoJsonErrorKind::UnknownGeometry)
+    };
+    Ok((geometry, json.offset))
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    // how many arrays and objects the parser is inside
+    depth: usize
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let trimmed = self.rest().trim_start_matches([' ', '\t', '\n', '\r']);
+        self.pos = self.input.len() - trimmed.len();
+    }
+
+    fn error<T>(&self, kind: GeoJsonErrorKind) -> Result<T, GeoJsonError> {
+        let kind = if self.rest().is_empty() { GeoJsonErrorKind::UnexpectedEnd } else { kind };
+        Err(GeoJsonError::new(kind, self.pos))
+    }
+
+    fn expect(&mut self, c: char, what: &'static str) -> Result<(), GeoJsonError> {
+        self.skip_whitespace();
+        if self.rest().starts_with(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            self.error(GeoJsonErrorKind::Expected(what))
+        }
+    }
+
+    fn parse(mut self) -> Result<Json, GeoJsonError> {
+        let json = self.value()?;
+        self.skip_whitespace();
+        if !self.rest().is_empty() {
+            return Err(GeoJsonError::new(GeoJsonErrorKind::TrailingInput, self.pos))
+        }
+        Ok(json)
+    }
+
+    fn value(&mut self) -> Result<Json, GeoJsonError> {
+        self.skip_whitespace();
+        let offset = self.pos;
+        let value = match self.rest().chars().next() {
+            Some('{') | Some('[') if self.depth == MAX_DEPTH => return self.error(GeoJsonErrorKind::TooDeep),
+            Some('{') => Value::Object(self.sequence('}', "',' or '}'", |parser| {
+                parser.skip_whitespace();
+                let key = parser.string()?;
+                parser.expect(':', "':'")?;
+                Ok((key, parser.value()?))
+            })?),
+            Some('[') => Value::Array(self.sequence(']', "',' or ']'", Parser::value)?),
+            Some('"') => Value::String(self.string()?),
+            Some('-') | Some('0'..='9') => Value::Number(self.number()?),
+            _ => {
+                let word = self.rest().chars().take_while(|c| c.is_ascii_alphabetic()).collect::<String>();
+                if !["null", "true", "false"].contains(&word.as_str()) {
+                    return self.error(GeoJsonErrorKind::Expected("a value"))
+                }
+                self.pos += word.len();
+                Value::Literal
+            }
+        };
+        Ok(Json{offset, value})
+    }
+
+    // the items of an array or object, after its opening bracket
+    fn sequence<T, F>(&mut self, close: char, separator: &'static str, mut item: F) -> Result<Vec<T>, GeoJsonError>
+        where F: FnMut(&mut Parser<'a>) -> Result<T, GeoJsonError>
+    {
+        self.pos += 1;
+        self.depth += 1;
+        self.skip_whitespace();
+        let mut items = Vec::new();
+        if self.rest().starts_with(close) {
+            self.pos += 1;
+            self.depth -= 1;
+            return Ok(items)
+        }
+        loop {
+            items.push(item(self)?);
+            self.skip_whitespace();
+            match self.rest().chars().next() {
+                Some(',') => self.pos += 1,
+                Some(c) if c == close => break,
+                _ => return self.error(GeoJsonErrorKind::Expected(separator))
+            }
+        }
+        self.pos += 1;
+        self.depth -= 1;
+        Ok(items)
+    }
+
+    fn string(&mut self) -> Result<String, GeoJsonError> {
+        if !self.rest().starts_with('"') { return self.error(GeoJsonErrorKind::Expected("a string")) }
+        self.pos += 1;
+        let mut string = String::new();
+        loop {
+            let c = match self.rest().chars().next() {
+                Some(c) => c,
+                None => return self.error(GeoJsonErrorKind::UnexpectedEnd)
+            };
+            match c {
+                '"' => { self.pos += 1; return Ok(string) },
+                '\\' => {
+                    let escape = &self.rest()[1..];
+                    let (c, len) = match escape.chars().next() {
+                        Some('"') => ('"', 1),
+                        Some('\\') => ('\\', 1),
+                        Some('/') => ('/', 1),
+                        Some('b') => ('\u{8}', 1),
+                        Some('f') => ('\u{c}', 1),
+                        Some('n') => ('\n', 1),
+                        Some('r') => ('\r', 1),
+                        Some('t') => ('\t', 1),
+                        // surrogate pairs aren't joined; they only matter in member names here
+                        Some('u') => match escape.get(1..5).and_then(|hex| u32::from_str_radix(hex, 16).ok()) {
+                            Some(code) => (char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER), 5),
+                            None => return self.error(GeoJsonErrorKind::InvalidEscape)
+                        },
+                        _ => return self.error(GeoJsonErrorKind::InvalidEscape)
+                    };
+                    string.push(c);
+                    self.pos += 1 + len
+                }
+                c if c < ' ' => return self.error(GeoJsonErrorKind::Expected("'\"'")),
+                c => { string.push(c); self.pos += c.len_utf8() }
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<f64, GeoJsonError> {
+        let len = self.rest().find(|c: char| !(c.is_ascii_digit() || "+-.eE".contains(c))).unwrap_or(self.rest().len());
+        let text = &self.rest()[..len];
+        if !is_json_number(text) { return self.error(GeoJsonErrorKind::InvalidNumber) }
+        // a valid JSON number can still overflow to infinity, as 1e400 does
+        let number = text.parse().ok().filter(|x: &f64| x.is_finite());
+        let number = number.map_or_else(|| self.error(GeoJsonErrorKind::InvalidNumber), Ok)?;
+        self.pos += len;
+        Ok(number)
+    }
+}
+
+// -?(0|[1-9][0-9]*)(\.[0-9]+)?([eE][+-]?[0-9]+)?
+fn is_json_number(text: &str) -> bool {
+    let digits = |s: &str| s.len() - s.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let mut s = text.strip_prefix('-').unwrap_or(text);
+    match digits(s) {
+        0 => return false,
+        n if n > 1 && s.starts_with('0') => return false,
+        n => s = &s[n..]
+    }
+    if let Some(fraction) = s.strip_prefix('.') {
+        let n = digits(fraction);
+        if n == 0 { return false }
+        s = &fraction[n..]
+    }
+    if let Some(exponent) = s.strip_prefix(|c| c == 'e' || c == 'E') {
+        let exponent = exponent.strip_prefix(|c| c == '+' || c == '-').unwrap_or(exponent);
+        let n = digits(exponent);
+        if n == 0 { return false }
+        s = &exponent[n..]
+    }
+    s.is_empty()
+}
@@ -0,0 +1,303 @@
+//! Well-Known Text: `POINT (1 2)`, `LINESTRING (0 0, 1 1)`, `POLYGON ((0 0, 1 0, 0 1, 0 0))`.
+//!
+//! Keywords are case-insensitive on input and upper case on output. Points are written in 2D
+//! unless some z coordinate is nonzero, in which case the whole geometry is written with `Z`.
+//! Input may omit the `Z` and give three coordinates anyway, as older tools do, as long as every
+//! point has the same number. Numbers are written in full, so writing and parsing again gives
+//! back exactly the same values; coordinates must be finite both ways.
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+use super::{number, Geometry, GeometryError, Line, Point};
+use crate::shapes::{Polygon, Rectangle, RegularPolygon, Square, Triangle};
+
+/// Conversion to Well-Known Text.
+pub trait ToWkt {
+    /// Fails with [`GeometryError::NotFinite`] for an infinite or NaN coordinate.
+    fn to_wkt(&self) -> Result<String, GeometryError>;
+}
+
+/// Parsing from Well-Known Text.
+pub trait FromWkt: Sized {
+    fn from_wkt(input: &str) -> Result<Self, WktError>;
+}
+
+/// Error returned when a string is not valid WKT for the requested type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WktError {
+    kind: WktErrorKind,
+    offset: usize
+}
+
+/// What went wrong while parsing WKT.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WktErrorKind {
+    /// The input ended in the middle of a geometry.
+    UnexpectedEnd,
+    /// Something else was found where the given token was expected, e.g. `"'('"`.
+    Expected(&'static str),
+    /// A coordinate was not a valid number.
+    InvalidNumber,
+    /// The geometry type is not one of `POINT`, `LINESTRING` or `POLYGON`.
+    UnknownGeometry,
+    /// Valid WKT that can't be represented: `EMPTY` geometries and `M` coordinates.
+    Unsupported,
+    /// A point with more than three coordinates, or a different number from the ones before it
+    /// or from what `Z` promised.
+    DimensionMismatch,
+    /// A line string with fewer than two points, or a ring with fewer than four.
+    TooFewPoints,
+    /// A ring whose last point is not its first.
+    RingNotClosed,
+    /// Something followed the end of the geometry.
+    TrailingInput,
+    /// The geometry is valid but doesn't fit the requested type.
+    Geometry(GeometryError)
+}
+
+impl WktError {
+    fn new(kind: WktErrorKind, offset: usize) -> WktError {
+        WktError{kind, offset}
+    }
+
+    pub fn kind(&self) -> WktErrorKind {
+        self.kind
+    }
+
+    /// Byte offset into the input at which parsing failed.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for WktError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            WktErrorKind::UnexpectedEnd => write!(f, "unexpected end of input")?,
+            WktErrorKind::Expected(what) => write!(f, "expected {}", what)?,
+            WktErrorKind::InvalidNumber => write!(f, "invalid number")?,
+            WktErrorKind::UnknownGeometry => write!(f, "unknown geometry type")?,
+            WktErrorKind::Unsupported => write!(f, "unsupported geometry")?,
+            WktErrorKind::DimensionMismatch => write!(f, "point has the wrong number of coordinates")?,
+            WktErrorKind::TooFewPoints => write!(f, "too few points")?,
+            WktErrorKind::RingNotClosed => write!(f, "ring does not end at its first point")?,
+            WktErrorKind::TrailingInput => write!(f, "unexpected input after the geometry")?,
+            WktErrorKind::Geometry(e) => write!(f, "{}", e)?
+        }
+        write!(f, " at byte {}", self.offset)
+    }
+}
+
+impl Error for WktError {}
+
+// writing
+
+impl ToWkt for Geometry {
+    fn to_wkt(&self) -> Result<String, GeometryError> {
+        if !self.is_finite() { return Err(GeometryError::NotFinite) }
+        let z = !self.is_planar();
+        let coordinates = |p: &Point| if z {
+            format!("{} {} {}", number(p.x), number(p.y), number(p.z))
+        } else {
+            format!("{} {}", number(p.x), number(p.y))
+        };
+        let list = |points: &[Point]| points.iter().map(coordinates).collect::<Vec<_>>().join(", ");
+        let body = match self {
+            Geometry::Point(p) => format!("({})", coordinates(p)),
+            Geometry::LineString(points) => format!("({})", list(points)),
+            Geometry::Polygon(rings) => {
+                let rings: Vec<String> = rings.iter().map(|ring| format!("({})", list(ring))).collect();
+                format!("({})", rings.join(", "))
+            }
+        };
+        Ok(format!("{}{} {}", self.kind().to_uppercase(), if z { " Z" } else { "" }, body))
+    }
+}
+
+// everything else is written through its Geometry
+macro_rules! to_wkt_impls {
+    ($($t:ty)*) => {$(
+        impl ToWkt for $t {
+            fn to_wkt(&self) -> Result<String, GeometryError> {
+                Geometry::from(self.clone()).to_wkt()
+            }
+        }
+    )*}
+}
+
+to_wkt_impls!(Point Line Polygon Triangle Rectangle Square RegularPolygon);
+
+// reading
+
+impl FromWkt for Geometry {
+    fn from_wkt(input: &str) -> Result<Geometry, WktError> {
+        Parser{input, pos: 0}.parse().map(|(geometry, _)| geometry)
+    }
+}
+
+// the concrete types are read as a Geometry, then converted
+macro_rules! from_wkt_impls {
+    ($($t:ty)*) => {$(
+        impl FromWkt for $t {
+            fn from_wkt(input: &str) -> Result<$t, WktError> {
+                let (geometry, start) = Parser{input, pos: 0}.parse()?;
+                <$t>::try_from(geometry).map_err(|e| WktError::new(WktErrorKind::Geometry(e), start))
+            }
+        }
+    )*}
+}
+
+from_wkt_impls!(Point Line Polygon);
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let trimmed = self.rest().trim_start();
+        self.pos = self.input.len() - trimmed.len();
+    }
+
+    fn error<T>(&self, kind: WktErrorKind) -> Result<T, WktError> {
+        let kind = if self.rest().is_empty() && kind != WktErrorKind::TrailingInput { WktErrorKind::UnexpectedEnd } else { kind };
+        Err(WktError::new(kind, self.pos))
+    }
+
+    // the next run of letters, upper-cased, without consuming it
+    fn peek_word(&self) -> String {
+        self.rest().chars().take_while(|c| c.is_ascii_alphabetic()).collect::<String>().to_uppercase()
+    }
+
+    fn expect(&mut self, c: char, what: &'static str) -> Result<(), WktError> {
+        self.skip_whitespace();
+        if self.rest().starts_with(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            self.error(WktErrorKind::Expected(what))
+        }
+    }
+
+    // the geometry and the offset at which it starts
+    fn parse(mut self) -> Result<(Geometry, usize), WktError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let kind = self.peek_word();
+        if kind.is_empty() { return self.error(WktErrorKind::Expected("a geometry type")) }
+        if !["POINT", "LINESTRING", "POLYGON"].contains(&kind.as_str()) {
+            return self.error(WktErrorKind::UnknownGeometry)
+        }
+        self.pos += kind.len();
+
+        self.skip_whitespace();
+        let dimension = match self.peek_word().as_str() {
+            "Z" => { self.pos += 1; Some(3) },
+            "M" | "ZM" => return self.error(WktErrorKind::Unsupported),
+            "EMPTY" => return self.error(WktErrorKind::Unsupported),
+            _ => None
+        };
+        if dimension.is_some() {
+            self.skip_whitespace();
+            if self.peek_word() == "EMPTY" { return self.error(WktErrorKind::Unsupported) }
+        }
+
+        let mut points = Points{dimension};
+        let geometry = match kind.as_str() {
+            "POINT" => {
+                self.expect('(', "'('")?;
+                let point = self.point(&mut points)?;
+                self.expect(')', "')'")?;
+                Geometry::Point(point)
+            }
+            "LINESTRING" => Geometry::LineString(self.point_list(&mut points, 2)?),
+            _ => {
+                self.expect('(', "'('")?;
+                let mut rings = Vec::new();
+                loop {
+                    self.skip_whitespace();
+                    let at = self.pos;
+                    let ring = self.point_list(&mut points, 4)?;
+                    if ring.first() != ring.last() {
+                        return Err(WktError::new(WktErrorKind::RingNotClosed, at))
+                    }
+                    rings.push(ring);
+                    if !self.separator("',' or ')'")? { break }
+                }
+                Geometry::Polygon(rings)
+            }
+        };
+
+        self.skip_whitespace();
+        if !self.rest().is_empty() { return self.error(WktErrorKind::TrailingInput) }
+        Ok((geometry, start))
+    }
+
+    // "(x y, x y, ...)" with at least `min` points
+    fn point_list(&mut self, points: &mut Points, min: usize) -> Result<Vec<Point>, WktError> {
+        self.expect('(', "'('")?;
+        let start = self.pos - 1;
+        let mut list = Vec::new();
+        loop {
+            list.push(self.point(points)?);
+            if !self.separator("',' or ')'")? { break }
+        }
+        if list.len() < min { return Err(WktError::new(WktErrorKind::TooFewPoints, start)) }
+        Ok(list)
+    }
+
+    // true after a comma, false after a closing parenthesis
+    fn separator(&mut self, what: &'static str) -> Result<bool, WktError> {
+        self.skip_whitespace();
+        match self.rest().chars().next() {
+            Some(',') => { self.pos += 1; Ok(true) },
+            Some(')') => { self.pos += 1; Ok(false) },
+            _ => self.error(WktErrorKind::Expected(what))
+        }
+    }
+
+    fn point(&mut self, points: &mut Points) -> Result<Point, WktError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let mut coordinates = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let len = self.rest().find(|c: char| c.is_whitespace() || c == ',' || c == '(' || c == ')')
+                .unwrap_or(self.rest().len());
+            if len == 0 { break }
+            let text = &self.rest()[..len];
+            // Rust reads inf and NaN, and overflows to infinity, none of which can be written back
+            let value = text.parse().ok().filter(|x: &f64| x.is_finite());
+            let value = value.map_or_else(|| self.error(WktErrorKind::InvalidNumber), Ok)?;
+            coordinates.push(value);
+            self.pos += len
+        }
+
+        match (coordinates.len(), points.dimension) {
+            (0, _) => self.error(WktErrorKind::Expected("a number")),
+            (1, _) => self.error(WktErrorKind::Expected("another coordinate")),
+            (n, None) if n <= 3 => {
+                points.dimension = Some(n);
+                Ok(point(&coordinates))
+            }
+            (n, Some(d)) if n == d => Ok(point(&coordinates)),
+            _ => Err(WktError::new(WktErrorKind::DimensionMismatch, start))
+        }
+    }
+}
+
+// the number of coordinates every point must have, once known
+struct Points {
+    dimension: Option<usize>
+}
+
+fn point(coordinates: &[f64]) -> Point {
+    Point::new(coordinates[0], coordinates[1], coordinates.get(2).copied().unwrap_or(0.0))
+}
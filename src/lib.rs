@@ -0,0 +1,23 @@
+//! Reusable types from the Rust Programming Language notes.
+//!
+//! The chapter demos in the binary are built on top of these modules, so anything shown
+//! there (complex numbers, vectors, points and lines, shapes, colours, animals) can also be used
+//! from other crates. Larger pieces built on them, such as the fractal renderer, live here too.
+
+pub mod animals;
+pub mod color;
+pub mod complex;
+pub mod fft;
+pub mod fractal;
+pub mod geometry;
+pub mod math;
+pub mod matrix;
+pub mod num;
+pub mod polynomial;
+pub mod quaternion;
+pub mod random;
+pub mod shapes;
+pub mod statistics;
+pub mod summable;
+pub mod svg;
+pub mod vector;
@@ -2,6 +2,7 @@
 #![allow(unused_variables)]
 #![allow(unused_assignments)]
 #![allow(unused_mut)]
+#![allow(clippy::disallowed_names)]
 
 // Ownership
 fn ownership() {
@@ -134,7 +135,7 @@ fn lifetime_in_structure_impl() {
 
 // Reference counted variables
 fn rc_variables() {
-    println!("----- 7.4. Reference counted variables -----");
+    println!("----- 7.5. Reference counted variables -----");
 
     // alternative to borrowing and ownership -- reference counted variables
     use std::rc::Rc;
@@ -238,13 +239,24 @@ fn mutex() {
     t.join().unwrap();
 }
 
+// Chapter registry
+use crate::registry::{Chapter, Section};
+
+pub const CHAPTER: Chapter = Chapter {
+    number: 7,
+    key: "lifetime_and_memory",
+    title: "Lifetime and Memory",
+    sections: &[
+        Section { number: 1, name: "Ownership", run: ownership },
+        Section { number: 2, name: "Borrowing", run: borrowing },
+        Section { number: 3, name: "Lifetime", run: lifetime },
+        Section { number: 4, name: "Lifetime in structure implementation", run: lifetime_in_structure_impl },
+        Section { number: 5, name: "Reference counted variables", run: rc_variables },
+        Section { number: 6, name: "Atomic reference counted variables", run: atomic_rc_variables },
+        Section { number: 7, name: "Using a Mutex for thread-safe mutability", run: mutex }
+    ]
+};
+
 pub fn main() {
-    println!("----- 7. Lifetime and Memory -----");
-    ownership();
-    borrowing();
-    lifetime();
-    lifetime_in_structure_impl();
-    rc_variables();
-    atomic_rc_variables();
-    mutex();
-}
\ No newline at end of file
+    CHAPTER.run()
+}
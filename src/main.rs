@@ -10,18 +10,88 @@ mod data_structures;
 mod functions;
 mod traits;
 mod lifetime_and_memory;
+mod showcase;
+mod registry;
+mod render;
+
+use std::env;
+use std::process;
+use registry::{Entry, CHAPTERS};
 
 const MEANING_OF_LIFE:u8 = 42; // no fixed address
 static CONST_VAR:u16 = 666; // fixed address, immutable
 static mut MUTABLE_VAR:u8 = 23; // fixed address, mutable (unsafe)
 
+const USAGE: &str = "\
+usage: RustProgrammingLanguage <command>
+
+commands:
+  list                     list every chapter and subsection
+  run <selector>...        run chapters or subsections, e.g. `run 4.10 6.5` or `run traits hashmap`
+  run --all                run every chapter in order
+  fractal [options]        render a Mandelbrot or Julia set (see `fractal --help`)
+  help                     print this message
+
+a selector is a chapter number (4), a section number (4.10),
+a chapter module name (data_structures) or a chapter/section title (\"Hashmap\")";
+
 // main function executes when program is run
 fn main() {
-    //ownership::main();
-    //types_and_variables::main();
-    //control_flow::main();
-    //data_structures::main();
-    //functions::main();
-    //traits::main();
-    lifetime_and_memory::main();
-}
\ No newline at end of file
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("list") => list(),
+        Some("run") => run(&args[1..]),
+        Some("fractal") if args[1..].iter().any(|a| a == "--help" || a == "-h") => println!("{}", render::USAGE),
+        Some("fractal") => if let Err(message) = render::fractal(&args[1..]) {
+            eprintln!("{}\n\n{}", message, render::USAGE);
+            process::exit(2)
+        },
+        Some("help") | Some("--help") | Some("-h") | None => println!("{}", USAGE),
+        Some(other) => {
+            eprintln!("unknown command '{}'\n\n{}", other, USAGE);
+            process::exit(2)
+        }
+    }
+}
+
+// prints the table of contents
+fn list() {
+    for chapter in CHAPTERS.iter() {
+        println!("{}", chapter);
+        for section in chapter.sections {
+            println!("  {:<6}{}", chapter.section_id(section), section.name)
+        }
+    }
+}
+
+// resolves every selector before running anything, so a typo doesn't leave a half-finished run
+fn run(selectors: &[String]) {
+    if selectors.iter().any(|s| s == "--all") {
+        if selectors.len() > 1 {
+            eprintln!("--all runs every chapter, so give it alone\n\n{}", USAGE);
+            process::exit(2)
+        }
+        CHAPTERS.iter().for_each(|chapter| chapter.run());
+        return
+    }
+    if selectors.is_empty() {
+        eprintln!("nothing to run: give at least one selector or --all\n\n{}", USAGE);
+        process::exit(2)
+    }
+
+    let mut entries: Vec<Entry> = Vec::new();
+    let mut unknown: Vec<&str> = Vec::new();
+    for selector in selectors {
+        let found = registry::lookup(selector);
+        if found.is_empty() { unknown.push(selector) }
+        entries.extend(found)
+    }
+    if !unknown.is_empty() {
+        eprintln!("unknown section(s): {} (see `list`)", unknown.join(", "));
+        process::exit(2)
+    }
+
+    for entry in entries {
+        entry.run()
+    }
+}
@@ -0,0 +1,9 @@
+//! Small arithmetic helpers.
+
+use crate::num::Num;
+
+/// Divides `x` by `y`, returning `None` instead of infinity, NaN or a panic when `y` is zero.
+pub fn safe_div<T: Num>(x: T, y: T) -> Option<T> {
+    if y.is_zero() { None }
+    else { Some(x / y) }
+}
@@ -0,0 +1,360 @@
+//! 3×3 and 4×4 matrices for transforming points in 3D space.
+//!
+//! Matrices act on column vectors, so `a * b` applied to a point applies `b` first and then `a`.
+//! A [`Mat3`] is a linear map about the origin: rotation and scaling. A [`Mat4`] works in
+//! homogeneous coordinates, so it can also translate and project. Multiplying a [`Point`],
+//! [`Line`] or [`Vec3`] by either kind of matrix transforms it; a `Vec3` is a direction, so a
+//! `Mat4` doesn't translate it.
+
+use std::fmt;
+use std::ops::Mul;
+
+use crate::geometry::{Line, Point};
+use crate::num::{Float, Num};
+use crate::quaternion::Quaternion;
+use crate::vector::Vec3;
+
+/// A 3×3 matrix, stored by rows.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat3<T = f64> {
+    pub rows: [[T; 3]; 3]
+}
+
+/// A 4×4 matrix, stored by rows.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat4<T = f64> {
+    pub rows: [[T; 4]; 4]
+}
+
+impl<T> Mat3<T> {
+    pub fn new(rows: [[T; 3]; 3]) -> Mat3<T> {
+        Mat3{rows}
+    }
+}
+
+impl<T> Mat4<T> {
+    pub fn new(rows: [[T; 4]; 4]) -> Mat4<T> {
+        Mat4{rows}
+    }
+}
+
+impl<T: Num> Mat3<T> {
+    pub fn identity() -> Mat3<T> {
+        Mat3::from_scale(Vec3::new(T::one(), T::one(), T::one()))
+    }
+
+    /// Scaling by `s.x`, `s.y` and `s.z` along the axes.
+    pub fn from_scale(s: Vec3<T>) -> Mat3<T> {
+        let o = T::zero();
+        Mat3{rows: [[s.x, o, o], [o, s.y, o], [o, o, s.z]]}
+    }
+
+    pub fn transpose(&self) -> Mat3<T> {
+        let m = &self.rows;
+        Mat3{rows: [[m[0][0], m[1][0], m[2][0]], [m[0][1], m[1][1], m[2][1]], [m[0][2], m[1][2], m[2][2]]]}
+    }
+
+    pub fn determinant(&self) -> T {
+        let m = &self.rows;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+}
+
+impl<T: Float> Mat3<T> {
+    /// The rotation by `angle` radians about `axis`, anticlockwise when looking back along the
+    /// axis. A zero axis gives no rotation.
+    pub fn from_axis_angle(axis: Vec3<T>, angle: T) -> Mat3<T> {
+        Quaternion::from_axis_angle(axis, angle).into()
+    }
+
+    /// The inverse transform, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Mat3<T>> {
+        let det = self.determinant();
+        if det == T::zero() { return None }
+        // the transposed matrix of cofactors, over the determinant
+        let m = &self.rows;
+        let cofactor = |i: usize, j: usize| {
+            let (r1, r2) = ((i + 1) % 3, (i + 2) % 3);
+            let (c1, c2) = ((j + 1) % 3, (j + 2) % 3);
+            m[r1][c1] * m[r2][c2] - m[r1][c2] * m[r2][c1]
+        };
+        let mut rows = [[T::zero(); 3]; 3];
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                *x = cofactor(j, i) / det
+            }
+        }
+        Some(Mat3{rows})
+    }
+}
+
+impl<T: Num> Mat4<T> {
+    pub fn identity() -> Mat4<T> {
+        Mat4::from_scale(Vec3::new(T::one(), T::one(), T::one()))
+    }
+
+    /// Moving every point by `v`.
+    pub fn from_translation(v: Vec3<T>) -> Mat4<T> {
+        let mut m = Mat4::identity();
+        m.rows[0][3] = v.x;
+        m.rows[1][3] = v.y;
+        m.rows[2][3] = v.z;
+        m
+    }
+
+    /// Scaling by `s.x`, `s.y` and `s.z` along the axes.
+    pub fn from_scale(s: Vec3<T>) -> Mat4<T> {
+        Mat3::from_scale(s).into()
+    }
+
+    pub fn transpose(&self) -> Mat4<T> {
+        let mut rows = self.rows;
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                *x = self.rows[j][i]
+            }
+        }
+        Mat4{rows}
+    }
+
+    pub fn determinant(&self) -> T {
+        let (s, c) = self.minors();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    // the 2×2 determinants of the top two rows and of the bottom two, which between them give
+    // the determinant and every cofactor
+    fn minors(&self) -> ([T; 6], [T; 6]) {
+        let m = &self.rows;
+        let top = |j: usize, k: usize| m[0][j] * m[1][k] - m[1][j] * m[0][k];
+        let bottom = |j: usize, k: usize| m[2][j] * m[3][k] - m[3][j] * m[2][k];
+        ([top(0, 1), top(0, 2), top(0, 3), top(1, 2), top(1, 3), top(2, 3)],
+         [bottom(0, 1), bottom(0, 2), bottom(0, 3), bottom(1, 2), bottom(1, 3), bottom(2, 3)])
+    }
+}
+
+impl<T: Float> Mat4<T> {
+    /// The rotation by `angle` radians about `axis` through the origin, anticlockwise when
+    /// looking back along the axis. A zero axis gives no rotation.
+    pub fn from_axis_angle(axis: Vec3<T>, angle: T) -> Mat4<T> {
+        Mat3::from_axis_angle(axis, angle).into()
+    }
+
+    /// A right-handed perspective projection looking down the negative z-axis, as in OpenGL.
+    /// `fov_y` is the vertical field of view in radians and `aspect` the width over the height;
+    /// the view between the planes `z = -near` and `z = -far` maps to the cube from -1 to 1.
+    pub fn perspective(fov_y: T, aspect: T, near: T, far: T) -> Mat4<T> {
+        let two = T::one() + T::one();
+        let f = T::one() / (fov_y / two).tan();
+        let o = T::zero();
+        Mat4{rows: [
+            [f / aspect, o, o, o],
+            [o, f, o, o],
+            [o, o, (far + near) / (near - far), two * far * near / (near - far)],
+            [o, o, -T::one(), o]
+        ]}
+    }
+
+    /// A right-handed orthographic projection looking down the negative z-axis, as in OpenGL:
+    /// the box from `(left, bottom, -near)` to `(right, top, -far)` maps to the cube from -1 to 1.
+    pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Mat4<T> {
+        let two = T::one() + T::one();
+        let o = T::zero();
+        Mat4{rows: [
+            [two / (right - left), o, o, -(right + left) / (right - left)],
+            [o, two / (top - bottom), o, -(top + bottom) / (top - bottom)],
+            [o, o, -two / (far - near), -(far + near) / (far - near)],
+            [o, o, o, T::one()]
+        ]}
+    }
+
+    /// The view from `eye` towards `target`, with `up` pointing up the screen: it moves the eye
+    /// to the origin looking down the negative z-axis, ready for a projection. `None` if the eye
+    /// is at the target or looking along `up`.
+    pub fn look_at(eye: Point<T>, target: Point<T>, up: Vec3<T>) -> Option<Mat4<T>> {
+        let forward = (target - eye).normalize()?;
+        let right = forward.cross(up).normalize()?;
+        let up = right.cross(forward);
+        let eye = Vec3::from(eye);
+        let o = T::zero();
+        Some(Mat4{rows: [
+            [right.x, right.y, right.z, -right.dot(eye)],
+            [up.x, up.y, up.z, -up.dot(eye)],
+            [-forward.x, -forward.y, -forward.z, forward.dot(eye)],
+            [o, o, o, T::one()]
+        ]})
+    }
+
+    /// The inverse transform, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Mat4<T>> {
+        let det = self.determinant();
+        if det == T::zero() { return None }
+        let m = &self.rows;
+        let (s, c) = self.minors();
+        let adjugate = [
+            [m[1][1] * c[5] - m[1][2] * c[4] + m[1][3] * c[3],
+             -m[0][1] * c[5] + m[0][2] * c[4] - m[0][3] * c[3],
+             m[3][1] * s[5] - m[3][2] * s[4] + m[3][3] * s[3],
+             -m[2][1] * s[5] + m[2][2] * s[4] - m[2][3] * s[3]],
+            [-m[1][0] * c[5] + m[1][2] * c[2] - m[1][3] * c[1],
+             m[0][0] * c[5] - m[0][2] * c[2] + m[0][3] * c[1],
+             -m[3][0] * s[5] + m[3][2] * s[2] - m[3][3] * s[1],
+             m[2][0] * s[5] - m[2][2] * s[2] + m[2][3] * s[1]],
+            [m[1][0] * c[4] - m[1][1] * c[2] + m[1][3] * c[0],
+             -m[0][0] * c[4] + m[0][1] * c[2] - m[0][3] * c[0],
+             m[3][0] * s[4] - m[3][1] * s[2] + m[3][3] * s[0],
+             -m[2][0] * s[4] + m[2][1] * s[2] - m[2][3] * s[0]],
+            [-m[1][0] * c[3] + m[1][1] * c[1] - m[1][2] * c[0],
+             m[0][0] * c[3] - m[0][1] * c[1] + m[0][2] * c[0],
+             -m[3][0] * s[3] + m[3][1] * s[1] - m[3][2] * s[0],
+             m[2][0] * s[3] - m[2][1] * s[1] + m[2][2] * s[0]]
+        ];
+        Some(Mat4{rows: adjugate.map(|row| row.map(|x| x / det))})
+    }
+}
+
+// a rotation or scaling, with no translation
+impl<T: Num> From<Mat3<T>> for Mat4<T> {
+    fn from(m: Mat3<T>) -> Mat4<T> {
+        let (o, i) = (T::zero(), T::one());
+        let r = &m.rows;
+        Mat4{rows: [
+            [r[0][0], r[0][1], r[0][2], o],
+            [r[1][0], r[1][1], r[1][2], o],
+            [r[2][0], r[2][1], r[2][2], o],
+            [o, o, o, i]
+        ]}
+    }
+}
+
+// the rotation of a unit quaternion
+impl<T: Num> From<Quaternion<T>> for Mat3<T> {
+    fn from(q: Quaternion<T>) -> Mat3<T> {
+        let (w, x, y, z) = (q.w, q.x, q.y, q.z);
+        let (i, two) = (T::one(), T::one() + T::one());
+        Mat3{rows: [
+            [i - two * (y * y + z * z), two * (x * y - w * z), two * (x * z + w * y)],
+            [two * (x * y + w * z), i - two * (x * x + z * z), two * (y * z - w * x)],
+            [two * (x * z - w * y), two * (y * z + w * x), i - two * (x * x + y * y)]
+        ]}
+    }
+}
+
+impl<T: Num> From<Quaternion<T>> for Mat4<T> {
+    fn from(q: Quaternion<T>) -> Mat4<T> {
+        Mat3::from(q).into()
+    }
+}
+
+// prints as [[a, b, c], [d, e, f], [g, h, i]]
+impl<T: fmt::Display> fmt::Display for Mat3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_rows(f, self.rows.iter().map(|row| &row[..]))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Mat4<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_rows(f, self.rows.iter().map(|row| &row[..]))
+    }
+}
+
+fn write_rows<'a, T: fmt::Display + 'a>(f: &mut fmt::Formatter<'_>, rows: impl Iterator<Item = &'a [T]>) -> fmt::Result {
+    let rows: Vec<String> = rows
+        .map(|row| format!("[{}]", row.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ")))
+        .collect();
+    write!(f, "[{}]", rows.join(", "))
+}
+
+// matrix * matrix: the right-hand transform first
+
+impl<T: Num> Mul for Mat3<T> {
+    type Output = Mat3<T>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut rows = [[T::zero(); 3]; 3];
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                *x = (0..3).fold(T::zero(), |sum, k| sum + self.rows[i][k] * rhs.rows[k][j])
+            }
+        }
+        Mat3{rows}
+    }
+}
+
+impl<T: Num> Mul for Mat4<T> {
+    type Output = Mat4<T>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut rows = [[T::zero(); 4]; 4];
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                *x = (0..4).fold(T::zero(), |sum, k| sum + self.rows[i][k] * rhs.rows[k][j])
+            }
+        }
+        Mat4{rows}
+    }
+}
+
+// matrix * vector, point or line
+
+impl<T: Num> Mul<Vec3<T>> for Mat3<T> {
+    type Output = Vec3<T>;
+    fn mul(self, rhs: Vec3<T>) -> Vec3<T> {
+        let m = &self.rows;
+        Vec3{
+            x: m[0][0] * rhs.x + m[0][1] * rhs.y + m[0][2] * rhs.z,
+            y: m[1][0] * rhs.x + m[1][1] * rhs.y + m[1][2] * rhs.z,
+            z: m[2][0] * rhs.x + m[2][1] * rhs.y + m[2][2] * rhs.z
+        }
+    }
+}
+
+impl<T: Num> Mul<Point<T>> for Mat3<T> {
+    type Output = Point<T>;
+    fn mul(self, rhs: Point<T>) -> Point<T> {
+        (self * Vec3::from(rhs)).into()
+    }
+}
+
+// a direction: the last column, the translation, doesn't apply
+impl<T: Num> Mul<Vec3<T>> for Mat4<T> {
+    type Output = Vec3<T>;
+    fn mul(self, rhs: Vec3<T>) -> Vec3<T> {
+        let m = &self.rows;
+        Vec3{
+            x: m[0][0] * rhs.x + m[0][1] * rhs.y + m[0][2] * rhs.z,
+            y: m[1][0] * rhs.x + m[1][1] * rhs.y + m[1][2] * rhs.z,
+            z: m[2][0] * rhs.x + m[2][1] * rhs.y + m[2][2] * rhs.z
+        }
+    }
+}
+
+// a position: (x, y, z, 1), divided through by the resulting w, which only a projection changes
+impl<T: Num> Mul<Point<T>> for Mat4<T> {
+    type Output = Point<T>;
+    fn mul(self, rhs: Point<T>) -> Point<T> {
+        let m = &self.rows;
+        let row = |i: usize| m[i][0] * rhs.x + m[i][1] * rhs.y + m[i][2] * rhs.z + m[i][3];
+        let w = row(3);
+        if w == T::one() {
+            Point{x: row(0), y: row(1), z: row(2)}
+        } else {
+            Point{x: row(0) / w, y: row(1) / w, z: row(2) / w}
+        }
+    }
+}
+
+impl<T: Num> Mul<Line<T>> for Mat3<T> {
+    type Output = Line<T>;
+    fn mul(self, rhs: Line<T>) -> Line<T> {
+        Line{start: self * rhs.start, end: self * rhs.end}
+    }
+}
+
+impl<T: Num> Mul<Line<T>> for Mat4<T> {
+    type Output = Line<T>;
+    fn mul(self, rhs: Line<T>) -> Line<T> {
+        Line{start: self * rhs.start, end: self * rhs.end}
+    }
+}
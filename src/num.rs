@@ -0,0 +1,207 @@
+//! A small numeric trait hierarchy, so generic code can be written once for every number type.
+//!
+//! * [`Zero`] and [`One`]: additive and multiplicative identities
+//! * [`Num`]: copyable values closed under `+ - * /` with both identities; every primitive
+//!   integer and float, and `Complex<T>` for `T: Num`
+//! * [`Signed`]: numbers with a sign; signed integers and floats
+//! * [`Real`]: ordered numbers that convert to `f64`; every primitive integer and float
+//! * [`Integer`]: primitive integers, with checked, saturating and wrapping arithmetic
+//! * [`Float`]: `f32` and `f64`, with the usual transcendental functions
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::complex::Complex;
+
+/// The additive identity.
+pub trait Zero: Sized + Add<Output = Self> {
+    fn zero() -> Self;
+    fn is_zero(&self) -> bool;
+}
+
+/// The multiplicative identity.
+pub trait One: Sized + Mul<Output = Self> {
+    fn one() -> Self;
+}
+
+/// A number: copyable, comparable for equality, and closed under the four arithmetic operators.
+///
+/// Integer division truncates and panics on a zero divisor, as for the primitive types.
+pub trait Num: Copy + PartialEq + Zero + One + Sub<Output = Self> + Div<Output = Self> {}
+
+impl<T> Num for T where T: Copy + PartialEq + Zero + One + Sub<Output = T> + Div<Output = T> {}
+
+/// A number that can be negative.
+pub trait Signed: Num + Neg<Output = Self> {
+    /// The absolute value. For signed integers `MIN.abs()` overflows, as for the primitive types.
+    fn abs(self) -> Self;
+    /// `-1`, `0` or `1` according to the sign (for floats, `±1` for `±0` and NaN for NaN).
+    fn signum(self) -> Self;
+    fn is_negative(&self) -> bool;
+}
+
+/// A real number: ordered, and convertible to `f64` for computations whose results aren't
+/// whole, such as a mean.
+pub trait Real: Num + PartialOrd {
+    /// Converts to `f64`, rounding to the nearest representable value.
+    fn to_f64(self) -> f64;
+}
+
+/// A primitive integer.
+pub trait Integer: Real + Ord {
+    const MIN: Self;
+    const MAX: Self;
+
+    /// `self + other`, or `None` on overflow.
+    fn checked_add(self, other: Self) -> Option<Self>;
+    /// `self + other`, clamped to `MIN..=MAX`.
+    fn saturating_add(self, other: Self) -> Self;
+    /// `self + other`, wrapping around at the boundary of the type.
+    fn wrapping_add(self, other: Self) -> Self;
+}
+
+/// A floating point number.
+pub trait Float: Signed + Real {
+    const PI: Self;
+    const EPSILON: Self;
+    const INFINITY: Self;
+    const NAN: Self;
+
+    /// Converts from `f64`, rounding to the nearest representable value.
+    fn from_f64(x: f64) -> Self;
+
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn to_radians(self) -> Self;
+    fn to_degrees(self) -> Self;
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+}
+
+macro_rules! num_impls {
+    ($zero:expr, $one:expr; $($t:ty)*) => {$(
+        impl Zero for $t {
+            fn zero() -> $t { $zero }
+            fn is_zero(&self) -> bool { *self == $zero }
+        }
+
+        impl One for $t {
+            fn one() -> $t { $one }
+        }
+
+        impl Real for $t {
+            fn to_f64(self) -> f64 { self as f64 }
+        }
+    )*}
+}
+
+num_impls!(0, 1; u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+num_impls!(0.0, 1.0; f32 f64);
+
+macro_rules! integer_impls {
+    ($($t:ty)*) => {$(
+        impl Integer for $t {
+            const MIN: $t = <$t>::MIN;
+            const MAX: $t = <$t>::MAX;
+
+            fn checked_add(self, other: $t) -> Option<$t> { <$t>::checked_add(self, other) }
+            fn saturating_add(self, other: $t) -> $t { <$t>::saturating_add(self, other) }
+            fn wrapping_add(self, other: $t) -> $t { <$t>::wrapping_add(self, other) }
+        }
+    )*}
+}
+
+integer_impls!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+macro_rules! signed_int_impls {
+    ($($t:ty)*) => {$(
+        impl Signed for $t {
+            fn abs(self) -> $t { <$t>::abs(self) }
+            fn signum(self) -> $t { <$t>::signum(self) }
+            fn is_negative(&self) -> bool { *self < 0 }
+        }
+    )*}
+}
+
+signed_int_impls!(i8 i16 i32 i64 i128 isize);
+
+// each Float method forwards to the inherent method of the same name
+macro_rules! float_impls {
+    ($($t:ident)*) => {$(
+        impl Signed for $t {
+            fn abs(self) -> $t { $t::abs(self) }
+            fn signum(self) -> $t { $t::signum(self) }
+            fn is_negative(&self) -> bool { self.is_sign_negative() }
+        }
+
+        impl Float for $t {
+            const PI: $t = std::$t::consts::PI;
+            const EPSILON: $t = $t::EPSILON;
+            const INFINITY: $t = $t::INFINITY;
+            const NAN: $t = $t::NAN;
+
+            fn from_f64(x: f64) -> $t { x as $t }
+
+            fn sqrt(self) -> $t { $t::sqrt(self) }
+            fn cbrt(self) -> $t { $t::cbrt(self) }
+            fn exp(self) -> $t { $t::exp(self) }
+            fn ln(self) -> $t { $t::ln(self) }
+            fn powi(self, n: i32) -> $t { $t::powi(self, n) }
+            fn powf(self, n: $t) -> $t { $t::powf(self, n) }
+            fn sin(self) -> $t { $t::sin(self) }
+            fn cos(self) -> $t { $t::cos(self) }
+            fn tan(self) -> $t { $t::tan(self) }
+            fn asin(self) -> $t { $t::asin(self) }
+            fn acos(self) -> $t { $t::acos(self) }
+            fn atan(self) -> $t { $t::atan(self) }
+            fn atan2(self, other: $t) -> $t { $t::atan2(self, other) }
+            fn hypot(self, other: $t) -> $t { $t::hypot(self, other) }
+            fn floor(self) -> $t { $t::floor(self) }
+            fn ceil(self) -> $t { $t::ceil(self) }
+            fn round(self) -> $t { $t::round(self) }
+            fn min(self, other: $t) -> $t { $t::min(self, other) }
+            fn max(self, other: $t) -> $t { $t::max(self, other) }
+            fn copysign(self, sign: $t) -> $t { $t::copysign(self, sign) }
+            fn mul_add(self, a: $t, b: $t) -> $t { $t::mul_add(self, a, b) }
+            fn to_radians(self) -> $t { $t::to_radians(self) }
+            fn to_degrees(self) -> $t { $t::to_degrees(self) }
+            fn is_nan(self) -> bool { $t::is_nan(self) }
+            fn is_finite(self) -> bool { $t::is_finite(self) }
+        }
+    )*}
+}
+
+float_impls!(f32 f64);
+
+impl<T: Num> Zero for Complex<T> {
+    fn zero() -> Complex<T> {
+        Complex::new(T::zero(), T::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<T: Num> One for Complex<T> {
+    fn one() -> Complex<T> {
+        Complex::new(T::one(), T::zero())
+    }
+}
@@ -1,7 +1,7 @@
 // Ownership in Rust
 
-pub fn main() {
-    println!("----- Ownership -----");
+fn moves_and_copies() {
+    println!("----- 1.1. Moves and copies -----");
 
     {
         let s1 = String::from("hello"); // s1 comes into scope
@@ -24,6 +24,10 @@ pub fn main() {
         println!("x = {} is still valid", x);
     } // Here, x goes out of scope, then s3, s2, and s1.
     // But because s1 and s2's values were moved, nothing special happens.
+}
+
+fn returning_ownership() {
+    println!("----- 1.2. Returning ownership -----");
 
     {
         let s4 = String::from("hello");                   // s4 comes into scope
@@ -33,8 +37,6 @@ pub fn main() {
 
         println!("The length of '{}' is {}.", s5, len);           // s5 and len are still valid, s4 is not
     }
-    borrowing_with_mutation();
-    string_slices();
     println!("All variables are out of scope now.")
 }
 
@@ -54,6 +56,8 @@ fn calculate_length(s: String) -> (String, usize) {
 }
 
 fn borrowing_with_mutation() {
+    println!("----- 1.3. Borrowing with mutation -----");
+
     // Variables and references are immutable by default.
     // let s = String::from("hello");
     // change(&s);
@@ -75,10 +79,31 @@ fn borrowing_with_mutation() {
 }
 
 fn string_slices() {
+    println!("----- 1.4. String slices -----");
+
     let mut s = String::from("hello world");
     let word = &s[..5]; // immutable borrow
     //s.clear();           // error! -- mutable borrow
     println!("the first word is: {}", word);
     //println!("the first word is: {}", *word); // error -- *word has unknown size at compile-time
     s.clear();
-}
\ No newline at end of file
+}
+
+// Chapter registry
+use crate::registry::{Chapter, Section};
+
+pub const CHAPTER: Chapter = Chapter {
+    number: 1,
+    key: "ownership",
+    title: "Ownership",
+    sections: &[
+        Section { number: 1, name: "Moves and copies", run: moves_and_copies },
+        Section { number: 2, name: "Returning ownership", run: returning_ownership },
+        Section { number: 3, name: "Borrowing with mutation", run: borrowing_with_mutation },
+        Section { number: 4, name: "String slices", run: string_slices }
+    ]
+};
+
+pub fn main() {
+    CHAPTER.run()
+}
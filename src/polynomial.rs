@@ -0,0 +1,243 @@
+//! Polynomials in one variable, and their complex roots.
+
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::complex::Complex;
+use crate::num::{Num, Zero};
+
+/// A polynomial `c₀ + c₁x + c₂x² + …` with coefficients of type `T`.
+///
+/// Coefficients are stored lowest degree first, without trailing zeros, so equal polynomials
+/// compare equal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial<T> {
+    coefficients: Vec<T>
+}
+
+impl<T: Zero> Polynomial<T> {
+    /// The polynomial with the given coefficients, lowest degree first.
+    pub fn new(mut coefficients: Vec<T>) -> Polynomial<T> {
+        while coefficients.last().is_some_and(Zero::is_zero) {
+            coefficients.pop();
+        }
+        Polynomial{coefficients}
+    }
+}
+
+impl<T> Polynomial<T> {
+    /// The zero polynomial.
+    pub fn zero() -> Polynomial<T> {
+        Polynomial{coefficients: Vec::new()}
+    }
+
+    /// Coefficients, lowest degree first; empty for the zero polynomial.
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    /// The degree, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+}
+
+impl<T: Num> Polynomial<T> {
+    /// Value at `x`, by Horner's method.
+    pub fn eval(&self, x: T) -> T {
+        self.coefficients.iter().rev().fold(T::zero(), |acc, &c| acc * x + c)
+    }
+
+    /// The derivative `c₁ + 2c₂x + 3c₃x² + …`.
+    pub fn derivative(&self) -> Polynomial<T> {
+        let mut k = T::zero();
+        let coefficients = self.coefficients.iter().skip(1)
+            .map(|&c| { k = k + T::one(); k * c })
+            .collect();
+        Polynomial::new(coefficients)
+    }
+
+    /// Quotient and remainder of long division by `divisor`, so that
+    /// `self == quotient * divisor + remainder` and the remainder has a lower degree than `divisor`.
+    ///
+    /// For integer coefficients this is `None` unless the leading coefficient of `divisor`
+    /// divides every coefficient it has to: `(x² + 1) / 2x` has no integer quotient.
+    ///
+    /// # Panics
+    ///
+    /// If `divisor` is the zero polynomial.
+    pub fn div_rem(&self, divisor: &Polynomial<T>) -> Option<(Polynomial<T>, Polynomial<T>)> {
+        let d = divisor.degree().expect("division by the zero polynomial");
+        let lead = divisor.coefficients[d];
+        let mut remainder = self.coefficients.clone();
+        if remainder.len() <= d {
+            return Some((Polynomial::zero(), Polynomial::new(remainder)))
+        }
+
+        // only integer division truncates, and only there can 1 / 2 be zero
+        let truncates = (T::one() / (T::one() + T::one())).is_zero();
+        let mut quotient = vec![T::zero(); remainder.len() - d];
+        for k in (0..quotient.len()).rev() {
+            let q = remainder[k + d] / lead;
+            if truncates && q * lead != remainder[k + d] { return None }
+            quotient[k] = q;
+            for (j, &c) in divisor.coefficients.iter().enumerate() {
+                remainder[k + j] = remainder[k + j] - q * c
+            }
+        }
+        remainder.truncate(d);
+        Some((Polynomial::new(quotient), Polynomial::new(remainder)))
+    }
+}
+
+impl<T: Num> Add for Polynomial<T> {
+    type Output = Polynomial<T>;
+    fn add(self, rhs: Self) -> Self::Output {
+        let (mut long, short) = if self.coefficients.len() >= rhs.coefficients.len() { (self, rhs) } else { (rhs, self) };
+        for (a, &b) in long.coefficients.iter_mut().zip(&short.coefficients) {
+            *a = *a + b
+        }
+        Polynomial::new(long.coefficients)
+    }
+}
+
+impl<T: Num + Neg<Output = T>> Neg for Polynomial<T> {
+    type Output = Polynomial<T>;
+    fn neg(self) -> Self::Output {
+        Polynomial{coefficients: self.coefficients.into_iter().map(|c| -c).collect()}
+    }
+}
+
+impl<T: Num> Sub for Polynomial<T> {
+    type Output = Polynomial<T>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        let at = |p: &Polynomial<T>, k: usize| p.coefficients.get(k).copied().unwrap_or_else(T::zero);
+        Polynomial::new((0..len).map(|k| at(&self, k) - at(&rhs, k)).collect())
+    }
+}
+
+impl<T: Num> Mul for Polynomial<T> {
+    type Output = Polynomial<T>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() { return Polynomial::zero() }
+        let mut product = vec![T::zero(); self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, &a) in self.coefficients.iter().enumerate() {
+            for (j, &b) in rhs.coefficients.iter().enumerate() {
+                product[i + j] = product[i + j] + a * b
+            }
+        }
+        Polynomial::new(product)
+    }
+}
+
+// prints highest degree first, e.g. 3x^2 - x + 1; compound coefficients such as complex numbers
+// are parenthesised
+impl<T: fmt::Display + Zero> fmt::Display for Polynomial<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for (k, c) in self.coefficients.iter().enumerate().rev() {
+            if c.is_zero() { continue }
+            let text = c.to_string();
+            let (negative, magnitude) = match text.strip_prefix('-') {
+                Some(m) if !m.contains(' ') => (true, m.to_string()),
+                _ => (false, text.clone())
+            };
+            let magnitude = if magnitude.contains(' ') { format!("({})", magnitude) } else { magnitude };
+
+            match (first, negative) {
+                (true, true) => write!(f, "-")?,
+                (true, false) => (),
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?
+            }
+            first = false;
+
+            // a coefficient of one is left implicit, except on the constant term
+            let coefficient = if k > 0 && magnitude == "1" { "" } else { magnitude.as_str() };
+            match k {
+                0 => write!(f, "{}", coefficient)?,
+                1 => write!(f, "{}x", coefficient)?,
+                _ => write!(f, "{}x^{}", coefficient, k)?
+            }
+        }
+        if first { write!(f, "0")? }
+        Ok(())
+    }
+}
+
+// root finding
+
+const MAX_ITERATIONS: usize = 500;
+const TOLERANCE: f64 = 1e-14;
+
+impl Polynomial<f64> {
+    /// All complex roots, repeated according to multiplicity, in no particular order.
+    ///
+    /// Empty for constant polynomials. See [`Polynomial::<Complex<f64>>::roots`].
+    pub fn roots(&self) -> Vec<Complex<f64>> {
+        let complex: Vec<_> = self.coefficients.iter().map(|&c| Complex::new(c, 0.0)).collect();
+        Polynomial{coefficients: complex}.roots()
+    }
+}
+
+impl Polynomial<Complex<f64>> {
+    /// All complex roots, repeated according to multiplicity, in no particular order.
+    ///
+    /// Uses the Aberth–Ehrlich method, which refines all roots simultaneously and converges
+    /// cubically for simple roots. Roots at zero are factored out exactly first. Multiple roots
+    /// converge more slowly and are accurate to roughly `1e-14^(1/m)` for multiplicity `m`.
+    pub fn roots(&self) -> Vec<Complex<f64>> {
+        let zero = Complex::new(0.0, 0.0);
+        // x = 0 is a root once for every vanishing low-order coefficient
+        let zeros = self.coefficients.iter().take_while(|&&c| c == zero).count();
+        let p = Polynomial{coefficients: self.coefficients[zeros..].to_vec()};
+        let mut roots = vec![zero; zeros];
+
+        let n = match p.degree() {
+            None | Some(0) => return roots,
+            Some(n) => n
+        };
+        if n == 1 {
+            roots.push(-p.coefficients[0] / p.coefficients[1]);
+            return roots
+        }
+
+        let dp = p.derivative();
+        let mut z = initial_guesses(&p);
+        for _ in 0..MAX_ITERATIONS {
+            let mut converged = true;
+            for i in 0..n {
+                let value = p.eval(z[i]);
+                if value == zero { continue }
+                let ratio = value / dp.eval(z[i]);
+                let repulsion = (0..n).filter(|&j| j != i)
+                    .fold(zero, |acc, j| acc + Complex::new(1.0, 0.0) / (z[i] - z[j]));
+                let step = ratio / (Complex::new(1.0, 0.0) - ratio * repulsion);
+                // a zero derivative or coincident guesses give a non-finite step; nudge instead
+                let step = if step.re.is_finite() && step.im.is_finite() { step } else { Complex::new(1e-3, 1e-3) };
+                z[i] -= step;
+                if step.norm() > TOLERANCE * z[i].norm().max(1.0) { converged = false }
+            }
+            if converged { break }
+        }
+        roots.extend(z);
+        roots
+    }
+}
+
+// n points on a circle whose radius is the geometric mean of the roots' moduli, at angles offset
+// from the real axis so no two guesses are conjugates
+fn initial_guesses(p: &Polynomial<Complex<f64>>) -> Vec<Complex<f64>> {
+    let n = p.coefficients.len() - 1;
+    let radius = (p.coefficients[0].norm() / p.coefficients[n].norm()).powf(1.0 / n as f64);
+    let radius = if radius.is_finite() && radius > 0.0 { radius } else { 1.0 };
+    (0..n).map(|k| {
+        let angle = 2.0 * std::f64::consts::PI * k as f64 / n as f64 + 0.4;
+        Complex::from_polar(radius, angle)
+    }).collect()
+}
@@ -0,0 +1,191 @@
+//! Quaternions, for rotations in 3D space.
+//!
+//! A unit quaternion `cos(θ/2) + sin(θ/2)(xi + yj + zk)` is a rotation by `θ` about the axis
+//! `(x, y, z)`. Multiplying two of them composes the rotations, `q * r` meaning `r` first, and
+//! multiplying a [`Vec3`], [`Point`] or [`Line`] by one rotates it about the origin. Rotations
+//! assume unit quaternions, which is what [`Quaternion::from_axis_angle`] gives.
+
+use std::fmt;
+use std::ops::{Mul, Neg};
+
+use crate::geometry::{Line, Point};
+use crate::num::{Float, Num};
+use crate::vector::Vec3;
+
+/// A quaternion `w + xi + yj + zk`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion<T = f64> {
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T
+}
+
+impl<T> Quaternion<T> {
+    pub fn new(w: T, x: T, y: T, z: T) -> Quaternion<T> {
+        Quaternion{w, x, y, z}
+    }
+}
+
+impl<T: Num> Quaternion<T> {
+    /// The quaternion `1`, which rotates nothing.
+    pub fn identity() -> Quaternion<T> {
+        Quaternion{w: T::one(), x: T::zero(), y: T::zero(), z: T::zero()}
+    }
+
+    /// The vector part `(x, y, z)`.
+    pub fn vector(&self) -> Vec3<T> {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    pub fn dot(&self, other: &Quaternion<T>) -> T {
+        self.w * other.w + self.vector().dot(other.vector())
+    }
+
+    pub fn norm_sqr(&self) -> T {
+        self.dot(self)
+    }
+
+    /// Rotates `v` about the origin.
+    pub fn rotate(&self, v: Vec3<T>) -> Vec3<T> {
+        // v + 2w(u × v) + 2u × (u × v), which is q v q* for a unit q = w + u
+        let u = self.vector();
+        let t = u.cross(v) * (T::one() + T::one());
+        v + t * self.w + u.cross(t)
+    }
+}
+
+impl<T: Num + Neg<Output = T>> Quaternion<T> {
+    /// The conjugate `w - xi - yj - zk`, which is the inverse rotation for a unit quaternion.
+    pub fn conj(&self) -> Quaternion<T> {
+        Quaternion{w: self.w, x: -self.x, y: -self.y, z: -self.z}
+    }
+}
+
+impl<T: Float> Quaternion<T> {
+    /// The rotation by `angle` radians about `axis`, anticlockwise when looking back along the
+    /// axis. A zero axis gives no rotation.
+    pub fn from_axis_angle(axis: Vec3<T>, angle: T) -> Quaternion<T> {
+        let axis = match axis.normalize() {
+            Some(axis) => axis,
+            None => return Quaternion::identity()
+        };
+        let half = angle / (T::one() + T::one());
+        let v = axis * half.sin();
+        Quaternion{w: half.cos(), x: v.x, y: v.y, z: v.z}
+    }
+
+    /// The unit axis and angle in radians, from 0 to 2π, of a unit quaternion's rotation. No
+    /// rotation has the axis `(1, 0, 0)`.
+    pub fn to_axis_angle(&self) -> (Vec3<T>, T) {
+        let v = self.vector();
+        let angle = (T::one() + T::one()) * v.norm().atan2(self.w);
+        let axis = v.normalize().unwrap_or_else(|| Vec3::new(T::one(), T::zero(), T::zero()));
+        (axis, angle)
+    }
+
+    pub fn norm(&self) -> T {
+        self.norm_sqr().sqrt()
+    }
+
+    /// The quaternion of norm 1 in the same direction, or `None` for zero.
+    pub fn normalize(&self) -> Option<Quaternion<T>> {
+        let norm = self.norm();
+        if norm == T::zero() { return None }
+        Some(Quaternion{w: self.w / norm, x: self.x / norm, y: self.y / norm, z: self.z / norm})
+    }
+
+    /// The multiplicative inverse, or `None` for zero.
+    pub fn inverse(&self) -> Option<Quaternion<T>> {
+        let norm_sqr = self.norm_sqr();
+        if norm_sqr == T::zero() { return None }
+        let c = self.conj();
+        Some(Quaternion{w: c.w / norm_sqr, x: c.x / norm_sqr, y: c.y / norm_sqr, z: c.z / norm_sqr})
+    }
+
+    /// Spherical linear interpolation between unit quaternions: `self` at 0, `other` at 1, and
+    /// in between a rotation turning at a constant rate, the short way round.
+    pub fn slerp(&self, other: &Quaternion<T>, t: T) -> Quaternion<T> {
+        // q and -q are the same rotation; pick whichever is nearer
+        let (other, cos) = match self.dot(other) {
+            d if d < T::zero() => (-*other, -d),
+            d => (*other, d)
+        };
+        let (a, b) = if cos > T::from_f64(0.9995) {
+            // nearly equal: the angle is too small to divide by, and a straight line is as good
+            (T::one() - t, t)
+        } else {
+            let theta = cos.acos();
+            let sin = theta.sin();
+            (((T::one() - t) * theta).sin() / sin, (t * theta).sin() / sin)
+        };
+        let q = Quaternion{
+            w: self.w * a + other.w * b,
+            x: self.x * a + other.x * b,
+            y: self.y * a + other.y * b,
+            z: self.z * a + other.z * b
+        };
+        q.normalize().unwrap_or(q)
+    }
+}
+
+// prints as w + xi + yj + zk, honouring a precision such as {:.2} on every part
+impl<T: fmt::Display> fmt::Display for Quaternion<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        let format = |part: &T| match precision {
+            Some(p) => format!("{:.*}", p, part),
+            None => part.to_string()
+        };
+        let mut text = format(&self.w);
+        for (part, unit) in [(&self.x, 'i'), (&self.y, 'j'), (&self.z, 'k')] {
+            let part = format(part);
+            match part.strip_prefix('-') {
+                Some(magnitude) => text += &format!(" - {}{}", magnitude, unit),
+                None => text += &format!(" + {}{}", part, unit)
+            }
+        }
+        write!(f, "{}", text)
+    }
+}
+
+// the Hamilton product: q * r rotates by r, then by q
+impl<T: Num> Mul for Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Quaternion{
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn neg(self) -> Self::Output {
+        Quaternion{w: -self.w, x: -self.x, y: -self.y, z: -self.z}
+    }
+}
+
+impl<T: Num> Mul<Vec3<T>> for Quaternion<T> {
+    type Output = Vec3<T>;
+    fn mul(self, rhs: Vec3<T>) -> Vec3<T> {
+        self.rotate(rhs)
+    }
+}
+
+impl<T: Num> Mul<Point<T>> for Quaternion<T> {
+    type Output = Point<T>;
+    fn mul(self, rhs: Point<T>) -> Point<T> {
+        self.rotate(rhs.into()).into()
+    }
+}
+
+impl<T: Num> Mul<Line<T>> for Quaternion<T> {
+    type Output = Line<T>;
+    fn mul(self, rhs: Line<T>) -> Line<T> {
+        Line{start: self * rhs.start, end: self * rhs.end}
+    }
+}
@@ -0,0 +1,58 @@
+//! A small seeded pseudo-random number generator, for reproducible simulations and examples.
+//!
+//! [`Rng`] is SplitMix64: fast and statistically sound for simulations, but not suitable for
+//! anything security related. The same seed always gives the same sequence, on every platform.
+
+use std::ops::Range;
+
+/// A deterministic pseudo-random number generator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: u64
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng{state: seed}
+    }
+
+    /// The next 64 uniformly distributed bits.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A uniformly distributed float in `0.0..1.0`.
+    pub fn next_f64(&mut self) -> f64 {
+        // the top 53 bits fill an f64 mantissa exactly
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// A number in `0..n`, with negligible bias for any `n` far below `2^64`.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "cannot pick a number below zero");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// A number in `range`.
+    ///
+    /// # Panics
+    ///
+    /// If `range` is empty.
+    pub fn range(&mut self, range: Range<u64>) -> u64 {
+        assert!(range.start < range.end, "cannot pick from an empty range");
+        range.start + self.below(range.end - range.start)
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        self.next_f64() < p
+    }
+}
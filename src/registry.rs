@@ -0,0 +1,147 @@
+// Section registry
+// every chapter module exposes a CHAPTER constant listing its numbered subsections,
+// so the runner can discover and run them without hard-wiring calls in main()
+
+use std::fmt;
+
+use crate::ownership;
+use crate::types_and_variables;
+use crate::control_flow;
+use crate::data_structures;
+use crate::functions;
+use crate::traits;
+use crate::lifetime_and_memory;
+use crate::showcase;
+
+// a numbered subsection, e.g. 4.10 Hashmap
+pub struct Section {
+    pub number: u8, // position within the chapter
+    pub name: &'static str,
+    pub run: fn()
+}
+
+// a chapter, e.g. 4. Data structures
+pub struct Chapter {
+    pub number: u8,
+    pub key: &'static str, // module name, e.g. "data_structures"
+    pub title: &'static str,
+    pub sections: &'static [Section]
+}
+
+impl Chapter {
+    // runs every subsection in order
+    pub fn run(&self) {
+        println!("----- {}. {} -----", self.number, self.title);
+        for section in self.sections {
+            (section.run)()
+        }
+    }
+
+    // section id, e.g. "4.10"
+    pub fn section_id(&self, section: &Section) -> String {
+        format!("{}.{}", self.number, section.number)
+    }
+}
+
+impl fmt::Display for Chapter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}. {} ({})", self.number, self.title, self.key)
+    }
+}
+
+// all chapters, in order
+pub static CHAPTERS: [&Chapter; 8] = [
+    &ownership::CHAPTER,
+    &types_and_variables::CHAPTER,
+    &control_flow::CHAPTER,
+    &data_structures::CHAPTER,
+    &functions::CHAPTER,
+    &traits::CHAPTER,
+    &lifetime_and_memory::CHAPTER,
+    &showcase::CHAPTER
+];
+
+// something that can be run: a whole chapter or a single subsection
+#[derive(Clone, Copy)]
+pub enum Entry {
+    Chapter(&'static Chapter),
+    Section(&'static Chapter, &'static Section)
+}
+
+impl Entry {
+    pub fn run(&self) {
+        match self {
+            Entry::Chapter(chapter) => chapter.run(),
+            Entry::Section(_, section) => (section.run)()
+        }
+    }
+}
+
+// resolves a selector to the entries it names
+// "4" or "data_structures" or "data structures" -> whole chapter
+// "4.10" or "hashmap" -> single subsection (names match case-insensitively)
+pub fn lookup(selector: &str) -> Vec<Entry> {
+    let wanted = normalize(selector);
+
+    for &chapter in CHAPTERS.iter() {
+        if wanted == chapter.number.to_string()
+            || wanted == chapter.key
+            || wanted == normalize(chapter.title) {
+            return vec![Entry::Chapter(chapter)]
+        }
+    }
+
+    let mut found = Vec::new();
+    for &chapter in CHAPTERS.iter() {
+        for section in chapter.sections {
+            if wanted == chapter.section_id(section) || wanted == normalize(section.name) {
+                found.push(Entry::Section(chapter, section))
+            }
+        }
+    }
+    found
+}
+
+// lowercase with underscores, so "Option<T>", "option<t>" and "data structures" compare sensibly
+fn normalize(s: &str) -> String {
+    s.trim().to_lowercase().replace(' ', "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // what each entry a selector finds would print as in `list`
+    fn ids(selector: &str) -> Vec<String> {
+        lookup(selector).iter().map(|entry| match entry {
+            Entry::Chapter(chapter) => chapter.number.to_string(),
+            Entry::Section(chapter, section) => chapter.section_id(section)
+        }).collect()
+    }
+
+    #[test]
+    fn chapters_by_number_key_or_title() {
+        assert_eq!(ids("4"), ["4"]);
+        assert_eq!(ids("data_structures"), ["4"]);
+        assert_eq!(ids("Data Structures"), ["4"]);
+        assert_eq!(ids(" 6 "), ["6"]);
+    }
+
+    #[test]
+    fn sections_by_id_or_name() {
+        assert_eq!(ids("4.10"), ["4.10"]);
+        assert_eq!(ids("hashmap"), ["4.10"]);
+        assert_eq!(ids("HASHMAP"), ["4.10"]);
+        assert_eq!(ids("option<t>"), ["4.4"]);
+        assert_eq!(ids("1.4"), ["1.4"]);
+    }
+
+    #[test]
+    fn unknown_selectors_find_nothing() {
+        assert!(ids("").is_empty());
+        assert!(ids("99").is_empty());
+        assert!(ids("4.99").is_empty());
+        assert!(ids("4.").is_empty());
+        assert!(ids("no such section").is_empty());
+    }
+}
@@ -0,0 +1,83 @@
+// Fractal rendering from the command line
+// parses the `fractal` command's options into fractal::Options and writes the image
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::str::FromStr;
+use rust_programming_language::complex::Complex;
+use rust_programming_language::fractal::{self, Fractal, Options, Palette};
+
+pub const USAGE: &str = "\
+usage: RustProgrammingLanguage fractal [options]
+
+options:
+  --julia <c>          render the Julia set for c, e.g. --julia \"-0.8+0.156i\" (default: Mandelbrot set)
+  --center <c>         center of the viewport (default: -0.5 for Mandelbrot, 0 for Julia)
+  --span <w>           width of the viewport in the complex plane (default: 3.5 for Mandelbrot, 3 for Julia)
+  --size <W>x<H>       image size in pixels (default: 800x600)
+  --iterations <n>     iteration limit (default: 256)
+  --palette <name>     grayscale, fire or ocean (default: fire)
+  --threads <n>        worker threads (default: one per CPU)
+  -o, --output <file>  output file; .pgm writes grayscale, anything else PPM (default: fractal.ppm)";
+
+// runs the command, returning a message for the user on bad options or failed writes
+pub fn fractal(args: &[String]) -> Result<(), String> {
+    let mut options = Options::default();
+    let mut center = None;
+    let mut span = None;
+    let mut output = String::from("fractal.ppm");
+
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value", flag));
+        match flag.as_str() {
+            "--julia" => options.fractal = Fractal::Julia(parse(flag, value()?)?),
+            "--center" => center = Some(parse::<Complex<f64>>(flag, value()?)?),
+            "--span" => span = Some(parse::<f64>(flag, value()?)?),
+            "--size" => {
+                let size = value()?;
+                let (w, h) = size.split_once('x').ok_or(format!("--size expects WxH, got '{}'", size))?;
+                options.width = parse(flag, w)?;
+                options.height = parse(flag, h)?;
+            },
+            "--iterations" => options.max_iterations = parse(flag, value()?)?,
+            "--palette" => options.palette = match value()?.as_str() {
+                "grayscale" | "greyscale" | "gray" | "grey" => Palette::Grayscale,
+                "fire" => Palette::Fire,
+                "ocean" => Palette::Ocean,
+                other => return Err(format!("unknown palette '{}'", other))
+            },
+            "--threads" => options.threads = parse(flag, value()?)?,
+            "-o" | "--output" => output = value()?.clone(),
+            other => return Err(format!("unknown option '{}'", other))
+        }
+    }
+
+    // a Julia set is centered on the origin
+    if let Fractal::Julia(_) = options.fractal {
+        options.viewport.center = Complex::new(0.0, 0.0);
+        options.viewport.width = 3.0;
+    }
+    if let Some(c) = center { options.viewport.center = c }
+    if let Some(w) = span { options.viewport.width = w }
+    if options.width == 0 || options.height == 0 {
+        return Err(String::from("the image must be at least 1x1"))
+    }
+
+    let image = fractal::render(&options);
+    let file = File::create(&output).map_err(|e| format!("cannot create {}: {}", output, e))?;
+    let mut out = BufWriter::new(file);
+    let written = if output.ends_with(".pgm") {
+        image.write_pgm(&mut out, options.palette)
+    } else {
+        image.write_ppm(&mut out, options.palette)
+    };
+    written.map_err(|e| format!("cannot write {}: {}", output, e))?;
+    println!("wrote {}x{} image to {}", options.width, options.height, output);
+    Ok(())
+}
+
+fn parse<T: FromStr>(flag: &str, value: &str) -> Result<T, String>
+    where T::Err: std::fmt::Display {
+    value.parse().map_err(|e| format!("invalid value '{}' for {}: {}", value, flag, e))
+}
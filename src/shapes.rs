@@ -0,0 +1,632 @@
+//! Plane shapes behind a common `Shape` trait.
+//!
+//! Angles are in radians, counterclockwise. Shapes are rotated and scaled about their
+//! [`center`](Shape::center), and points on the boundary count as inside.
+//!
+//! The [`collision`] submodule finds where any two shapes meet, through `&dyn Shape<f64>`, and
+//! [`algorithms`] has convex hulls, triangulation and point-in-polygon tests for lists of vertices.
+//! Large collections of shapes can be searched by position with a [`QuadTree`] from [`index`].
+
+pub mod algorithms;
+pub mod collision;
+pub mod index;
+
+pub use algorithms::{convex_hull, point_in_polygon, shoelace_area, signed_area, triangulate, winding_number};
+pub use collision::{collide, intersection_points, overlaps, Collision};
+pub use index::{Bounded, ItemId, QuadTree};
+
+use std::f64::consts::PI;
+
+use crate::num::Num;
+
+/// A plane shape whose measurements are of type `T`.
+pub trait Shape<T> {
+    /// Lowercase name of the kind of shape, e.g. `"circle"`.
+    fn kind(&self) -> &'static str;
+    /// Number of straight sides, or `None` for curved shapes.
+    fn number_of_sides(&self) -> Option<usize>;
+    fn area(&self) -> T;
+    /// Length of the boundary.
+    fn perimeter(&self) -> T;
+    /// The centroid; the center of symmetry for symmetric shapes.
+    fn center(&self) -> (T, T);
+    /// The smallest axis-aligned box containing the shape.
+    fn bounding_box(&self) -> BoundingBox<T>;
+    /// Whether `point` is inside the shape or on its boundary.
+    fn contains(&self, point: (T, T)) -> bool;
+    /// The boundary, as used for collision detection.
+    fn outline(&self) -> Outline<T>;
+
+    /// Moves the shape by `(dx, dy)`.
+    fn translate(&mut self, dx: T, dy: T);
+    /// Multiplies every length by `factor`, keeping the center fixed; a negative factor also
+    /// reflects the shape through its center.
+    fn scale(&mut self, factor: T);
+    /// Turns the shape counterclockwise by `angle` radians about its center.
+    fn rotate(&mut self, angle: T);
+}
+
+/// An axis-aligned box given by its lowest and highest corners.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox<T = f64> {
+    pub min: (T, T),
+    pub max: (T, T)
+}
+
+impl<T: Num + PartialOrd> BoundingBox<T> {
+    pub fn new(min: (T, T), max: (T, T)) -> BoundingBox<T> {
+        BoundingBox{min, max}
+    }
+
+    pub fn width(&self) -> T {
+        self.max.0 - self.min.0
+    }
+
+    pub fn height(&self) -> T {
+        self.max.1 - self.min.1
+    }
+
+    /// Whether `point` is inside the box or on its boundary.
+    pub fn contains(&self, point: (T, T)) -> bool {
+        self.min.0 <= point.0 && point.0 <= self.max.0 && self.min.1 <= point.1 && point.1 <= self.max.1
+    }
+
+    /// Whether the two boxes overlap or touch.
+    pub fn intersects(&self, other: &BoundingBox<T>) -> bool {
+        self.min.0 <= other.max.0 && other.min.0 <= self.max.0
+            && self.min.1 <= other.max.1 && other.min.1 <= self.max.1
+    }
+
+    /// Whether `other` lies wholly inside this box, boundaries included.
+    pub fn encloses(&self, other: &BoundingBox<T>) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// The smallest box containing both boxes.
+    pub fn union(&self, other: &BoundingBox<T>) -> BoundingBox<T> {
+        let min = |a: T, b: T| if b < a { b } else { a };
+        let max = |a: T, b: T| if b > a { b } else { a };
+        BoundingBox{
+            min: (min(self.min.0, other.min.0), min(self.min.1, other.min.1)),
+            max: (max(self.max.0, other.max.0), max(self.max.1, other.max.1))
+        }
+    }
+}
+
+impl BoundingBox<f64> {
+    /// The smallest box containing every point, or `None` if there are none.
+    pub fn of_points<I: IntoIterator<Item = (f64, f64)>>(points: I) -> Option<BoundingBox<f64>> {
+        points.into_iter().fold(None, |bounds, (x, y)| Some(match bounds {
+            None => BoundingBox{min: (x, y), max: (x, y)},
+            Some(BoundingBox{min, max}) => BoundingBox{
+                min: (min.0.min(x), min.1.min(y)),
+                max: (max.0.max(x), max.1.max(y))
+            }
+        }))
+    }
+
+    /// The distance from `point` to the nearest point of the box, 0 if inside.
+    pub fn distance_to(&self, point: (f64, f64)) -> f64 {
+        let dx = (self.min.0 - point.0).max(point.0 - self.max.0).max(0.0);
+        let dy = (self.min.1 - point.1).max(point.1 - self.max.1).max(0.0);
+        dx.hypot(dy)
+    }
+}
+
+/// The boundary of a shape, reduced to the two cases collision detection needs.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outline<T = f64> {
+    Circle { center: (T, T), radius: T },
+    /// Corners in order. Ellipses are approximated by [`ELLIPSE_OUTLINE_SIDES`] inscribed sides.
+    Polygon(Vec<(T, T)>)
+}
+
+/// How many sides the polygon approximating an ellipse's [`Outline`] has.
+pub const ELLIPSE_OUTLINE_SIDES: usize = 64;
+
+/// A circle given by its center and radius.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle {
+    pub center: (f64, f64),
+    pub radius: f64
+}
+
+/// A rectangle given by two opposite corners, then turned by `rotation` about its center.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rectangle {
+    pub lower_left: (f64, f64),
+    pub upper_right: (f64, f64),
+    pub rotation: f64
+}
+
+/// A square given by its lower left corner and side length, then turned by `rotation` about
+/// its center.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Square {
+    pub lower_left: (f64, f64),
+    pub side: f64,
+    pub rotation: f64
+}
+
+/// A triangle given by its three corners, in either order.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle {
+    pub vertices: [(f64, f64); 3]
+}
+
+/// An ellipse given by its center and its semi-axes along x and y, then turned by `rotation`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipse {
+    pub center: (f64, f64),
+    pub radii: (f64, f64),
+    pub rotation: f64
+}
+
+/// A regular polygon with `sides` sides whose corners lie on a circle of `radius` around
+/// `center`; the first corner is at angle `rotation`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegularPolygon {
+    pub center: (f64, f64),
+    pub sides: usize,
+    pub radius: f64,
+    pub rotation: f64
+}
+
+/// A simple polygon (one whose edges don't cross) given by its corners in order, either way
+/// round. The last corner connects back to the first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon {
+    pub vertices: Vec<(f64, f64)>
+}
+
+impl Circle {
+    pub fn new(center: (f64, f64), radius: f64) -> Circle {
+        Circle{center, radius}
+    }
+}
+
+impl Rectangle {
+    /// An axis-aligned rectangle.
+    pub fn new(lower_left: (f64, f64), upper_right: (f64, f64)) -> Rectangle {
+        Rectangle{lower_left, upper_right, rotation: 0.0}
+    }
+
+    pub fn width(&self) -> f64 {
+        (self.upper_right.0 - self.lower_left.0).abs()
+    }
+
+    pub fn height(&self) -> f64 {
+        (self.upper_right.1 - self.lower_left.1).abs()
+    }
+
+    /// The corners, counterclockwise from the lower left one before rotation.
+    pub fn vertices(&self) -> [(f64, f64); 4] {
+        box_vertices(self.lower_left, self.upper_right, self.rotation)
+    }
+}
+
+impl Square {
+    /// An axis-aligned square.
+    pub fn new(lower_left: (f64, f64), side: f64) -> Square {
+        Square{lower_left, side, rotation: 0.0}
+    }
+
+    /// The corners, counterclockwise from the lower left one before rotation.
+    pub fn vertices(&self) -> [(f64, f64); 4] {
+        let (x, y) = self.lower_left;
+        box_vertices(self.lower_left, (x + self.side, y + self.side), self.rotation)
+    }
+}
+
+impl Triangle {
+    pub fn new(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> Triangle {
+        Triangle{vertices: [a, b, c]}
+    }
+}
+
+impl Ellipse {
+    /// An ellipse with its axes along x and y.
+    pub fn new(center: (f64, f64), radii: (f64, f64)) -> Ellipse {
+        Ellipse{center, radii, rotation: 0.0}
+    }
+}
+
+impl RegularPolygon {
+    /// A regular polygon with a corner straight to the right of its center.
+    ///
+    /// # Panics
+    ///
+    /// If there are fewer than three sides.
+    pub fn new(center: (f64, f64), sides: usize, radius: f64) -> RegularPolygon {
+        assert!(sides >= 3, "a polygon needs at least three sides, got {}", sides);
+        RegularPolygon{center, sides, radius, rotation: 0.0}
+    }
+
+    pub fn side_length(&self) -> f64 {
+        2.0 * self.radius * (PI / self.sides as f64).sin()
+    }
+
+    /// The corners, counterclockwise from the one at angle `rotation`.
+    pub fn vertices(&self) -> Vec<(f64, f64)> {
+        (0..self.sides).map(|k| {
+            let angle = self.rotation + 2.0 * PI * k as f64 / self.sides as f64;
+            (self.center.0 + self.radius * angle.cos(), self.center.1 + self.radius * angle.sin())
+        }).collect()
+    }
+}
+
+impl Polygon {
+    /// # Panics
+    ///
+    /// If there are fewer than three vertices.
+    pub fn new(vertices: Vec<(f64, f64)>) -> Polygon {
+        assert!(vertices.len() >= 3, "a polygon needs at least three vertices, got {}", vertices.len());
+        Polygon{vertices}
+    }
+
+    // twice the signed area; positive when the vertices run counterclockwise
+    fn signed_double_area(&self) -> f64 {
+        2.0 * signed_area(&self.vertices)
+    }
+}
+
+impl Shape<f64> for Circle {
+    fn kind(&self) -> &'static str { "circle" }
+    fn number_of_sides(&self) -> Option<usize> { None }
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+    fn perimeter(&self) -> f64 {
+        2.0 * PI * self.radius
+    }
+    fn center(&self) -> (f64, f64) { self.center }
+    fn bounding_box(&self) -> BoundingBox {
+        let (x, y) = self.center;
+        let r = self.radius.abs();
+        BoundingBox::new((x - r, y - r), (x + r, y + r))
+    }
+    fn contains(&self, point: (f64, f64)) -> bool {
+        distance(self.center, point) <= self.radius.abs()
+    }
+    fn outline(&self) -> Outline {
+        Outline::Circle{center: self.center, radius: self.radius.abs()}
+    }
+    fn translate(&mut self, dx: f64, dy: f64) {
+        self.center = offset(self.center, dx, dy)
+    }
+    fn scale(&mut self, factor: f64) {
+        self.radius *= factor.abs()
+    }
+    fn rotate(&mut self, _angle: f64) {}
+}
+
+impl Shape<f64> for Rectangle {
+    fn kind(&self) -> &'static str { "rectangle" }
+    fn number_of_sides(&self) -> Option<usize> { Some(4) }
+    fn area(&self) -> f64 {
+        self.width() * self.height()
+    }
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.width() + self.height())
+    }
+    fn center(&self) -> (f64, f64) {
+        let horizontal = (self.upper_right.0 + self.lower_left.0) / 2.0;
+        let vertical = (self.upper_right.1 + self.lower_left.1) / 2.0;
+        (horizontal, vertical)
+    }
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::of_points(self.vertices().iter().copied()).unwrap()
+    }
+    fn contains(&self, point: (f64, f64)) -> bool {
+        box_contains(self.center(), self.width(), self.height(), self.rotation, point)
+    }
+    fn outline(&self) -> Outline {
+        Outline::Polygon(self.vertices().to_vec())
+    }
+    fn translate(&mut self, dx: f64, dy: f64) {
+        self.lower_left = offset(self.lower_left, dx, dy);
+        self.upper_right = offset(self.upper_right, dx, dy)
+    }
+    fn scale(&mut self, factor: f64) {
+        // rectangles are symmetric about their center, so reflecting changes nothing
+        let center = self.center();
+        self.lower_left = scale_about(self.lower_left, center, factor.abs());
+        self.upper_right = scale_about(self.upper_right, center, factor.abs())
+    }
+    fn rotate(&mut self, angle: f64) {
+        self.rotation += angle
+    }
+}
+
+impl Shape<f64> for Square {
+    fn kind(&self) -> &'static str { "square" }
+    fn number_of_sides(&self) -> Option<usize> { Some(4) }
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+    fn perimeter(&self) -> f64 {
+        4.0 * self.side.abs()
+    }
+    fn center(&self) -> (f64, f64) {
+        let half = self.side / 2.0;
+        (self.lower_left.0 + half, self.lower_left.1 + half)
+    }
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::of_points(self.vertices().iter().copied()).unwrap()
+    }
+    fn contains(&self, point: (f64, f64)) -> bool {
+        box_contains(self.center(), self.side.abs(), self.side.abs(), self.rotation, point)
+    }
+    fn outline(&self) -> Outline {
+        Outline::Polygon(self.vertices().to_vec())
+    }
+    fn translate(&mut self, dx: f64, dy: f64) {
+        self.lower_left = offset(self.lower_left, dx, dy)
+    }
+    fn scale(&mut self, factor: f64) {
+        let center = self.center();
+        self.side *= factor.abs();
+        self.lower_left = (center.0 - self.side / 2.0, center.1 - self.side / 2.0)
+    }
+    fn rotate(&mut self, angle: f64) {
+        self.rotation += angle
+    }
+}
+
+impl Shape<f64> for Triangle {
+    fn kind(&self) -> &'static str { "triangle" }
+    fn number_of_sides(&self) -> Option<usize> { Some(3) }
+    fn area(&self) -> f64 {
+        let [a, b, c] = self.vertices;
+        cross(a, b, c).abs() / 2.0
+    }
+    fn perimeter(&self) -> f64 {
+        edges(&self.vertices).map(|(p, q)| distance(p, q)).sum()
+    }
+    fn center(&self) -> (f64, f64) {
+        let [a, b, c] = self.vertices;
+        ((a.0 + b.0 + c.0) / 3.0, (a.1 + b.1 + c.1) / 3.0)
+    }
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::of_points(self.vertices.iter().copied()).unwrap()
+    }
+    fn contains(&self, point: (f64, f64)) -> bool {
+        let [a, b, c] = self.vertices;
+        // inside when the point is on the same side of every edge
+        let signs = [cross(a, b, point), cross(b, c, point), cross(c, a, point)];
+        signs.iter().all(|&s| s >= 0.0) || signs.iter().all(|&s| s <= 0.0)
+    }
+    fn outline(&self) -> Outline {
+        Outline::Polygon(self.vertices.to_vec())
+    }
+    fn translate(&mut self, dx: f64, dy: f64) {
+        for v in self.vertices.iter_mut() {
+            *v = offset(*v, dx, dy)
+        }
+    }
+    fn scale(&mut self, factor: f64) {
+        let center = self.center();
+        for v in self.vertices.iter_mut() {
+            *v = scale_about(*v, center, factor)
+        }
+    }
+    fn rotate(&mut self, angle: f64) {
+        let center = self.center();
+        for v in self.vertices.iter_mut() {
+            *v = rotate_about(*v, center, angle)
+        }
+    }
+}
+
+impl Shape<f64> for Ellipse {
+    fn kind(&self) -> &'static str { "ellipse" }
+    fn number_of_sides(&self) -> Option<usize> { None }
+    fn area(&self) -> f64 {
+        PI * (self.radii.0 * self.radii.1).abs()
+    }
+    // exact, by the arithmetic-geometric mean (see Gauss's series for the complete elliptic
+    // integral of the second kind); converges in a handful of steps
+    fn perimeter(&self) -> f64 {
+        let (mut a, mut b) = (self.radii.0.abs().max(self.radii.1.abs()), self.radii.0.abs().min(self.radii.1.abs()));
+        // a flat ellipse is a segment traversed twice; the iteration below would never converge
+        if b == 0.0 { return 4.0 * a }
+        let mut total = (a * a + b * b) / 2.0;
+        let mut power = 0.5;
+        while a - b > f64::EPSILON * a {
+            let c = (a - b) / 2.0;
+            let (next_a, next_b) = ((a + b) / 2.0, (a * b).sqrt());
+            power *= 2.0;
+            total -= power * c * c;
+            a = next_a;
+            b = next_b
+        }
+        2.0 * PI * total / a
+    }
+    fn center(&self) -> (f64, f64) { self.center }
+    fn bounding_box(&self) -> BoundingBox {
+        let (a, b) = self.radii;
+        let (sin, cos) = self.rotation.sin_cos();
+        let half_width = (a * a * cos * cos + b * b * sin * sin).sqrt();
+        let half_height = (a * a * sin * sin + b * b * cos * cos).sqrt();
+        let (x, y) = self.center;
+        BoundingBox::new((x - half_width, y - half_height), (x + half_width, y + half_height))
+    }
+    fn contains(&self, point: (f64, f64)) -> bool {
+        let (x, y) = local(point, self.center, self.rotation);
+        let (a, b) = self.radii;
+        (x / a).powi(2) + (y / b).powi(2) <= 1.0
+    }
+    fn outline(&self) -> Outline {
+        let (sin, cos) = self.rotation.sin_cos();
+        let (a, b) = self.radii;
+        Outline::Polygon((0..ELLIPSE_OUTLINE_SIDES).map(|k| {
+            let (y, x) = (2.0 * PI * k as f64 / ELLIPSE_OUTLINE_SIDES as f64).sin_cos();
+            let (x, y) = (a * x, b * y);
+            (self.center.0 + x * cos - y * sin, self.center.1 + x * sin + y * cos)
+        }).collect())
+    }
+    fn translate(&mut self, dx: f64, dy: f64) {
+        self.center = offset(self.center, dx, dy)
+    }
+    fn scale(&mut self, factor: f64) {
+        self.radii = (self.radii.0 * factor.abs(), self.radii.1 * factor.abs())
+    }
+    fn rotate(&mut self, angle: f64) {
+        self.rotation += angle
+    }
+}
+
+impl Shape<f64> for RegularPolygon {
+    fn kind(&self) -> &'static str { "regular polygon" }
+    fn number_of_sides(&self) -> Option<usize> { Some(self.sides) }
+    fn area(&self) -> f64 {
+        let n = self.sides as f64;
+        n * self.radius * self.radius * (2.0 * PI / n).sin() / 2.0
+    }
+    fn perimeter(&self) -> f64 {
+        self.sides as f64 * self.side_length().abs()
+    }
+    fn center(&self) -> (f64, f64) { self.center }
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::of_points(self.vertices()).unwrap()
+    }
+    fn contains(&self, point: (f64, f64)) -> bool {
+        polygon_contains(&self.vertices(), point)
+    }
+    fn outline(&self) -> Outline {
+        Outline::Polygon(self.vertices())
+    }
+    fn translate(&mut self, dx: f64, dy: f64) {
+        self.center = offset(self.center, dx, dy)
+    }
+    fn scale(&mut self, factor: f64) {
+        self.radius *= factor.abs();
+        if factor < 0.0 { self.rotation += PI }
+    }
+    fn rotate(&mut self, angle: f64) {
+        self.rotation += angle
+    }
+}
+
+impl Shape<f64> for Polygon {
+    fn kind(&self) -> &'static str { "polygon" }
+    fn number_of_sides(&self) -> Option<usize> { Some(self.vertices.len()) }
+    fn area(&self) -> f64 {
+        self.signed_double_area().abs() / 2.0
+    }
+    fn perimeter(&self) -> f64 {
+        edges(&self.vertices).map(|(p, q)| distance(p, q)).sum()
+    }
+    // the centroid of the enclosed area, which need not be the mean of the vertices
+    fn center(&self) -> (f64, f64) {
+        let double_area = self.signed_double_area();
+        if double_area == 0.0 {
+            let n = self.vertices.len() as f64;
+            let (x, y) = self.vertices.iter().fold((0.0, 0.0), |(x, y), v| (x + v.0, y + v.1));
+            return (x / n, y / n)
+        }
+        let (x, y) = edges(&self.vertices).fold((0.0, 0.0), |(x, y), (p, q)| {
+            let w = p.0 * q.1 - q.0 * p.1;
+            (x + (p.0 + q.0) * w, y + (p.1 + q.1) * w)
+        });
+        (x / (3.0 * double_area), y / (3.0 * double_area))
+    }
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::of_points(self.vertices.iter().copied()).unwrap()
+    }
+    fn contains(&self, point: (f64, f64)) -> bool {
+        polygon_contains(&self.vertices, point)
+    }
+    fn outline(&self) -> Outline {
+        Outline::Polygon(self.vertices.clone())
+    }
+    fn translate(&mut self, dx: f64, dy: f64) {
+        for v in self.vertices.iter_mut() {
+            *v = offset(*v, dx, dy)
+        }
+    }
+    fn scale(&mut self, factor: f64) {
+        let center = self.center();
+        for v in self.vertices.iter_mut() {
+            *v = scale_about(*v, center, factor)
+        }
+    }
+    fn rotate(&mut self, angle: f64) {
+        let center = self.center();
+        for v in self.vertices.iter_mut() {
+            *v = rotate_about(*v, center, angle)
+        }
+    }
+}
+
+// point helpers
+
+fn offset(p: (f64, f64), dx: f64, dy: f64) -> (f64, f64) {
+    (p.0 + dx, p.1 + dy)
+}
+
+fn distance(p: (f64, f64), q: (f64, f64)) -> f64 {
+    (q.0 - p.0).hypot(q.1 - p.1)
+}
+
+fn scale_about(p: (f64, f64), center: (f64, f64), factor: f64) -> (f64, f64) {
+    (center.0 + (p.0 - center.0) * factor, center.1 + (p.1 - center.1) * factor)
+}
+
+fn rotate_about(p: (f64, f64), center: (f64, f64), angle: f64) -> (f64, f64) {
+    // keeps unrotated shapes exact
+    if angle == 0.0 { return p }
+    let (sin, cos) = angle.sin_cos();
+    let (x, y) = (p.0 - center.0, p.1 - center.1);
+    (center.0 + x * cos - y * sin, center.1 + x * sin + y * cos)
+}
+
+// p relative to a frame with the given origin and rotation
+fn local(p: (f64, f64), origin: (f64, f64), rotation: f64) -> (f64, f64) {
+    let (x, y) = rotate_about(p, origin, -rotation);
+    (x - origin.0, y - origin.1)
+}
+
+// twice the signed area of the triangle abc; positive when it turns counterclockwise
+fn cross(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+// each edge of a closed polygon, including the one from the last vertex back to the first
+fn edges(vertices: &[(f64, f64)]) -> impl Iterator<Item = ((f64, f64), (f64, f64))> + '_ {
+    vertices.iter().copied().zip(vertices.iter().copied().cycle().skip(1))
+}
+
+// the corners of the box with opposite corners a and b, turned about its center
+fn box_vertices(a: (f64, f64), b: (f64, f64), rotation: f64) -> [(f64, f64); 4] {
+    let (left, right) = (a.0.min(b.0), a.0.max(b.0));
+    let (bottom, top) = (a.1.min(b.1), a.1.max(b.1));
+    let center = ((left + right) / 2.0, (bottom + top) / 2.0);
+    [(left, bottom), (right, bottom), (right, top), (left, top)]
+        .map(|corner| rotate_about(corner, center, rotation))
+}
+
+fn box_contains(center: (f64, f64), width: f64, height: f64, rotation: f64, point: (f64, f64)) -> bool {
+    let (x, y) = local(point, center, rotation);
+    x.abs() <= width / 2.0 && y.abs() <= height / 2.0
+}
+
+// whether point lies on the segment pq, ends included
+fn on_segment(p: (f64, f64), q: (f64, f64), point: (f64, f64)) -> bool {
+    cross(p, q, point) == 0.0
+        && point.0 >= p.0.min(q.0) && point.0 <= p.0.max(q.0)
+        && point.1 >= p.1.min(q.1) && point.1 <= p.1.max(q.1)
+}
+
+// even-odd ray casting, with points on an edge counted as inside
+fn polygon_contains(vertices: &[(f64, f64)], point: (f64, f64)) -> bool {
+    let mut inside = false;
+    for (p, q) in edges(vertices) {
+        if on_segment(p, q, point) { return true }
+        if (p.1 > point.1) != (q.1 > point.1) {
+            let x = p.0 + (point.1 - p.1) / (q.1 - p.1) * (q.0 - p.0);
+            if point.0 < x { inside = !inside }
+        }
+    }
+    inside
+}
@@ -0,0 +1,136 @@
+//! Computational geometry on polygons given as lists of vertices.
+//!
+//! A polygon's last vertex joins back to its first, and may run either way round. Degenerate
+//! input is allowed throughout: repeated vertices, vertices in a straight line and polygons with
+//! no area give the answers described on each function rather than panicking.
+
+use super::{cross, edges, on_segment};
+
+/// The convex hull by Andrew's monotone chain: the corners of the smallest convex polygon
+/// containing every point, counterclockwise from the lowest of the leftmost points.
+///
+/// Repeated points appear once and points along an edge of the hull are left out, so points in
+/// a straight line give just the two ends, and a single distinct point gives itself.
+pub fn convex_hull(points: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|p, q| p.0.total_cmp(&q.0).then(p.1.total_cmp(&q.1)));
+    sorted.dedup();
+    if sorted.len() < 3 { return sorted }
+
+    // the lower hull from left to right, then the upper hull back again; each ends where the
+    // other starts, so their last points are dropped
+    let mut hull: Vec<(f64, f64)> = Vec::new();
+    for pass in 0..2 {
+        let start = hull.len();
+        for &p in sorted.iter() {
+            while hull.len() >= start + 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+                hull.pop();
+            }
+            hull.push(p)
+        }
+        hull.pop();
+        if pass == 0 { sorted.reverse() }
+    }
+    hull
+}
+
+/// The area enclosed by the shoelace formula, positive when the vertices run counterclockwise
+/// and negative when clockwise. The parts of a self-intersecting polygon that run opposite ways
+/// cancel out.
+pub fn signed_area(vertices: &[(f64, f64)]) -> f64 {
+    edges(vertices).map(|(p, q)| p.0 * q.1 - q.0 * p.1).sum::<f64>() / 2.0
+}
+
+/// The area enclosed by the shoelace formula, whichever way round the vertices run. It is the
+/// same as [`Shape::area`](super::Shape::area) for a [`Polygon`](super::Polygon).
+pub fn shoelace_area(vertices: &[(f64, f64)]) -> f64 {
+    signed_area(vertices).abs()
+}
+
+/// How many times the boundary winds counterclockwise around `point`; negative when it winds
+/// clockwise, and 0 outside.
+///
+/// For a point on the boundary the answer depends on which edges it lies on, so it should be
+/// tested with [`point_in_polygon`] instead.
+pub fn winding_number(vertices: &[(f64, f64)], point: (f64, f64)) -> i32 {
+    // count edges crossing the horizontal ray to the right of the point, upwards crossings
+    // with the point on their left and downwards ones with it on their right
+    let mut winding = 0;
+    for (p, q) in edges(vertices) {
+        if p.1 <= point.1 {
+            if q.1 > point.1 && cross(p, q, point) > 0.0 { winding += 1 }
+        } else if q.1 <= point.1 && cross(p, q, point) < 0.0 {
+            winding -= 1
+        }
+    }
+    winding
+}
+
+/// Whether `point` lies on the boundary or has a nonzero [`winding_number`], so that the loops
+/// of a self-intersecting polygon count as inside however many times they overlap.
+pub fn point_in_polygon(vertices: &[(f64, f64)], point: (f64, f64)) -> bool {
+    edges(vertices).any(|(p, q)| on_segment(p, q, point)) || winding_number(vertices, point) != 0
+}
+
+/// Cuts a simple polygon into triangles by ear clipping, returning the indices of the corners
+/// of each triangle, counterclockwise.
+///
+/// Repeated vertices and vertices in a straight line with their neighbours are skipped, as
+/// they would only give triangles with no area. So every triangle has positive area, there are
+/// at most `n - 2` of them for `n` vertices, and a polygon with no area gives none. The
+/// triangles of a self-intersecting polygon cover it only approximately.
+pub fn triangulate(vertices: &[(f64, f64)]) -> Vec<[usize; 3]> {
+    // work counterclockwise, so that the ears are the convex corners
+    let mut remaining: Vec<usize> = (0..vertices.len()).collect();
+    if signed_area(vertices) < 0.0 { remaining.reverse() }
+    // the corner at position k of what remains, as indices and as points
+    let corner = |remaining: &[usize], k: usize| {
+        let n = remaining.len();
+        let (prev, cur, next) = (remaining[(k + n - 1) % n], remaining[k], remaining[(k + 1) % n]);
+        ([prev, cur, next], (vertices[prev], vertices[cur], vertices[next]))
+    };
+
+    let mut triangles = Vec::new();
+    let mut i = 0;
+    let mut since_last_clip = 0;
+    while remaining.len() >= 3 {
+        // repeats of the previous vertex and straight lines go first, as clipping them would
+        // give triangles with no area; clipping an ear can straighten its neighbours
+        let flat = (0..remaining.len()).find(|&k| {
+            let (_, (a, b, c)) = corner(&remaining, k);
+            cross(a, b, c) == 0.0
+        });
+        if let Some(k) = flat {
+            remaining.remove(k);
+            continue
+        }
+
+        let n = remaining.len();
+        i %= n;
+        let (indices, (a, b, c)) = corner(&remaining, i);
+        // an ear is a convex corner whose triangle holds no other vertex; if a full pass finds
+        // none, the polygon must cross itself, and any convex corner will do
+        let desperate = since_last_clip > n;
+        let is_ear = cross(a, b, c) > 0.0 && (desperate || !remaining.iter().any(|&j| {
+            let p = vertices[j];
+            !indices.contains(&j) && p != a && p != b && p != c && in_triangle(a, b, c, p)
+        }));
+        if is_ear {
+            triangles.push(indices);
+            remaining.remove(i);
+            since_last_clip = 0;
+        } else if desperate && since_last_clip > 2 * n {
+            // not even a convex corner: what is left has no area
+            break
+        } else {
+            i += 1;
+            since_last_clip += 1
+        }
+    }
+    triangles
+}
+
+// whether p lies inside or on the counterclockwise triangle abc
+fn in_triangle(a: (f64, f64), b: (f64, f64), c: (f64, f64), p: (f64, f64)) -> bool {
+    cross(a, b, p) >= 0.0 && cross(b, c, p) >= 0.0 && cross(c, a, p) >= 0.0
+}
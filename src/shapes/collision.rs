@@ -0,0 +1,203 @@
+//! Overlap tests, intersection points and penetration depth for any two shapes.
+//!
+//! Everything works on the shapes' [`Outline`]s, so any pair of `&dyn Shape<f64>` can be
+//! compared. Overlap and intersection points are exact for circles and polygons, convex or not.
+//! Penetration depth comes from the separating axis theorem, which needs convex shapes: a
+//! concave polygon is treated as its convex hull there.
+
+use super::{convex_hull, cross, distance, edges, polygon_contains, Outline, Shape};
+
+/// How two overlapping shapes meet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Collision {
+    /// Where the boundaries cross; empty if one shape lies wholly inside the other.
+    pub points: Vec<(f64, f64)>,
+    /// How far the second shape must move along `normal` to stop overlapping.
+    pub depth: f64,
+    /// Unit vector along which the shapes are pushed apart, pointing from the first shape
+    /// towards the second.
+    pub normal: (f64, f64)
+}
+
+/// Whether the shapes overlap or touch.
+pub fn overlaps(a: &dyn Shape<f64>, b: &dyn Shape<f64>) -> bool {
+    let (a, b) = (a.outline(), b.outline());
+    a.bounding_box().intersects(&b.bounding_box()) && outlines_overlap(&a, &b)
+}
+
+/// The points where the boundaries of the two shapes cross or touch.
+pub fn intersection_points(a: &dyn Shape<f64>, b: &dyn Shape<f64>) -> Vec<(f64, f64)> {
+    crossings(&a.outline(), &b.outline())
+}
+
+/// How the shapes collide, or `None` if they don't overlap. Touching shapes collide with
+/// depth zero.
+pub fn collide(a: &dyn Shape<f64>, b: &dyn Shape<f64>) -> Option<Collision> {
+    let (a, b) = (a.outline(), b.outline());
+    if !a.bounding_box().intersects(&b.bounding_box()) || !outlines_overlap(&a, &b) { return None }
+    let (depth, normal) = penetration(&a, &b);
+    Some(Collision{points: crossings(&a, &b), depth, normal})
+}
+
+impl Outline {
+    fn bounding_box(&self) -> super::BoundingBox {
+        match self {
+            Outline::Circle{center: (x, y), radius: r} =>
+                super::BoundingBox::new((x - r, y - r), (x + r, y + r)),
+            Outline::Polygon(vertices) => super::BoundingBox::of_points(vertices.iter().copied()).unwrap()
+        }
+    }
+
+    fn contains(&self, point: (f64, f64)) -> bool {
+        match self {
+            Outline::Circle{center, radius} => distance(*center, point) <= *radius,
+            Outline::Polygon(vertices) => polygon_contains(vertices, point)
+        }
+    }
+
+    // any point of the outline, to test whether it lies inside another one
+    fn some_point(&self) -> (f64, f64) {
+        match self {
+            Outline::Circle{center, radius} => (center.0 + radius, center.1),
+            Outline::Polygon(vertices) => vertices[0]
+        }
+    }
+}
+
+// with no crossing boundaries, the shapes overlap only if one is inside the other
+fn outlines_overlap(a: &Outline, b: &Outline) -> bool {
+    !crossings(a, b).is_empty() || a.contains(b.some_point()) || b.contains(a.some_point())
+}
+
+fn crossings(a: &Outline, b: &Outline) -> Vec<(f64, f64)> {
+    let points = match (a, b) {
+        (Outline::Circle{center: c1, radius: r1}, Outline::Circle{center: c2, radius: r2}) =>
+            circle_circle(*c1, *r1, *c2, *r2),
+        (Outline::Circle{center, radius}, Outline::Polygon(vertices))
+        | (Outline::Polygon(vertices), Outline::Circle{center, radius}) =>
+            edges(vertices).flat_map(|(p, q)| segment_circle(p, q, *center, *radius)).collect(),
+        (Outline::Polygon(first), Outline::Polygon(second)) =>
+            edges(first).flat_map(|(p, q)| edges(second).flat_map(move |(r, s)| segment_segment(p, q, r, s))).collect()
+    };
+    dedup(points)
+}
+
+// a crossing at a shared vertex is found once for each edge meeting there
+fn dedup(points: Vec<(f64, f64)>) -> Vec<(f64, f64)> {
+    let mut unique: Vec<(f64, f64)> = Vec::new();
+    for p in points {
+        let scale = p.0.abs().max(p.1.abs()).max(1.0);
+        if !unique.iter().any(|&q| distance(p, q) <= 1e-9 * scale) { unique.push(p) }
+    }
+    unique
+}
+
+fn circle_circle(c1: (f64, f64), r1: f64, c2: (f64, f64), r2: f64) -> Vec<(f64, f64)> {
+    let d = distance(c1, c2);
+    if d > r1 + r2 || d < (r1 - r2).abs() || d == 0.0 { return Vec::new() }
+    // distance from c1 to the chord joining the crossings, and half the chord's length
+    let along = (d * d + r1 * r1 - r2 * r2) / (2.0 * d);
+    let half_chord = (r1 * r1 - along * along).max(0.0).sqrt();
+    let (ux, uy) = ((c2.0 - c1.0) / d, (c2.1 - c1.1) / d);
+    let mid = (c1.0 + along * ux, c1.1 + along * uy);
+    vec![(mid.0 - half_chord * uy, mid.1 + half_chord * ux), (mid.0 + half_chord * uy, mid.1 - half_chord * ux)]
+}
+
+fn segment_circle(p: (f64, f64), q: (f64, f64), center: (f64, f64), radius: f64) -> Vec<(f64, f64)> {
+    // |p + t(q - p) - center|² = r², a quadratic in t
+    let (dx, dy) = (q.0 - p.0, q.1 - p.1);
+    let (fx, fy) = (p.0 - center.0, p.1 - center.1);
+    let a = dx * dx + dy * dy;
+    let b = 2.0 * (fx * dx + fy * dy);
+    let c = fx * fx + fy * fy - radius * radius;
+    let discriminant = b * b - 4.0 * a * c;
+    if a == 0.0 || discriminant < 0.0 { return Vec::new() }
+    let root = discriminant.sqrt();
+    [(-b - root) / (2.0 * a), (-b + root) / (2.0 * a)].iter()
+        .filter(|&&t| (0.0..=1.0).contains(&t))
+        .map(|&t| (p.0 + t * dx, p.1 + t * dy))
+        .collect()
+}
+
+// the crossing of segments pq and rs; for collinear overlapping segments, the ends of the overlap
+fn segment_segment(p: (f64, f64), q: (f64, f64), r: (f64, f64), s: (f64, f64)) -> Vec<(f64, f64)> {
+    let (d1, d2) = ((q.0 - p.0, q.1 - p.1), (s.0 - r.0, s.1 - r.1));
+    let denominator = d1.0 * d2.1 - d1.1 * d2.0;
+    if denominator == 0.0 {
+        if cross(p, q, r) != 0.0 { return Vec::new() }
+        // collinear: keep whichever endpoints lie on the other segment
+        let on = |x: (f64, f64), a: (f64, f64), b: (f64, f64)|
+            x.0 >= a.0.min(b.0) && x.0 <= a.0.max(b.0) && x.1 >= a.1.min(b.1) && x.1 <= a.1.max(b.1);
+        return [(p, r, s), (q, r, s), (r, p, q), (s, p, q)].iter()
+            .filter(|&&(x, a, b)| on(x, a, b))
+            .map(|&(x, _, _)| x)
+            .collect()
+    }
+    let (ex, ey) = (r.0 - p.0, r.1 - p.1);
+    let t = (ex * d2.1 - ey * d2.0) / denominator;
+    let u = (ex * d1.1 - ey * d1.0) / denominator;
+    if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+        vec![(p.0 + t * d1.0, p.1 + t * d1.1)]
+    } else {
+        Vec::new()
+    }
+}
+
+// separating axis theorem: of all the candidate axes, the one along which the shapes overlap
+// least gives the depth and normal
+fn penetration(a: &Outline, b: &Outline) -> (f64, (f64, f64)) {
+    if let (Outline::Circle{center: c1, radius: r1}, Outline::Circle{center: c2, radius: r2}) = (a, b) {
+        let d = distance(*c1, *c2);
+        let normal = if d == 0.0 { (1.0, 0.0) } else { ((c2.0 - c1.0) / d, (c2.1 - c1.1) / d) };
+        return (r1 + r2 - d, normal)
+    }
+
+    let (a, b) = (convex(a), convex(b));
+    let mut axes: Vec<(f64, f64)> = Vec::new();
+    for outline in [&a, &b] {
+        if let Outline::Polygon(vertices) = outline {
+            axes.extend(edges(vertices).map(|(p, q)| (q.1 - p.1, p.0 - q.0)))
+        }
+    }
+    // a circle against a polygon also needs the axis through the polygon's nearest corner
+    if let (Outline::Circle{center, ..}, Outline::Polygon(vertices))
+         | (Outline::Polygon(vertices), Outline::Circle{center, ..}) = (&a, &b) {
+        let nearest = vertices.iter().copied()
+            .min_by(|&u, &v| distance(u, *center).total_cmp(&distance(v, *center)))
+            .unwrap();
+        axes.push((nearest.0 - center.0, nearest.1 - center.1))
+    }
+
+    let mut best = (f64::INFINITY, (1.0, 0.0));
+    for (x, y) in axes {
+        let length = x.hypot(y);
+        if length == 0.0 { continue }
+        let axis = (x / length, y / length);
+        let (min_a, max_a) = project(&a, axis);
+        let (min_b, max_b) = project(&b, axis);
+        // push b forwards along the axis, or backwards, whichever is shorter
+        let (depth, normal) = if max_a - min_b <= max_b - min_a {
+            (max_a - min_b, axis)
+        } else {
+            (max_b - min_a, (-axis.0, -axis.1))
+        };
+        if depth < best.0 { best = (depth, normal) }
+    }
+    best
+}
+
+fn project(outline: &Outline, axis: (f64, f64)) -> (f64, f64) {
+    let dot = |p: (f64, f64)| p.0 * axis.0 + p.1 * axis.1;
+    match outline {
+        Outline::Circle{center, radius} => (dot(*center) - radius, dot(*center) + radius),
+        Outline::Polygon(vertices) => vertices.iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &v| (lo.min(dot(v)), hi.max(dot(v))))
+    }
+}
+
+fn convex(outline: &Outline) -> Outline {
+    match outline {
+        Outline::Polygon(vertices) => Outline::Polygon(convex_hull(vertices)),
+        circle => circle.clone()
+    }
+}
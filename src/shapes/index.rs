@@ -0,0 +1,335 @@
+//! A quadtree for finding shapes by position without looking at every one.
+//!
+//! Each node covers a square-ish region and splits into four quarters once it holds more than
+//! [`NODE_CAPACITY`] items. An item lives in the smallest node whose region wholly contains its
+//! bounding box, so large items stay near the root and small ones sink to the leaves. Queries
+//! only visit the nodes whose regions reach the area asked about.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::iter::FromIterator;
+
+use super::{edges, BoundingBox, Circle, Ellipse, Outline, Polygon, Rectangle, RegularPolygon, Shape, Square, Triangle};
+
+/// How many items a node holds before it splits.
+pub const NODE_CAPACITY: usize = 8;
+
+/// How many times the region can be quartered; nodes this deep never split.
+pub const MAX_DEPTH: usize = 16;
+
+/// Anything with a position in the plane that a [`QuadTree`] can hold.
+///
+/// Every [`Shape<f64>`] is `Bounded`, measuring containment and distance exactly; other types
+/// need only give a bounding box, and are then treated as filling it.
+pub trait Bounded {
+    /// The smallest axis-aligned box containing the item.
+    fn bounds(&self) -> BoundingBox;
+
+    /// Whether `point` is inside the item or on its boundary.
+    fn contains_point(&self, point: (f64, f64)) -> bool {
+        self.bounds().contains(point)
+    }
+
+    /// The distance from `point` to the nearest part of the item, 0 if inside. It must be at
+    /// least the distance to the bounding box, or nearest-neighbour queries go wrong.
+    fn distance_to(&self, point: (f64, f64)) -> f64 {
+        self.bounds().distance_to(point)
+    }
+}
+
+// every shape, measuring containment and distance exactly
+macro_rules! shape_impls {
+    ($($t:ty)*) => {$(
+        impl Bounded for $t {
+            fn bounds(&self) -> BoundingBox {
+                self.bounding_box()
+            }
+
+            fn contains_point(&self, point: (f64, f64)) -> bool {
+                self.contains(point)
+            }
+
+            fn distance_to(&self, point: (f64, f64)) -> f64 {
+                if self.contains(point) { 0.0 } else { outline_distance(&self.outline(), point) }
+            }
+        }
+    )*}
+}
+
+shape_impls!(Circle Rectangle Square Triangle Ellipse RegularPolygon Polygon dyn Shape<f64>);
+
+// so that a tree can hold a mixture of shapes as Box<dyn Shape<f64>>, or borrow them
+impl<B: Bounded + ?Sized> Bounded for Box<B> {
+    fn bounds(&self) -> BoundingBox {
+        (**self).bounds()
+    }
+
+    fn contains_point(&self, point: (f64, f64)) -> bool {
+        (**self).contains_point(point)
+    }
+
+    fn distance_to(&self, point: (f64, f64)) -> f64 {
+        (**self).distance_to(point)
+    }
+}
+
+impl<B: Bounded + ?Sized> Bounded for &B {
+    fn bounds(&self) -> BoundingBox {
+        (**self).bounds()
+    }
+
+    fn contains_point(&self, point: (f64, f64)) -> bool {
+        (**self).contains_point(point)
+    }
+
+    fn distance_to(&self, point: (f64, f64)) -> f64 {
+        (**self).distance_to(point)
+    }
+}
+
+impl Bounded for BoundingBox {
+    fn bounds(&self) -> BoundingBox {
+        *self
+    }
+}
+
+/// Identifies an item in a [`QuadTree`]. Once the item is removed, the id may be reused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ItemId(usize);
+
+/// A quadtree over items of type `T`.
+#[derive(Debug, Clone)]
+pub struct QuadTree<T> {
+    root: Node,
+    // items by id, with the bounding box they were filed under; None once removed
+    items: Vec<Option<(T, BoundingBox)>>,
+    free: Vec<usize>,
+    len: usize
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    region: BoundingBox,
+    depth: usize,
+    items: Vec<usize>,
+    children: Option<Box<[Node; 4]>>
+}
+
+impl<T: Bounded> QuadTree<T> {
+    /// An empty tree covering `region`. Items outside the region can still be added, but are
+    /// kept at the root, where every query has to look at them.
+    pub fn new(region: BoundingBox) -> QuadTree<T> {
+        QuadTree{root: Node::new(region, 0), items: Vec::new(), free: Vec::new(), len: 0}
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds an item, returning the id with which to find or remove it.
+    pub fn insert(&mut self, item: T) -> ItemId {
+        let bounds = item.bounds();
+        let id = match self.free.pop() {
+            Some(id) => {
+                self.items[id] = Some((item, bounds));
+                id
+            }
+            None => {
+                self.items.push(Some((item, bounds)));
+                self.items.len() - 1
+            }
+        };
+        self.root.insert(id, &bounds, &self.items);
+        self.len += 1;
+        ItemId(id)
+    }
+
+    /// Removes and returns the item, or `None` if it was already removed.
+    pub fn remove(&mut self, id: ItemId) -> Option<T> {
+        let (item, bounds) = self.items.get_mut(id.0)?.take()?;
+        self.root.remove(id.0, &bounds);
+        self.free.push(id.0);
+        self.len -= 1;
+        Some(item)
+    }
+
+    pub fn get(&self, id: ItemId) -> Option<&T> {
+        self.items.get(id.0)?.as_ref().map(|(item, _)| item)
+    }
+
+    /// Every item, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (ItemId, &T)> {
+        self.items.iter().enumerate().filter_map(|(id, entry)| entry.as_ref().map(|(item, _)| (ItemId(id), item)))
+    }
+
+    /// The items whose bounding boxes overlap or touch `area`.
+    pub fn query_box(&self, area: &BoundingBox) -> Vec<(ItemId, &T)> {
+        let mut found = Vec::new();
+        self.root.visit(&|region| region.intersects(area), &mut |id| {
+            let (item, bounds) = self.items[id].as_ref().unwrap();
+            if bounds.intersects(area) { found.push((ItemId(id), item)) }
+        });
+        found
+    }
+
+    /// The items containing `point`.
+    pub fn query_point(&self, point: (f64, f64)) -> Vec<(ItemId, &T)> {
+        let mut found = Vec::new();
+        self.root.visit(&|region| region.contains(point), &mut |id| {
+            let (item, bounds) = self.items[id].as_ref().unwrap();
+            if bounds.contains(point) && item.contains_point(point) { found.push((ItemId(id), item)) }
+        });
+        found
+    }
+
+    /// The `k` items nearest to `point`, nearest first, by [`Bounded::distance_to`]. Items at
+    /// the same distance come in no particular order.
+    pub fn nearest(&self, point: (f64, f64), k: usize) -> Vec<(ItemId, &T)> {
+        // best first: always expand whichever node or item could be nearest, so items come off
+        // the heap in order of distance
+        let mut heap = BinaryHeap::new();
+        heap.push(Candidate{distance: 0.0, entry: Entry::Node(&self.root)});
+        let mut found = Vec::new();
+        while let Some(Candidate{entry, ..}) = heap.pop() {
+            if found.len() == k { break }
+            match entry {
+                Entry::Item(id) => found.push((ItemId(id), &self.items[id].as_ref().unwrap().0)),
+                Entry::Node(node) => {
+                    for &id in &node.items {
+                        let (item, _) = self.items[id].as_ref().unwrap();
+                        heap.push(Candidate{distance: item.distance_to(point), entry: Entry::Item(id)})
+                    }
+                    for child in node.children.iter().flat_map(|children| children.iter()) {
+                        heap.push(Candidate{distance: child.region.distance_to(point), entry: Entry::Node(child)})
+                    }
+                }
+            }
+        }
+        found
+    }
+}
+
+// a tree over every item, covering all of them
+impl<T: Bounded> FromIterator<T> for QuadTree<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> QuadTree<T> {
+        let items: Vec<T> = iter.into_iter().collect();
+        let region = items.iter().map(|item| item.bounds()).reduce(|a, b| a.union(&b))
+            .unwrap_or_else(|| BoundingBox::new((0.0, 0.0), (0.0, 0.0)));
+        let mut tree = QuadTree::new(region);
+        for item in items {
+            tree.insert(item);
+        }
+        tree
+    }
+}
+
+impl Node {
+    fn new(region: BoundingBox, depth: usize) -> Node {
+        Node{region, depth, items: Vec::new(), children: None}
+    }
+
+    // the child wholly containing bounds, if there is one
+    fn child_for(&mut self, bounds: &BoundingBox) -> Option<&mut Node> {
+        self.children.as_mut()?.iter_mut().find(|child| child.region.encloses(bounds))
+    }
+
+    fn insert<T>(&mut self, id: usize, bounds: &BoundingBox, items: &[Option<(T, BoundingBox)>]) {
+        if let Some(child) = self.child_for(bounds) {
+            return child.insert(id, bounds, items)
+        }
+        self.items.push(id);
+        if self.children.is_none() && self.items.len() > NODE_CAPACITY && self.depth < MAX_DEPTH {
+            self.split(items)
+        }
+    }
+
+    // quarters the region, moving down every item that fits in a quarter
+    fn split<T>(&mut self, items: &[Option<(T, BoundingBox)>]) {
+        let BoundingBox{min, max} = self.region;
+        let mid = ((min.0 + max.0) / 2.0, (min.1 + max.1) / 2.0);
+        let depth = self.depth + 1;
+        self.children = Some(Box::new([
+            Node::new(BoundingBox::new(min, mid), depth),
+            Node::new(BoundingBox::new((mid.0, min.1), (max.0, mid.1)), depth),
+            Node::new(BoundingBox::new((min.0, mid.1), (mid.0, max.1)), depth),
+            Node::new(BoundingBox::new(mid, max), depth)
+        ]));
+        for id in std::mem::take(&mut self.items) {
+            let bounds = items[id].as_ref().unwrap().1;
+            self.insert(id, &bounds, items)
+        }
+    }
+
+    // an item is always filed as deep as it fits, even after a split, so it is found by the
+    // same path insert takes
+    fn remove(&mut self, id: usize, bounds: &BoundingBox) {
+        if let Some(child) = self.child_for(bounds) {
+            return child.remove(id, bounds)
+        }
+        self.items.retain(|&other| other != id)
+    }
+
+    // calls found with every item in the nodes whose regions pass the test
+    fn visit(&self, test: &dyn Fn(&BoundingBox) -> bool, found: &mut dyn FnMut(usize)) {
+        if !test(&self.region) && self.depth > 0 { return }
+        self.items.iter().for_each(|&id| found(id));
+        for child in self.children.iter().flat_map(|children| children.iter()) {
+            child.visit(test, found)
+        }
+    }
+}
+
+// a node or item waiting in the nearest-neighbour heap, nearest first
+struct Candidate<'a> {
+    distance: f64,
+    entry: Entry<'a>
+}
+
+enum Entry<'a> {
+    Node(&'a Node),
+    Item(usize)
+}
+
+impl PartialEq for Candidate<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate<'_> {}
+
+impl PartialOrd for Candidate<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// reversed, as BinaryHeap pops the greatest; at equal distances items come before nodes
+impl Ord for Candidate<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let is_item = |c: &Candidate| matches!(c.entry, Entry::Item(_));
+        other.distance.total_cmp(&self.distance).then(is_item(self).cmp(&is_item(other)))
+    }
+}
+
+fn outline_distance(outline: &Outline, point: (f64, f64)) -> f64 {
+    match outline {
+        Outline::Circle{center, radius} => ((point.0 - center.0).hypot(point.1 - center.1) - radius).max(0.0),
+        Outline::Polygon(vertices) => edges(vertices)
+            .map(|(p, q)| segment_distance(p, q, point))
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+fn segment_distance(p: (f64, f64), q: (f64, f64), point: (f64, f64)) -> f64 {
+    let (dx, dy) = (q.0 - p.0, q.1 - p.1);
+    let length_sqr = dx * dx + dy * dy;
+    let t = if length_sqr == 0.0 { 0.0 } else {
+        (((point.0 - p.0) * dx + (point.1 - p.1) * dy) / length_sqr).clamp(0.0, 1.0)
+    };
+    (point.0 - p.0 - t * dx).hypot(point.1 - p.1 - t * dy)
+}
@@ -0,0 +1,151 @@
+// Showcase
+// the library modules put together: shapes, spatial indexing, SVG, geometry formats and transforms
+#![allow(clippy::approx_constant)]
+
+use std::convert::TryFrom;
+use rust_programming_language::color::Color;
+use rust_programming_language::geometry::{Geometry, Line, Point};
+use rust_programming_language::shapes::{Circle, Polygon, Rectangle, Shape, Square, Triangle};
+
+// Moving shapes
+fn moving_shapes() {
+    println!("----- 8.1. Moving shapes -----");
+
+    // every shape can be moved, resized and turned in place
+    let mut tri = Triangle::new((0.0, 0.0), (4.0, 0.0), (0.0, 3.0));
+    println!("{:?} contains (1, 1)? {}", tri, tri.contains((1.0, 1.0)));
+    tri.translate(10.0, 0.0);
+    tri.scale(2.0);
+    tri.rotate(std::f64::consts::FRAC_PI_2);
+    println!("moved, doubled and turned: {:?} contains (1, 1)? {}", tri, tri.contains((1.0, 1.0)));
+    println!("area {}, perimeter {}, bounding box {:?}", tri.area(), tri.perimeter(), tri.bounding_box());
+}
+
+// Polygon algorithms
+use rust_programming_language::shapes::{convex_hull, shoelace_area, triangulate, winding_number};
+
+fn polygon_algorithms() {
+    println!("----- 8.2. Polygon algorithms -----");
+
+    // the algorithms in shapes/algorithms.rs work on plain lists of vertices; the shoelace
+    // formula agrees with Shape::area, whichever way round the vertices run
+    let notched = vec![(0.0, 0.0), (4.0, 0.0), (4.0, 3.0), (2.0, 1.0), (0.0, 3.0), (0.0, 0.0), (2.0, 0.0)];
+    println!("shoelace area = {}, Shape::area = {}", shoelace_area(&notched), Polygon::new(notched.clone()).area());
+    println!("convex hull = {:?}", convex_hull(&notched));
+    // the repeated corner and the point halfway along the bottom add no triangles
+    let pieces: Vec<Triangle> = triangulate(&notched).iter()
+        .map(|&[a, b, c]| Triangle::new(notched[a], notched[b], notched[c]))
+        .collect();
+    println!("{} triangles of total area {}", pieces.len(), pieces.iter().map(|t| t.area()).sum::<f64>());
+    println!("(2, 2) has winding number {}, (2, 0.5) has {}", winding_number(&notched, (2.0, 2.0)), winding_number(&notched, (2.0, 0.5)));
+}
+
+// Spatial index
+use rust_programming_language::shapes::{BoundingBox, ItemId, QuadTree};
+
+fn spatial_index() {
+    println!("----- 8.3. Spatial index -----");
+
+    // a QuadTree (shapes/index.rs) finds shapes by position without scanning every one;
+    // it holds anything Bounded, which every shape is, so also Box<dyn Shape<f64>>
+    let mut index: QuadTree<Box<dyn Shape<f64>>> = QuadTree::new(BoundingBox::new((0.0, 0.0), (100.0, 100.0)));
+    for i in 0..10 {
+        let x = i as f64 * 10.0;
+        index.insert(Box::new(Circle::new((x, x), 4.0)));
+        index.insert(Box::new(Square::new((x, 90.0 - x), 5.0)));
+    }
+    let big = index.insert(Box::new(Circle::new((50.0, 50.0), 30.0)));
+    let kinds = |found: Vec<(ItemId, &Box<dyn Shape<f64>>)>| found.iter().map(|(_, s)| s.kind()).collect::<Vec<_>>();
+    println!("at (41, 41): {:?}", kinds(index.query_point((41.0, 41.0))));
+    println!("near (0, 50): {:?}", kinds(index.nearest((0.0, 50.0), 3)));
+    index.remove(big);
+    println!("at (41, 41) after removing the big circle: {:?}", kinds(index.query_point((41.0, 41.0))));
+    println!("in the box (0, 0)-(25, 25): {} shapes", index.query_box(&BoundingBox::new((0.0, 0.0), (25.0, 25.0))).len());
+}
+
+// SVG export
+use rust_programming_language::svg::{Drawing, Options as SvgOptions, Style};
+
+fn svg_export() {
+    println!("----- 8.4. SVG export -----");
+
+    // a Drawing (svg.rs) takes any &dyn Shape<f64> and Lines, and fits them into an SVG image
+    let circ = Circle{center: (0.0, 1.32), radius: std::f64::consts::FRAC_1_SQRT_2};
+    let rect = Rectangle{lower_left: (0.0, 1.32), upper_right: (3.14159, 2.7182), rotation: 0.0};
+    let tri = Triangle::new((0.0, 0.0), (0.0, 8.0), (-6.0, 0.0));
+    let mut drawing = Drawing::new();
+    drawing.shape(&circ, Style::fill(Color::Blue))
+        .shape(&rect, Style::stroke(Color::Red))
+        .shape(&tri, Style{stroke: Some(Color::Green), fill: Some(Color::RGB(200, 255, 200)), stroke_width: 2.0})
+        .line(&Line::new(Point::new(0.0, 0.0, 0.0), Point::new(12.0, 4.0, 0.0)), Style::default());
+    print!("{}", drawing.to_svg(&SvgOptions{width: 400, height: 300, ..SvgOptions::default()}));
+}
+
+// WKT and GeoJSON
+use rust_programming_language::geometry::geojson::{FromGeoJson, ToGeoJson};
+use rust_programming_language::geometry::wkt::{FromWkt, ToWkt};
+
+fn wkt_and_geojson() {
+    println!("----- 8.5. WKT and GeoJSON -----");
+
+    // points, lines and plane shapes all convert into a Geometry (geometry.rs), which is what
+    // gets written as Well-Known Text or GeoJSON; TryFrom goes back, and can fail
+    let square: Geometry = Square::new((0.0, 0.0), 2.0).into();
+    println!("{}", square.to_wkt().unwrap());
+    println!("{}", Line::new(Point::new(0.0, 0.0, 0.0), Point::new(1.5, -2.0, 3.0)).to_geojson().unwrap());
+    let parsed = Polygon::from_wkt("polygon ((0 0, 4 0, 0 3, 0 0))").unwrap();
+    println!("{:?}, area {}", parsed, parsed.area());
+    println!("{:?}", Point::try_from(square).map_err(|e| e.to_string()));
+    // parse errors say where the problem is
+    if let Err(e) = Geometry::from_geojson(r#"{"type": "Point", "coordinates": [1, "2"]}"#) {
+        println!("{}", e)
+    }
+    if let Err(e) = Line::from_wkt("LINESTRING (0 0, 1 1, 2 2)") {
+        println!("{}", e)
+    }
+}
+
+// Transforms
+use rust_programming_language::matrix::Mat4;
+use rust_programming_language::quaternion::Quaternion;
+use rust_programming_language::vector::Vec3;
+
+fn transforms() {
+    println!("----- 8.6. Transforms -----");
+
+    // * composes transforms (matrix.rs, quaternion.rs) and applies them to points and lines;
+    // the right-hand transform is applied first
+    let quarter_turn = Quaternion::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), std::f64::consts::FRAC_PI_2);
+    let model = Mat4::from_translation(Vec3::new(10.0, 0.0, 0.0)) * Mat4::from(quarter_turn) * Mat4::from_scale(Vec3::new(2.0, 2.0, 2.0));
+    let edge = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0));
+    println!("quarter turn = {:.3}, edge rotated = {:.3?}", quarter_turn, quarter_turn * edge);
+    println!("edge scaled, rotated then moved = {:.3?}", model * edge);
+    println!("and back again = {:.3?}", model.inverse().unwrap() * (model * edge));
+    let halfway = Quaternion::identity().slerp(&quarter_turn, 0.5);
+    println!("halfway turn = {:.3}, angle {:.1} degrees", halfway, halfway.to_axis_angle().1.to_degrees());
+    // a camera 5 units up the z-axis, projected into the cube from -1 to 1
+    let camera = Mat4::perspective(std::f64::consts::FRAC_PI_2, 1.0, 1.0, 100.0)
+        * Mat4::look_at(Point::new(0.0, 0.0, 5.0), Point::origin(), Vec3::new(0.0, 1.0, 0.0)).unwrap();
+    println!("(1, 1, 0) appears at {:.3?} on screen", camera * Point::new(1.0, 1.0, 0.0));
+}
+
+// Chapter registry
+use crate::registry::{Chapter, Section};
+
+pub const CHAPTER: Chapter = Chapter {
+    number: 8,
+    key: "showcase",
+    title: "Showcase",
+    sections: &[
+        Section { number: 1, name: "Moving shapes", run: moving_shapes },
+        Section { number: 2, name: "Polygon algorithms", run: polygon_algorithms },
+        Section { number: 3, name: "Spatial index", run: spatial_index },
+        Section { number: 4, name: "SVG export", run: svg_export },
+        Section { number: 5, name: "WKT and GeoJSON", run: wkt_and_geojson },
+        Section { number: 6, name: "Transforms", run: transforms }
+    ]
+};
+
+pub fn main() {
+    CHAPTER.run()
+}
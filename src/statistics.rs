@@ -0,0 +1,307 @@
+//! Descriptive statistics of collections of numbers.
+//!
+//! [`Statistics`] adds mean, median, mode, variance, percentiles, extremes and histograms to
+//! slices, and so to arrays and `Vec`s, in the same way as [`Summable`](crate::summable::Summable)
+//! adds sums. Methods return `None` where the statistic is undefined, e.g. the mean of nothing.
+//! Means, medians, percentiles and variances are `f64` whatever the element type, so the mean of
+//! `[1, 2]` is 1.5.
+//!
+//! [`RunningStats`] computes the count, mean, variance and extremes in a single pass with
+//! Welford's algorithm, for data that is streamed rather than held in memory:
+//! `readings.map(parse).collect::<RunningStats<f64>>()`.
+//!
+//! In the order-based statistics (median, mode and percentiles) NaN sorts above every number,
+//! or below if its sign bit is set; other results involving NaN are unspecified, other than not
+//! panicking.
+
+use std::iter::FromIterator;
+use std::ops::Range;
+
+use crate::num::{Float, Real};
+use crate::summable;
+
+/// Descriptive statistics of a collection of `T`s.
+///
+/// The extremes are [`minimum`](Statistics::minimum) and [`maximum`](Statistics::maximum)
+/// rather than `min` and `max`, which `Vec<T>` and arrays of an `Ord` type would resolve to
+/// [`Ord::min`] instead.
+pub trait Statistics<T> {
+    /// The smallest element, or `None` if empty.
+    fn minimum(&self) -> Option<T>;
+
+    /// The largest element, or `None` if empty.
+    fn maximum(&self) -> Option<T>;
+
+    /// The most frequent elements, in ascending order; several if tied, empty if empty.
+    fn mode(&self) -> Vec<T>;
+
+    /// The arithmetic mean.
+    fn mean(&self) -> Option<f64>;
+
+    /// The middle element once sorted, or the mean of the middle two.
+    fn median(&self) -> Option<f64>;
+
+    /// The `p`th percentile, interpolating linearly between the closest ranks, so that the
+    /// 0th is the minimum, the 50th the median and the 100th the maximum.
+    ///
+    /// # Panics
+    ///
+    /// If `p` is not within `0..=100`.
+    fn percentile(&self, p: f64) -> Option<f64>;
+
+    /// Variance of the elements as a whole population, dividing by `n`.
+    fn population_variance(&self) -> Option<f64>;
+
+    /// Variance of the elements as a sample, dividing by `n - 1`; needs two or more elements.
+    fn sample_variance(&self) -> Option<f64>;
+
+    /// Square root of [`population_variance`](Statistics::population_variance).
+    fn population_std_dev(&self) -> Option<f64> {
+        self.population_variance().map(f64::sqrt)
+    }
+
+    /// Square root of [`sample_variance`](Statistics::sample_variance).
+    fn sample_std_dev(&self) -> Option<f64> {
+        self.sample_variance().map(f64::sqrt)
+    }
+
+    /// Counts of the elements in `bins` equal-width bins spanning the minimum to the maximum.
+    ///
+    /// Bins are measured in `f64`, so integers can be binned too: `[1, 2, 3, 4]` in two bins
+    /// counts `1..2.5` and `2.5..=4`.
+    ///
+    /// # Panics
+    ///
+    /// If `bins` is zero.
+    fn histogram(&self, bins: usize) -> Option<Histogram<T>>;
+}
+
+impl<T: Real> Statistics<T> for [T] {
+    fn minimum(&self) -> Option<T> {
+        self.iter().copied().reduce(|a, b| if b < a { b } else { a })
+    }
+
+    fn maximum(&self) -> Option<T> {
+        self.iter().copied().reduce(|a, b| if b > a { b } else { a })
+    }
+
+    fn mode(&self) -> Vec<T> {
+        let sorted = sorted(self);
+        let mut modes = Vec::new();
+        let mut best = 0;
+        for run in sorted.chunk_by(|a, b| a == b) {
+            if run.len() > best {
+                best = run.len();
+                modes.clear()
+            }
+            if run.len() == best { modes.push(run[0]) }
+        }
+        modes
+    }
+
+    fn mean(&self) -> Option<f64> {
+        if self.is_empty() { return None }
+        Some(summable::neumaier_sum::<f64, _>(self.iter().map(|&x| x.to_f64())) / self.len() as f64)
+    }
+
+    fn median(&self) -> Option<f64> {
+        self.percentile(50.0)
+    }
+
+    fn percentile(&self, p: f64) -> Option<f64> {
+        assert!((0.0..=100.0).contains(&p), "percentile must be within 0..=100");
+        if self.is_empty() { return None }
+        let sorted = sorted(self);
+        let rank = p / 100.0 * (sorted.len() - 1) as f64;
+        let (below, above) = (sorted[rank.floor() as usize].to_f64(), sorted[rank.ceil() as usize].to_f64());
+        Some(below + (above - below) * rank.fract())
+    }
+
+    fn population_variance(&self) -> Option<f64> {
+        let n = self.len();
+        if n == 0 { return None }
+        Some(squared_deviations(self) / n as f64)
+    }
+
+    fn sample_variance(&self) -> Option<f64> {
+        let n = self.len();
+        if n < 2 { return None }
+        Some(squared_deviations(self) / (n - 1) as f64)
+    }
+
+    fn histogram(&self, bins: usize) -> Option<Histogram<T>> {
+        assert!(bins > 0, "a histogram needs at least one bin");
+        let (min, max) = (self.minimum()?, self.maximum()?);
+        let mut histogram = Histogram{min, max, counts: vec![0; bins]};
+        for &x in self {
+            if let Some(bin) = histogram.bin(x) { histogram.counts[bin] += 1 }
+        }
+        Some(histogram)
+    }
+}
+
+// a sorted copy; only NaN is unordered, and total_cmp puts it at either end, so this is a total
+// order (comparing everything in f64 would not be: large integers can round to the same value)
+fn sorted<T: Real>(xs: &[T]) -> Vec<T> {
+    let mut sorted = xs.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or_else(|| a.to_f64().total_cmp(&b.to_f64())));
+    sorted
+}
+
+// Σ(x - mean)², with the mean computed first; two passes are more accurate than one
+fn squared_deviations<T: Real>(xs: &[T]) -> f64 {
+    let mean = summable::neumaier_sum::<f64, _>(xs.iter().map(|&x| x.to_f64())) / xs.len() as f64;
+    summable::neumaier_sum(xs.iter().map(|&x| (x.to_f64() - mean) * (x.to_f64() - mean)))
+}
+
+/// Counts of values falling into equal-width bins between `min` and `max`.
+///
+/// Every bin is half-open except the last, which also includes `max`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram<T> {
+    pub min: T,
+    pub max: T,
+    pub counts: Vec<usize>
+}
+
+impl<T: Real> Histogram<T> {
+    /// The width of each bin.
+    pub fn bin_width(&self) -> f64 {
+        (self.max.to_f64() - self.min.to_f64()) / self.counts.len() as f64
+    }
+
+    /// The values counted by the `i`th bin.
+    pub fn bin_range(&self, i: usize) -> Range<f64> {
+        let width = self.bin_width();
+        let start = self.min.to_f64() + width * i as f64;
+        start..start + width
+    }
+
+    /// The bin `x` falls into, or `None` if it is outside `min..=max`.
+    pub fn bin(&self, x: T) -> Option<usize> {
+        if !(x >= self.min && x <= self.max) { return None }
+        let width = self.bin_width();
+        if width == 0.0 { return Some(0) }
+        let i = ((x.to_f64() - self.min.to_f64()) / width).floor() as usize;
+        Some(i.min(self.counts.len() - 1))
+    }
+}
+
+/// Single-pass count, mean, variance and extremes, by Welford's algorithm.
+///
+/// Values are added one at a time with [`push`](RunningStats::push), or all at once with
+/// `extend` or `collect`, and two accumulators can be [`merge`](RunningStats::merge)d, e.g.
+/// when the data was split between threads.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RunningStats<T> {
+    count: usize,
+    mean: T,
+    // sum of squared deviations from the current mean
+    m2: T,
+    min: T,
+    max: T
+}
+
+impl<T: Float> RunningStats<T> {
+    /// An accumulator that has seen no values.
+    pub fn new() -> RunningStats<T> {
+        RunningStats{count: 0, mean: T::zero(), m2: T::zero(), min: T::INFINITY, max: -T::INFINITY}
+    }
+
+    /// Adds a value.
+    pub fn push(&mut self, x: T) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean = self.mean + delta / T::from_f64(self.count as f64);
+        self.m2 = self.m2 + delta * (x - self.mean);
+        self.min = self.min.min(x);
+        self.max = self.max.max(x)
+    }
+
+    /// Combines the values seen by `other` into `self`, as if they had been pushed here.
+    pub fn merge(&mut self, other: &RunningStats<T>) {
+        if other.count == 0 { return }
+        if self.count == 0 {
+            *self = *other;
+            return
+        }
+        let count = self.count + other.count;
+        let (n, m) = (T::from_f64(self.count as f64), T::from_f64(other.count as f64));
+        let total = T::from_f64(count as f64);
+        let delta = other.mean - self.mean;
+        self.mean = self.mean + delta * m / total;
+        self.m2 = self.m2 + other.m2 + delta * delta * n * m / total;
+        self.count = count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max)
+    }
+
+    /// How many values have been pushed.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn mean(&self) -> Option<T> {
+        if self.count == 0 { None } else { Some(self.mean) }
+    }
+
+    pub fn min(&self) -> Option<T> {
+        if self.count == 0 { None } else { Some(self.min) }
+    }
+
+    pub fn max(&self) -> Option<T> {
+        if self.count == 0 { None } else { Some(self.max) }
+    }
+
+    /// Variance of the values as a whole population, dividing by `n`.
+    pub fn population_variance(&self) -> Option<T> {
+        if self.count == 0 { None } else { Some(self.m2 / T::from_f64(self.count as f64)) }
+    }
+
+    /// Variance of the values as a sample, dividing by `n - 1`; needs two or more values.
+    pub fn sample_variance(&self) -> Option<T> {
+        if self.count < 2 { None } else { Some(self.m2 / T::from_f64((self.count - 1) as f64)) }
+    }
+
+    pub fn population_std_dev(&self) -> Option<T> {
+        self.population_variance().map(Float::sqrt)
+    }
+
+    pub fn sample_std_dev(&self) -> Option<T> {
+        self.sample_variance().map(Float::sqrt)
+    }
+}
+
+impl<T: Float> Default for RunningStats<T> {
+    fn default() -> RunningStats<T> {
+        RunningStats::new()
+    }
+}
+
+impl<T: Float> Extend<T> for RunningStats<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        items.into_iter().for_each(|x| self.push(x))
+    }
+}
+
+impl<'a, T: Float + 'a> Extend<&'a T> for RunningStats<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, items: I) {
+        items.into_iter().for_each(|&x| self.push(x))
+    }
+}
+
+impl<T: Float> FromIterator<T> for RunningStats<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(items: I) -> RunningStats<T> {
+        let mut stats = RunningStats::new();
+        stats.extend(items);
+        stats
+    }
+}
+
+impl<'a, T: Float + 'a> FromIterator<&'a T> for RunningStats<T> {
+    fn from_iter<I: IntoIterator<Item = &'a T>>(items: I) -> RunningStats<T> {
+        let mut stats = RunningStats::new();
+        stats.extend(items);
+        stats
+    }
+}
@@ -0,0 +1,105 @@
+//! Summing collections of numbers.
+//!
+//! [`Summable`] adds summation methods to slices, and so to arrays and `Vec`s too. The free
+//! functions of this module do the same for anything that can be iterated over, whether it yields
+//! numbers or references to them, e.g. `kahan_sum(buffer.iter())` or `checked_sum(0..200u8)`.
+//!
+//! Plain [`Summable::sum`] behaves like `+`: integer overflow panics in debug builds and wraps in
+//! release builds. Pick [`checked_sum`], [`saturating_sum`] or [`wrapping_sum`] to decide what
+//! should happen instead, and [`neumaier_sum`] (or [`kahan_sum`]) to avoid the rounding error that
+//! builds up when adding many floats one at a time.
+
+use std::borrow::Borrow;
+
+use crate::num::{Float, Integer, Num, Signed};
+
+/// Summation methods for a collection of `T`s.
+///
+/// Implemented for slices; for iterators, use the free functions of this module, which take
+/// anything that can be iterated over.
+pub trait Summable<T> {
+    /// Sum of the elements, with the overflow behaviour of `+`; zero if empty.
+    fn sum(&self) -> T;
+
+    /// Sum of the elements, or `None` if an intermediate sum overflows.
+    fn checked_sum(&self) -> Option<T> where T: Integer;
+
+    /// Sum of the elements, clamping each intermediate sum to `T::MIN..=T::MAX`.
+    fn saturating_sum(&self) -> T where T: Integer;
+
+    /// Sum of the elements, wrapping around on overflow.
+    fn wrapping_sum(&self) -> T where T: Integer;
+
+    /// Kahan compensated sum of the elements.
+    fn kahan_sum(&self) -> T where T: Float;
+
+    /// Neumaier's improvement of the Kahan sum, which also stays accurate when an element is
+    /// larger in magnitude than the running total.
+    fn neumaier_sum(&self) -> T where T: Float;
+}
+
+impl<T: Num> Summable<T> for [T] {
+    fn sum(&self) -> T { sum(self) }
+    fn checked_sum(&self) -> Option<T> where T: Integer { checked_sum(self) }
+    fn saturating_sum(&self) -> T where T: Integer { saturating_sum(self) }
+    fn wrapping_sum(&self) -> T where T: Integer { wrapping_sum(self) }
+    fn kahan_sum(&self) -> T where T: Float { kahan_sum(self) }
+    fn neumaier_sum(&self) -> T where T: Float { neumaier_sum(self) }
+}
+
+/// Sum of the items, with the overflow behaviour of `+`.
+pub fn sum<T: Num, I>(items: I) -> T where I: IntoIterator, I::Item: Borrow<T> {
+    items.into_iter().fold(T::zero(), |acc, x| acc + *x.borrow())
+}
+
+/// Sum of the items, or `None` if an intermediate sum overflows.
+pub fn checked_sum<T: Integer, I>(items: I) -> Option<T> where I: IntoIterator, I::Item: Borrow<T> {
+    items.into_iter().try_fold(T::zero(), |acc, x| acc.checked_add(*x.borrow()))
+}
+
+/// Sum of the items, clamping each intermediate sum to `T::MIN..=T::MAX`.
+///
+/// With mixed signs the result depends on the order: `[MAX, 1, -1]` sums to `MAX - 1`.
+pub fn saturating_sum<T: Integer, I>(items: I) -> T where I: IntoIterator, I::Item: Borrow<T> {
+    items.into_iter().fold(T::zero(), |acc, x| acc.saturating_add(*x.borrow()))
+}
+
+/// Sum of the items, wrapping around on overflow.
+pub fn wrapping_sum<T: Integer, I>(items: I) -> T where I: IntoIterator, I::Item: Borrow<T> {
+    items.into_iter().fold(T::zero(), |acc, x| acc.wrapping_add(*x.borrow()))
+}
+
+/// Kahan compensated sum: the low-order bits lost by each addition are carried into the next.
+pub fn kahan_sum<T: Float, I>(items: I) -> T where I: IntoIterator, I::Item: Borrow<T> {
+    let mut total = T::zero();
+    let mut compensation = T::zero();
+    for x in items {
+        let y = *x.borrow() - compensation;
+        let t = total + y;
+        compensation = (t - total) - y;
+        total = t
+    }
+    total
+}
+
+/// Neumaier compensated sum, which unlike [`kahan_sum`] also handles items larger than the
+/// running total, e.g. `[1.0, 1e100, 1.0, -1e100]` sums to `2.0`.
+///
+/// The compensation is accumulated in `f64`: for `f32` items, a million lost low-order bits
+/// summed in `f32` would lose bits of their own.
+pub fn neumaier_sum<T: Float, I>(items: I) -> T where I: IntoIterator, I::Item: Borrow<T> {
+    let mut total = T::zero();
+    let mut compensation = 0.0;
+    for x in items {
+        let x = *x.borrow();
+        let t = total + x;
+        // whichever operand is smaller lost its low-order bits, and these recover them exactly
+        compensation += if Signed::abs(total) >= Signed::abs(x) {
+            ((total - t) + x).to_f64()
+        } else {
+            ((x - t) + total).to_f64()
+        };
+        total = t
+    }
+    T::from_f64(total.to_f64() + compensation)
+}
@@ -0,0 +1,194 @@
+//! Drawing shapes and line segments as SVG, to see geometry rather than read it.
+//!
+//! Shapes are drawn from their [`Outline`], so ellipses come out as many-sided polygons.
+//! Lines are 3D segments projected onto the xy-plane. The y axis points up, as in the rest of
+//! the library, and is flipped to SVG's downward y when drawing.
+
+use std::fmt::Write as _;
+use std::io::{self, Write};
+
+use crate::color::Color;
+use crate::geometry::Line;
+use crate::shapes::{BoundingBox, Outline, Shape};
+
+/// How an element is painted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// Outline colour, or `None` for no outline.
+    pub stroke: Option<Color>,
+    /// Fill colour, or `None` to leave the inside transparent. Lines are never filled.
+    pub fill: Option<Color>,
+    /// Outline width in pixels, whatever the zoom.
+    pub stroke_width: f64
+}
+
+/// How the drawing maps onto the image.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Options {
+    /// Image width in pixels.
+    pub width: u32,
+    /// Image height in pixels.
+    pub height: u32,
+    /// The region to show, or `None` to fit everything drawn. Either way the aspect ratio is
+    /// kept and the region centred.
+    pub viewport: Option<BoundingBox>,
+    /// Empty space around the region, in pixels.
+    pub margin: f64,
+    /// Colour behind everything, or `None` for transparent.
+    pub background: Option<Color>
+}
+
+/// Shapes and lines to be drawn, in order, each with its own style.
+#[derive(Debug, Clone, Default)]
+pub struct Drawing {
+    elements: Vec<(Element, Style)>
+}
+
+#[derive(Debug, Clone)]
+enum Element {
+    Outline(Outline),
+    Segment((f64, f64), (f64, f64))
+}
+
+impl Default for Style {
+    // a thin black outline
+    fn default() -> Style {
+        Style{stroke: Some(Color::RGB(0, 0, 0)), fill: None, stroke_width: 1.0}
+    }
+}
+
+impl Style {
+    pub fn stroke(color: Color) -> Style {
+        Style{stroke: Some(color), ..Style::default()}
+    }
+
+    pub fn fill(color: Color) -> Style {
+        Style{stroke: None, fill: Some(color), ..Style::default()}
+    }
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options{width: 800, height: 600, viewport: None, margin: 20.0, background: None}
+    }
+}
+
+impl Drawing {
+    pub fn new() -> Drawing {
+        Drawing::default()
+    }
+
+    /// Adds a shape, drawn above everything added before it.
+    pub fn shape(&mut self, shape: &dyn Shape<f64>, style: Style) -> &mut Drawing {
+        self.elements.push((Element::Outline(shape.outline()), style));
+        self
+    }
+
+    /// Adds a line segment, ignoring its z coordinates.
+    pub fn line(&mut self, line: &Line<f64>, style: Style) -> &mut Drawing {
+        let segment = Element::Segment((line.start.x, line.start.y), (line.end.x, line.end.y));
+        self.elements.push((segment, style));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// The smallest box containing everything drawn, or `None` if nothing is.
+    pub fn bounds(&self) -> Option<BoundingBox> {
+        self.elements.iter().map(|(element, _)| match element {
+            Element::Outline(Outline::Circle{center: (x, y), radius: r}) =>
+                BoundingBox::new((x - r, y - r), (x + r, y + r)),
+            Element::Outline(Outline::Polygon(vertices)) => BoundingBox::of_points(vertices.iter().copied()).unwrap(),
+            Element::Segment(p, q) => BoundingBox::of_points(vec![*p, *q]).unwrap()
+        }).reduce(|a, b| a.union(&b))
+    }
+
+    /// The drawing as an SVG document.
+    pub fn to_svg(&self, options: &Options) -> String {
+        let transform = Transform::new(self, options);
+        let mut svg = String::new();
+        // writing to a String cannot fail
+        let _ = writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+                         w = options.width, h = options.height);
+        if let Some(background) = options.background {
+            let _ = writeln!(svg, r#"  <rect width="100%" height="100%" fill="{}"/>"#, color(background));
+        }
+        for (element, style) in &self.elements {
+            let _ = match element {
+                Element::Outline(Outline::Circle{center, radius}) => {
+                    let (x, y) = transform.apply(*center);
+                    writeln!(svg, r#"  <circle cx="{}" cy="{}" r="{}"{}/>"#,
+                             number(x), number(y), number(radius * transform.scale), paint(style, true))
+                }
+                Element::Outline(Outline::Polygon(vertices)) => {
+                    let points: Vec<String> = vertices.iter()
+                        .map(|&v| { let (x, y) = transform.apply(v); format!("{},{}", number(x), number(y)) })
+                        .collect();
+                    writeln!(svg, r#"  <polygon points="{}"{}/>"#, points.join(" "), paint(style, true))
+                }
+                Element::Segment(p, q) => {
+                    let ((x1, y1), (x2, y2)) = (transform.apply(*p), transform.apply(*q));
+                    writeln!(svg, r#"  <line x1="{}" y1="{}" x2="{}" y2="{}"{}/>"#,
+                             number(x1), number(y1), number(x2), number(y2), paint(style, false))
+                }
+            };
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Writes the drawing as an SVG document.
+    pub fn write_svg<W: Write>(&self, out: &mut W, options: &Options) -> io::Result<()> {
+        out.write_all(self.to_svg(options).as_bytes())
+    }
+}
+
+// from drawing coordinates to pixels: uniform scale, centred, y flipped
+struct Transform {
+    scale: f64,
+    origin: (f64, f64),
+    offset: (f64, f64)
+}
+
+impl Transform {
+    fn new(drawing: &Drawing, options: &Options) -> Transform {
+        let region = options.viewport.or_else(|| drawing.bounds())
+            .unwrap_or(BoundingBox::new((0.0, 0.0), (1.0, 1.0)));
+        let (width, height) = (region.width(), region.height());
+        let available = ((options.width as f64 - 2.0 * options.margin).max(1.0),
+                         (options.height as f64 - 2.0 * options.margin).max(1.0));
+        // a horizontal or vertical line is scaled by its length alone, and a point not at all
+        let fit = |available: f64, extent: f64| if extent > 0.0 { available / extent } else { f64::INFINITY };
+        let scale = fit(available.0, width).min(fit(available.1, height));
+        let scale = if scale.is_finite() { scale } else { 1.0 };
+        let offset = (options.margin + (available.0 - width * scale) / 2.0,
+                      options.margin + (available.1 - height * scale) / 2.0);
+        Transform{scale, origin: (region.min.0, region.max.1), offset}
+    }
+
+    fn apply(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        (self.offset.0 + (x - self.origin.0) * self.scale, self.offset.1 + (self.origin.1 - y) * self.scale)
+    }
+}
+
+fn paint(style: &Style, fillable: bool) -> String {
+    let fill = match style.fill {
+        Some(c) if fillable => color(c),
+        _ => "none".to_string()
+    };
+    let stroke = style.stroke.map_or("none".to_string(), color);
+    format!(r#" fill="{}" stroke="{}" stroke-width="{}""#, fill, stroke, number(style.stroke_width))
+}
+
+fn color(c: Color) -> String {
+    c.to_hex()
+}
+
+// at most two decimals, without trailing zeros: plenty for pixels
+fn number(x: f64) -> String {
+    let s = format!("{:.2}", x);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" { "0".to_string() } else { s.to_string() }
+}
@@ -1,46 +1,19 @@
 // Traits
 #![allow(unused_variables)]
 #![allow(unused_mut)]
+#![allow(clippy::approx_constant, clippy::unnecessary_cast, clippy::needless_return,
+         clippy::useless_vec)]
 
 // Traits
+use rust_programming_language::animals::{Animal, AnimalRegistry, AnyAnimal, Cat, Human};
+use rust_programming_language::statistics::{RunningStats, Statistics};
+use rust_programming_language::summable::Summable;
+
 fn traits() {
     println!("----- 6.1. Traits -----");
 
-    trait Animal {
-        fn new(name: &'static str) -> Self; // static method
-        fn name(&self) -> &'static str;
-        fn talk(&self) {
-            println!("{} cannot talk", self.name())
-        }
-    }
-
-    struct Human {
-        name: &'static str
-    }
-
-    struct Cat {
-        name: &'static str
-    }
-
-    impl Animal for Human {
-        fn new(name: &'static str) -> Human {
-            Human{name}
-        }
-        fn name(&self) -> &'static str { self.name }
-        fn talk(&self) {
-            println!("{} says hello", self.name)
-        }
-    }
-
-    impl Animal for Cat {
-        fn new(name: &'static str) -> Cat {
-            Cat{name}
-        }
-        fn name(&self) -> &'static str { self.name }
-        fn talk(&self) {
-            println!("{} says meow", self.name)
-        }
-    }
+    // the Animal trait has a static method (new), a required method (name)
+    // and default methods (speak, talk, ...) -- Human and Cat override speak, see animals.rs
 
     let isaac = Human::new("Isaac");
     isaac.talk();
@@ -49,88 +22,56 @@ fn traits() {
     fluffy.talk();
 
     // defining behaviors for types we don't own
-    // define a sum() method for vectors
-    trait Summable<T> {
-        fn sum(&self) -> T;
-    }
-
-    // implement sum() as the sum of elements
-    impl Summable<i32> for Vec<i32> {
-        fn sum(&self) -> i32 {
-            let mut acc = 0;
-            for x in self {
-                acc += *x
-            }
-            return acc
-        }
-    }
-
-    let a = vec![1,2,3,4,5];
+    // Summable<T> (summable.rs) defines sum() and friends for slices of any numeric type [T],
+    // and so for vectors and arrays too
+    let a = vec![1, 2, 3, 4, 5];
     println!("vector sum = {}", a.sum());
+    let b = [0.5, 0.25, 0.125];
+    println!("array sum = {}", b.sum());
+
+    // overflow can be caught or avoided
+    let bytes: Vec<u8> = vec![200, 50, 10];
+    println!("checked u8 sum = {:?}", bytes.checked_sum());
+    println!("saturating u8 sum = {}", bytes.saturating_sum());
+    println!("wrapping u8 sum = {}", bytes.wrapping_sum());
+
+    // and compensated summation keeps the bits that plain float addition loses
+    let readings = vec![0.1f32; 1_000_000];
+    println!("f32 sum = {}, Kahan sum = {}", readings.sum(), readings.kahan_sum());
+
+    // Statistics<T> (statistics.rs) is built the same way on top of Summable
+    let scores = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    println!("mean = {:?}, median = {:?}, mode = {:?}", scores.mean(), scores.median(), scores.mode());
+    println!("standard deviation = {:?}, 90th percentile = {:?}", scores.population_std_dev(), scores.percentile(90.0));
+    // a RunningStats sees each value once, so the data never has to be held in memory
+    let running: RunningStats<f64> = (1..=1000).map(f64::from).collect();
+    println!("streamed: n = {}, mean = {:?}, sample variance = {:?}", running.count(), running.mean(), running.sample_variance());
 }
 
 // Trait parameters
 use std::fmt::Debug;
+use rust_programming_language::shapes::{collide, Circle, Ellipse, Polygon, Rectangle, RegularPolygon, Shape, Square, Triangle};
 
 fn trait_params() {
     println!("----- 6.2. Trait parameters -----");
 
-    trait Shape<T> {
-        fn kind(&self) -> &'static str;
-        fn number_of_sides(&self) -> Option<u8>;
-        fn area(&self) -> T;
-        fn center(&self) -> (T, T);
-    }
-
-    #[derive(Debug)] // equivalent to deriving Show in Haskell
-    struct Circle {
-        center: (f32, f32),
-        radius: f32
-    }
-
-    #[derive(Debug)]
-    struct Rectangle {
-        lower_left: (f32, f32),
-        upper_right: (f32, f32)
-    }
-
-    impl Shape<f32> for Circle {
-        fn kind(&self) -> &'static str { "circle" }
-        fn number_of_sides(&self) -> Option<u8> { None }
-        fn area(&self) -> f32 {
-            let pi = std::f32::consts::PI;
-            let rsq = (self.radius * self.radius) as f32;
-            pi * rsq
-        }
-        fn center(&self) -> (f32, f32) { self.center }
-    }
-
-    impl Shape<f32> for Rectangle {
-        fn kind(&self) -> &'static str { "rectangle" }
-        fn number_of_sides(&self) -> Option<u8> { Some(4) }
-        fn area(&self) -> f32 {
-            let width = self.upper_right.0 - self.lower_left.0;
-            let length = self.upper_right.1 - self.lower_left.1;
-            length * width
-        }
-        fn center(&self) -> (f32, f32) {
-            let horizontal = (self.upper_right.0 + self.lower_left.0) / 2.0;
-            let vertical = (self.upper_right.1 + self.lower_left.1) / 2.0;
-            (horizontal, vertical)
-        }
-    }
+    // Shape<T> is parameterised by the type of its measurements;
+    // Circle and Rectangle implement Shape<f64> and derive Debug (see shapes.rs)
+    // deriving Debug is equivalent to deriving Show in Haskell
 
-    let circ = Circle{center: (0.0, 1.32), radius: std::f32::consts::FRAC_1_SQRT_2};
-    let rect = Rectangle{lower_left: (0.0, 1.32), upper_right: (3.14159, 2.7182)};
+    let circ = Circle{center: (0.0, 1.32), radius: std::f64::consts::FRAC_1_SQRT_2};
+    let rect = Rectangle{lower_left: (0.0, 1.32), upper_right: (3.14159, 2.7182), rotation: 0.0};
 
     // on-the-fly constraints
     // trait-bound syntax: fn shape_details<T: Shape + Debug>(shape: T, shape2: T,...) {...}
     // fn shape_details<T>(shape: T, shape2: T,...) where T: Shape + Debug {...}
-    fn shape_details(shape: impl Shape<f32> + Debug) {
+    fn shape_details(shape: impl Shape<f64> + Debug) {
         println!("Details of {:?}", shape);
         println!("Number of sides: {:?}", shape.number_of_sides());
         println!("Center: {:?}", shape.center());
         println!("Area: {}", shape.area());
+        println!("Perimeter: {}", shape.perimeter());
+        println!("Bounding box: {:?}", shape.bounding_box());
     }
 
     shape_details(circ);
@@ -190,33 +131,13 @@ fn drop_() {
 }
 
 // Operator overloading
-use std::ops::{Add, Mul, Sub, AddAssign};
-use std::fmt;
-use std::process::Output;
+use rust_programming_language::complex::Complex;
 
 fn operator_overloading() {
     println!("------ 6.5. Operator overloading -----");
 
     // operator overloading in Rust is accomplished through traits
-
-    #[derive(Debug, Copy, Clone)]
-    struct Complex<T> {
-        re: T,
-        im: T
-    }
-
-    impl<T> Complex<T> {
-        fn new(r: T, i: T) -> Complex<T> {
-            Complex::<T>{re:r, im:i}
-        }
-    }
-
-    // implementing display trait for Complex<T: std::fmt::Display>
-    impl<T: std::fmt::Display> fmt::Display for Complex<T> {
-        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            write!(f, "{} + {}i", self.re, self.im)
-        }
-    }
+    // Complex<T> (complex.rs) implements Display, Add, Sub, Mul, AddAssign and PartialEq
 
     // nice default display
     let mut a = Complex::new(1,2);
@@ -225,60 +146,45 @@ fn operator_overloading() {
     println!("b = {}", b);
 
     // + overloading for Complex<T: Add<Output = T>>
-    impl<T: Add<Output = T>> Add for Complex<T> {
-        type Output = Complex<T>;
-        // self is a reference; Self is the type of the rhs operand, i.e. Complex<T>
-        fn add(self, rhs: Self) -> Self::Output {
-            Complex{
-                re: self.re + rhs.re,
-                im: self.im + rhs.im
-            }
-        }
-    }
     // - overloading for Complex<T: Sub<Output = T>>
-    impl<T: Sub<Output = T>> Sub for Complex<T> {
-        type Output = Complex<T>;
-        fn sub(self, rhs: Self) -> Self::Output {
-            Complex{
-                re: self.re - rhs.re,
-                im: self.im - rhs.im
-            }
-        }
-    }
     // * overloading for Complex<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy>
-    // Copy is necessary because we use real and imaginary parts multiple times in calculation
-    impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Mul for Complex<T> {
-        type Output = Complex<T>;
-        fn mul(self, rhs: Self) -> Self::Output {
-            Complex{
-                re: self.re * rhs.re - self.im * rhs.im,
-                im: self.re * rhs.im + self.im * rhs.re
-            }
-        }
-    }
-    impl<T: AddAssign<T>> AddAssign for Complex<T> {
-        fn add_assign(&mut self, rhs: Self) {
-            self.re += rhs.re;
-            self.im += rhs.im
-        }
-    }
-
+    // += overloading for Complex<T: AddAssign<T>>
     println!("a + b = {}", a + b);
+    println!("a - b = {}", a - b); // negative parts are displayed with a minus sign
     println!("a * b = {}", a * b);
     a += b;
     println!("a = {}", a);
 
+    // scalars can appear on either side
+    println!("2 * b = {}", 2 * b);
+    println!("b * 2 = {}", b * 2);
+
+    // division, negation and the conjugate
+    let c = Complex::new(1.0, -2.0);
+    let d = Complex::new(3.0, 4.0);
+    println!("c / d = {:.2}", c / d);
+    println!("-c = {}", -c);
+    println!("conj(c) = {}", c.conj());
+
+    // polar form and transcendental functions for float parts
+    println!("|d| = {}, arg(d) = {:.4}", d.norm(), d.arg());
+    println!("sqrt(-4) = {}", Complex::new(-4.0, 0.0).sqrt());
+    let i_pi = Complex::new(0.0, std::f64::consts::PI);
+    println!("e^(i*pi) = {:.3}", i_pi.exp());
+
+    // FromStr lets us parse complex numbers, including what Display prints
+    let e: Complex<f64> = "1 - 2i".parse().unwrap();
+    println!("\"1 - 2i\" parses to {}, c == e = {}", e, c == e);
+    println!("\"5∠90°\" parses to {:.3}", "5∠90°".parse::<Complex<f64>>().unwrap());
+    if let Err(err) = "3 + 4k".parse::<Complex<f64>>() {
+        println!("\"3 + 4k\" does not parse: {}", err)
+    }
+
     // for comparing complex numbers, we need to talk about equality
     // two types of equality:
     // partial equality
     // full equality: x = x (cannot be supported for x = NAN)
-    impl<T: PartialEq> PartialEq for Complex<T> {
-        fn eq(&self, other: &Self) -> bool {
-            self.re == other.re && self.im == other.im
-        }
-    }
-
-    impl<T: Eq> Eq for Complex<T> {}
+    // Complex<T> is PartialEq when T is, and Eq when T is
     // these traits can also be derived automatically with #[derive(PartialEq, Eq)]
 
     println!("a == b = {}", a == b);
@@ -358,33 +264,28 @@ fn why_dynamic_dispatch() {
 
     // dynamic dispatch is needed if we have a function accepting arguments of a type with subtypes having different implementations
     // e.g. area(x: &Shape) and both Circle and Square are Shape
-    trait Shape {
-        fn area(&self) -> f64;
-    }
-
-    struct Circle{radius:f64}
-    struct Square{side:f64}
-
-    impl Shape for Circle {
-        fn area(&self) -> f64 {
-            self.radius * self.radius * std::f64::consts::PI
-        }
-    }
+    let l_shape = Polygon::new(vec![(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (1.0, 1.0), (1.0, 2.0), (0.0, 2.0)]);
+    let shapes: [&dyn Shape<f64>;7] = [
+        &Circle::new((0.0, 0.0), 5.0),
+        &Circle::new((0.0, 0.0), 2.0),
+        &Square::new((0.0, 0.0), 5.0),
+        &Triangle::new((0.0, 0.0), (3.0, 0.0), (0.0, 4.0)),
+        &Ellipse::new((0.0, 0.0), (2.0, 1.0)),
+        &RegularPolygon::new((0.0, 0.0), 6, 1.0),
+        &l_shape
+    ];
 
-    impl Shape for Square {
-        fn area(&self) -> f64 {
-            self.side * self.side
-        }
+    for (i, shape) in shapes.iter().enumerate() {
+        println!("Shape #{} ({}) has area {:.4} and perimeter {:.4}", i, shape.kind(), shape.area(), shape.perimeter());
     }
 
-    let shapes: [&dyn Shape;3] = [
-        &Circle{radius: 5.0},
-        &Circle{radius: 2.0},
-        &Square{side: 5.0}
-    ];
-
+    // shapes::collision works on any two &dyn Shape<f64>, whatever their concrete types
+    let ball = Circle::new((5.5, 1.0), 1.0);
     for (i, shape) in shapes.iter().enumerate() {
-        println!("Shape #{} has area {}", i, shape.area());
+        match collide(&ball, *shape) {
+            Some(hit) => println!("the ball hits shape #{} {:.4} deep at {:.4?}", i, hit.depth, hit.points),
+            None => println!("the ball misses shape #{}", i)
+        }
     }
 }
 
@@ -392,63 +293,93 @@ fn why_dynamic_dispatch() {
 fn vectors_of_different_objects() {
     println!("----- 6.9. Vectors of different objects ------");
 
-    trait Animal {
-        fn new(name: &'static str) -> Self; // static method
-        fn name(&self) -> &'static str;
-        fn talk(&self) {
-            println!("{} cannot talk", self.name())
-        }
+    // Human, Cat and the Animal trait are the ones from animals.rs (see 6.1)
+    let isaac: Human = Animal::new("Isaac");
+    isaac.talk();
+    let garfield: Cat = Animal::new("Garfield");
+    garfield.talk();
+
+    //let animals: Vec<Animal> = vec![isaac, garfield]; <--- does not compile, Animal is not a type
+    // option 1: a vector of Boxes holding trait objects
+    // this only compiles because Animal::new is marked `where Self: Sized`, which keeps it off dyn Animal
+    let animals: Vec<Box<dyn Animal>> = vec![Box::new(isaac), Box::new(garfield)];
+    for animal in animals.iter() {
+        animal.talk()
     }
 
-    struct Human {
-        name: &'static str
+    // option 2: an enum { Human(Human), Cat(Cat) } which uses the Human and Cat structs
+    let animals: Vec<AnyAnimal> = vec![isaac.into(), garfield.into(), AnyAnimal::Cat(Animal::new("Tom"))];
+    for animal in animals.iter() {
+        animal.talk()
     }
 
-    struct Cat {
+    // an AnimalRegistry (animals/registry.rs) builds boxed animals from a species name,
+    // and new species can be registered from out here without touching the library
+    struct Dog {
         name: &'static str
     }
 
-    impl Animal for Human {
-        fn new(name: &'static str) -> Human {
-            Human{name}
+    impl Animal for Dog {
+        fn new(name: &'static str) -> Dog {
+            Dog{name}
         }
         fn name(&self) -> &'static str { self.name }
-        fn talk(&self) {
-            println!("{} says hello", self.name)
+        fn speak(&self) -> String {
+            format!("{} says woof", self.name)
         }
     }
 
-    impl Animal for Cat {
-        fn new(name: &'static str) -> Cat {
-            Cat{name}
-        }
-        fn name(&self) -> &'static str { self.name }
-        fn talk(&self) {
-            println!("{} says meow", self.name)
+    let mut registry = AnimalRegistry::with_builtins();
+    registry.register::<Dog>("dog");
+    for (species, name) in [("cat", "Fluffy"), ("Dog", "Rex"), ("parrot", "Polly")] {
+        match registry.create(species, name) {
+            Ok(animal) => animal.talk(),
+            Err(e) => println!("{}", e)
         }
     }
+}
 
-    let isaac = Human{name: "Isaac"};
-    isaac.talk();
-    let garfield = Cat{name: "Garfield"};
-    garfield.talk();
+// A zoo simulation
+use rust_programming_language::animals::Zoo;
 
-    //let animals: Vec<Animal> = vec![isaac, garfield]; <--- does not compile
-    // define enum { Human(Human), Cat(Cat) } which uses the Human and Cat structs
-    // or define a vector of Boxes
-    //let mut animals: Vec<Box<dyn Animal>> = Vec::new(); <--- does not compile either...
-    //animals.push(Box::new(isaac));
-    //animals.push(Box::new(garfield));
+fn zoo_simulation() {
+    println!("----- 6.10. Zoo simulation -----");
+
+    // Human and Cat have hunger, energy and happiness (animals/state.rs), which change as they
+    // act; the mood those give decides what they say when they talk
+    // the Zoo (animals/zoo.rs) only sees Box<dyn Animal>, and calls act() on each one every tick
+    let registry = AnimalRegistry::with_builtins();
+    let mut zoo = Zoo::new(42); // same seed, same story
+    for (species, name) in [("human", "Isaac"), ("cat", "Garfield"), ("cat", "Fluffy")] {
+        zoo.add(registry.create(species, name).unwrap())
+    }
+
+    zoo.run(16);
+    println!("after {} ticks:\n", zoo.ticks());
+    print!("{}", zoo)
 }
 
+// Chapter registry
+use crate::registry::{Chapter, Section};
+
+pub const CHAPTER: Chapter = Chapter {
+    number: 6,
+    key: "traits",
+    title: "Traits",
+    sections: &[
+        Section { number: 1, name: "Traits", run: traits },
+        Section { number: 2, name: "Trait parameters", run: trait_params },
+        Section { number: 3, name: "Into", run: into },
+        Section { number: 4, name: "Drop", run: drop_ },
+        Section { number: 5, name: "Operator overloading", run: operator_overloading },
+        Section { number: 6, name: "Static dispatch", run: static_dispatch },
+        Section { number: 7, name: "Dynamic dispatch", run: dynamic_dispatch },
+        Section { number: 8, name: "Why dynamic dispatch", run: why_dynamic_dispatch },
+        Section { number: 9, name: "Vectors of different objects", run: vectors_of_different_objects },
+        Section { number: 10, name: "Zoo simulation", run: zoo_simulation }
+    ]
+};
+
 pub fn main() {
-    println!("----- 6. Traits -----");
-    traits();
-    trait_params();
-    into();
-    drop_();
-    operator_overloading();
-    static_dispatch();
-    dynamic_dispatch();
-    why_dynamic_dispatch();
-}
\ No newline at end of file
+    CHAPTER.run()
+}
@@ -1,4 +1,6 @@
 // Types and Variables
+#![allow(static_mut_refs)]
+#![allow(clippy::approx_constant, clippy::nonminimal_bool, clippy::overly_complex_bool_expr)]
 
 use std::mem;
 use crate::MUTABLE_VAR; // imports from main.rs
@@ -142,10 +144,21 @@ fn stack_and_heap() {
     println!("p2 x-coordinate = {}", p3.x);
 }
 
+// Chapter registry
+use crate::registry::{Chapter, Section};
+
+pub const CHAPTER: Chapter = Chapter {
+    number: 2,
+    key: "types_and_variables",
+    title: "Types and Variables",
+    sections: &[
+        Section { number: 1, name: "Fundamental data types", run: fundamental_data_types },
+        Section { number: 2, name: "Operators", run: operators },
+        Section { number: 3, name: "Scope and shadowing", run: scope_and_shadowing },
+        Section { number: 4, name: "Stack and heap", run: stack_and_heap }
+    ]
+};
+
 pub fn main() {
-    println!("----- 2. Types and Variables -----");
-    fundamental_data_types();
-    operators();
-    scope_and_shadowing();
-    stack_and_heap();
-}
\ No newline at end of file
+    CHAPTER.run()
+}
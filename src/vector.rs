@@ -0,0 +1,176 @@
+//! Vectors in 3D space.
+//!
+//! `Vec3<T>` supports the arithmetic operators (and their `*Assign` forms) between two vectors
+//! and with a scalar `T`, with dot and cross products for any [`Num`] and lengths, directions
+//! and angles for a [`Float`]. A [`Point`](crate::geometry::Point) is a position rather than a
+//! displacement: subtracting two points gives a `Vec3`, and a `Vec3` can be added to a point.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::num::{Float, Num, Zero};
+
+/// A vector with components of type `T`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Vec3<T = f64> {
+    pub x: T,
+    pub y: T,
+    pub z: T
+}
+
+impl<T> Vec3<T> {
+    pub fn new(x: T, y: T, z: T) -> Vec3<T> {
+        Vec3{x, y, z}
+    }
+}
+
+impl<T: Zero> Vec3<T> {
+    /// The zero vector.
+    pub fn zero() -> Vec3<T> {
+        Vec3{x: T::zero(), y: T::zero(), z: T::zero()}
+    }
+}
+
+impl<T: Num> Vec3<T> {
+    /// The dot product `x₁x₂ + y₁y₂ + z₁z₂`.
+    pub fn dot(self, other: Vec3<T>) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// The cross product, perpendicular to both vectors and following the right-hand rule.
+    pub fn cross(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3{
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x
+        }
+    }
+
+    /// The squared length, which needs no square root and is exact for integers.
+    pub fn norm_sqr(self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T: Float> Vec3<T> {
+    /// The length.
+    pub fn norm(self) -> T {
+        self.norm_sqr().sqrt()
+    }
+
+    /// The vector of length 1 in the same direction, or `None` for the zero vector.
+    pub fn normalize(self) -> Option<Vec3<T>> {
+        let norm = self.norm();
+        if norm == T::zero() { None } else { Some(self / norm) }
+    }
+
+    /// The distance between the tips of the two vectors.
+    pub fn distance(self, other: Vec3<T>) -> T {
+        (other - self).norm()
+    }
+
+    /// The vector `t` of the way from `self` to `other`: `self` at 0, `other` at 1.
+    pub fn lerp(self, other: Vec3<T>, t: T) -> Vec3<T> {
+        self + (other - self) * t
+    }
+
+    /// The angle between the vectors in radians, from 0 to π; 0 if either is the zero vector.
+    pub fn angle(self, other: Vec3<T>) -> T {
+        // atan2 stays accurate for nearly parallel vectors, where acos of the dot product doesn't
+        self.cross(other).norm().atan2(self.dot(other))
+    }
+}
+
+// prints as <x, y, z>
+impl<T: fmt::Display> fmt::Display for Vec3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}, {}, {}>", self.x, self.y, self.z)
+    }
+}
+
+// vector op vector
+
+impl<T: Add<Output = T>> Add for Vec3<T> {
+    type Output = Vec3<T>;
+    fn add(self, rhs: Self) -> Self::Output {
+        Vec3{x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z}
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec3<T> {
+    type Output = Vec3<T>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vec3{x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z}
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec3<T> {
+    type Output = Vec3<T>;
+    fn neg(self) -> Self::Output {
+        Vec3{x: -self.x, y: -self.y, z: -self.z}
+    }
+}
+
+impl<T: AddAssign<T>> AddAssign for Vec3<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z
+    }
+}
+
+impl<T: SubAssign<T>> SubAssign for Vec3<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z
+    }
+}
+
+// vector op scalar
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vec3<T> {
+    type Output = Vec3<T>;
+    fn mul(self, rhs: T) -> Self::Output {
+        Vec3{x: self.x * rhs, y: self.y * rhs, z: self.z * rhs}
+    }
+}
+
+impl<T: Div<Output = T> + Copy> Div<T> for Vec3<T> {
+    type Output = Vec3<T>;
+    fn div(self, rhs: T) -> Self::Output {
+        Vec3{x: self.x / rhs, y: self.y / rhs, z: self.z / rhs}
+    }
+}
+
+impl<T: MulAssign<T> + Copy> MulAssign<T> for Vec3<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs
+    }
+}
+
+impl<T: DivAssign<T> + Copy> DivAssign<T> for Vec3<T> {
+    fn div_assign(&mut self, rhs: T) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs
+    }
+}
+
+// scalar op vector
+// coherence rules only allow this for concrete scalar types, hence the macro
+
+macro_rules! scalar_lhs_ops {
+    ($($t:ty)*) => {$(
+        impl Mul<Vec3<$t>> for $t {
+            type Output = Vec3<$t>;
+            fn mul(self, rhs: Vec3<$t>) -> Vec3<$t> {
+                rhs * self
+            }
+        }
+    )*}
+}
+
+scalar_lhs_ops!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
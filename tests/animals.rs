@@ -0,0 +1,201 @@
+use rust_programming_language::animals::{Activity, Animal, AnimalRegistry, AnyAnimal, Cat, Human, Mood, State, Temperament, Zoo};
+use rust_programming_language::random::Rng;
+
+// a species from outside the animals module, which keeps the default speak
+struct Dog {
+    name: &'static str
+}
+
+impl Animal for Dog {
+    fn new(name: &'static str) -> Dog { Dog{name} }
+    fn name(&self) -> &'static str { self.name }
+}
+
+const NAMES: [(&str, &str); 3] = [("human", "Isaac"), ("cat", "Fluffy"), ("cat", "Tom")];
+
+fn boxed() -> Vec<Box<dyn Animal>> {
+    NAMES.iter().map(|&(species, name)| -> Box<dyn Animal> {
+        if species == "human" { Box::new(Human::new(name)) } else { Box::new(Cat::new(name)) }
+    }).collect()
+}
+
+fn enums() -> Vec<AnyAnimal> {
+    NAMES.iter().map(|&(species, name)| {
+        if species == "human" { AnyAnimal::Human(Human::new(name)) } else { AnyAnimal::Cat(Cat::new(name)) }
+    }).collect()
+}
+
+#[test]
+fn trait_objects_and_the_enum_behave_alike() {
+    let (mut boxed, mut enums) = (boxed(), enums());
+    // the same seed for both, so their needs and moods change alike
+    let (mut boxed_rng, mut enum_rng) = (Rng::new(7), Rng::new(7));
+    for _ in 0..200 {
+        for (b, e) in boxed.iter_mut().zip(enums.iter_mut()) {
+            assert_eq!(b.name(), e.name());
+            assert_eq!(b.speak(), e.speak());
+            assert_eq!(b.state(), e.state());
+            assert_eq!(e.as_animal().speak(), e.speak());
+            assert_eq!(b.act(&mut boxed_rng), e.act(&mut enum_rng));
+        }
+    }
+}
+
+#[test]
+fn animals_start_out_content() {
+    let speech: Vec<_> = enums().iter().map(AnyAnimal::speak).collect();
+    assert_eq!(speech, ["Isaac says hello", "Fluffy says meow", "Tom says meow"]);
+    assert_eq!(boxed().iter().map(|a| a.speak()).collect::<Vec<_>>(), speech);
+}
+
+#[test]
+fn registered_species_can_be_created() {
+    let mut registry = AnimalRegistry::with_builtins();
+    assert!(!registry.contains("dog"));
+    registry.register::<Dog>("dog");
+    let rex = registry.create("dog", "Rex").unwrap();
+    assert_eq!((rex.name(), rex.speak()), ("Rex", "Rex cannot talk".to_string()));
+    assert_eq!(registry.create("cat", "Fluffy").unwrap().speak(), "Fluffy says meow");
+    assert_eq!(registry.create("human", "Isaac").unwrap().speak(), "Isaac says hello");
+
+    // a later registration replaces the earlier one
+    registry.register_with("dog", |name| Box::new(Cat::new(name)));
+    assert_eq!(registry.create("dog", "Rex").unwrap().speak(), "Rex says meow");
+}
+
+#[test]
+fn species_names_are_case_insensitive() {
+    let mut registry = AnimalRegistry::new();
+    registry.register::<Dog>("Dog");
+    assert!(registry.contains("dog") && registry.contains("DOG"));
+    assert_eq!(registry.create("dOg", "Rex").unwrap().name(), "Rex");
+    assert_eq!(registry.species().collect::<Vec<_>>(), ["dog"]);
+    registry.register::<Cat>("DOG");
+    assert_eq!(registry.species().count(), 1);
+}
+
+#[test]
+fn unknown_species_lists_the_registered_ones_in_order() {
+    let mut registry = AnimalRegistry::with_builtins();
+    registry.register::<Dog>("Zebra");
+    registry.register::<Dog>("aardvark");
+    let error = registry.create("Unicorn", "Sparkle").err().unwrap();
+    assert_eq!(error.species(), "Unicorn");
+    assert_eq!(error.known(), ["aardvark", "cat", "human", "zebra"]);
+    assert_eq!(error.to_string(), "unknown species 'Unicorn', expected one of: aardvark, cat, human, zebra");
+
+    let error = AnimalRegistry::new().create("cat", "Fluffy").err().unwrap();
+    assert!(error.known().is_empty());
+    assert_eq!(error.to_string(), "unknown species 'cat': no species are registered");
+}
+
+fn zoo(seed: u64, ticks: u32) -> Zoo {
+    let mut zoo = Zoo::new(seed);
+    zoo.add(Box::new(Human::new("Isaac")));
+    zoo.add(Box::new(Cat::new("Fluffy")));
+    zoo.add(Box::new(Dog::new("Rex")));
+    zoo.run(ticks);
+    zoo
+}
+
+fn level(hunger: u8, energy: u8, happiness: u8) -> State {
+    State{hunger, energy, happiness}
+}
+
+#[test]
+fn a_seed_replays_the_same_zoo() {
+    let log = zoo(42, 100).to_string();
+    assert_eq!(zoo(42, 100).to_string(), log);
+    assert_ne!(zoo(43, 100).to_string(), log);
+    assert_ne!(zoo(0, 100).to_string(), zoo(1, 100).to_string());
+    // animals that don't act are still listed
+    assert!(log.ends_with("Rex (hunger 20, energy 80, happiness 60, content):\n  nothing happened\n"), "{}", log);
+}
+
+#[test]
+fn speech_follows_the_mood() {
+    let zoo = zoo(7, 500);
+    let mut moods = Vec::new();
+    for resident in zoo.residents().iter().take(2) {
+        for event in resident.log.iter().filter(|e| e.activity == Activity::Talk) {
+            let mood = event.state.mood();
+            let speech = event.speech.as_deref().unwrap();
+            let expected = match (resident.animal.name(), mood) {
+                ("Isaac", Mood::Happy) => "Isaac says what a lovely day",
+                ("Isaac", Mood::Content) => "Isaac says hello",
+                ("Isaac", Mood::Hungry) => "Isaac asks when lunch is",
+                ("Isaac", Mood::Tired) => "Isaac yawns",
+                ("Isaac", Mood::Grumpy) => "Isaac grumbles",
+                (_, Mood::Happy) => "Fluffy purrs",
+                (_, Mood::Content) => "Fluffy says meow",
+                (_, Mood::Hungry) => "Fluffy meows loudly at the food bowl",
+                (_, Mood::Tired) => "Fluffy yawns",
+                (_, Mood::Grumpy) => "Fluffy hisses"
+            };
+            assert_eq!(speech, expected, "at tick {} ({})", event.tick, event.state);
+            if !moods.contains(&mood) { moods.push(mood) }
+        }
+        assert!(resident.log.iter().all(|e| e.speech.is_some() == (e.activity == Activity::Talk)));
+    }
+    assert!(moods.len() >= 3, "only heard {:?}", moods);
+}
+
+#[test]
+fn moods_put_the_most_pressing_need_first() {
+    assert_eq!(State::default().mood(), Mood::Content);
+    assert_eq!(level(70, 10, 10).mood(), Mood::Hungry);
+    assert_eq!(level(69, 20, 10).mood(), Mood::Tired);
+    assert_eq!(level(69, 21, 25).mood(), Mood::Grumpy);
+    assert_eq!(level(0, 100, 75).mood(), Mood::Happy);
+    assert_eq!(level(0, 100, 74).mood(), Mood::Content);
+}
+
+#[test]
+fn needs_stay_within_bounds() {
+    for seed in 0..20 {
+        let zoo = zoo(seed, 1000);
+        assert_eq!(zoo.ticks(), 1000);
+        for event in zoo.residents().iter().flat_map(|r| &r.log) {
+            let State{hunger, energy, happiness} = event.state;
+            assert!(hunger <= 100 && energy <= 100 && happiness <= 100, "seed {}, tick {}: {}", seed, event.tick, event.state);
+        }
+    }
+}
+
+#[test]
+fn any_temperament_is_allowed() {
+    let extremes = [
+        Temperament{appetite: u32::MAX, sleepiness: u32::MAX, playfulness: u32::MAX, sociability: u32::MAX},
+        Temperament{appetite: 0, sleepiness: 0, playfulness: 0, sociability: 0},
+        Temperament{appetite: u32::MAX, sleepiness: 0, playfulness: 0, sociability: 0}
+    ];
+    let mut rng = Rng::new(1);
+    for temperament in &extremes {
+        for &start in &[level(100, 0, 0), level(0, 100, 100), State::default()] {
+            let mut state = start;
+            for _ in 0..100 {
+                state.step(temperament, &mut rng);
+                assert!(state.hunger <= 100 && state.energy <= 100 && state.happiness <= 100);
+            }
+        }
+    }
+    // with nothing else to draw them, animals only wander
+    let mut state = State::default();
+    assert_eq!(state.step(&extremes[1], &mut rng), Activity::Wander);
+}
+
+#[test]
+fn rng_is_reproducible_and_in_range() {
+    let (mut a, mut b) = (Rng::new(99), Rng::new(99));
+    let first: Vec<u64> = (0..10).map(|_| a.next_u64()).collect();
+    assert_eq!(first, (0..10).map(|_| b.next_u64()).collect::<Vec<_>>());
+    assert_ne!(first, (0..10).map(|_| Rng::new(100).next_u64()).collect::<Vec<_>>());
+
+    let mut rng = Rng::new(5);
+    for _ in 0..1000 {
+        assert!(rng.below(7) < 7);
+        assert!((10..13).contains(&rng.range(10..13)));
+        assert!((0.0..1.0).contains(&rng.next_f64()));
+    }
+    assert_eq!(rng.below(1), 0);
+}
@@ -0,0 +1,301 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+use rust_programming_language::color::{Color, Hsl, Hsv, Lab, Palette, ParseColorErrorKind};
+
+fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    assert!((actual - expected).abs() <= tolerance, "expected {}, got {}", expected, actual);
+}
+
+fn hash(c: Color) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    c.hash(&mut hasher);
+    hasher.finish()
+}
+
+// every 15th value of each channel, corners included
+fn rgb_grid() -> impl Iterator<Item = (u8, u8, u8)> {
+    (0..=255u8).step_by(15).flat_map(|r| (0..=255u8).step_by(15).flat_map(move |g| (0..=255u8).step_by(15).map(move |b| (r, g, b))))
+}
+
+// the total squared distance in L*a*b* from each pixel to the closest palette colour, which
+// is what k-means minimises
+fn error(palette: &Palette, pixels: &[Color]) -> f64 {
+    pixels.iter().map(|p| {
+        let lab = p.to_lab();
+        palette.colors().iter().map(|c| lab.delta_e76(&c.to_lab()).powi(2)).fold(f64::INFINITY, f64::min)
+    }).sum()
+}
+
+#[test]
+fn delta_e2000_matches_sharma_wu_and_dalal() {
+    let pairs = [
+        ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+        ((50.0, 0.0, 0.0), (50.0, -1.0, 2.0), 2.3669),
+        ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492),
+        ((50.0, 2.5, 0.0), (50.0, 0.0, -2.5), 4.3065),
+        ((60.2574, -34.0099, 36.2677), (60.4626, -34.1751, 39.4387), 1.2644),
+        ((22.7233, 20.0904, -46.6940), (23.0331, 14.9730, -42.5619), 2.0373),
+        ((2.0776, 0.0795, -1.1350), (0.9033, -0.0636, -0.5514), 0.9082)
+    ];
+    for &((l1, a1, b1), (l2, a2, b2), expected) in &pairs {
+        let (x, y) = (Lab::new(l1, a1, b1), Lab::new(l2, a2, b2));
+        assert_close(x.delta_e2000(&y), expected, 1e-4);
+        assert_close(y.delta_e2000(&x), expected, 1e-4);
+    }
+}
+
+#[test]
+fn delta_e76_is_euclidean() {
+    assert_close(Lab::new(50.0, 3.0, 4.0).delta_e76(&Lab::new(50.0, 0.0, 0.0)), 5.0, 1e-12);
+}
+
+#[test]
+fn lab_of_known_colours() {
+    let white = Color::RGB(255, 255, 255).to_lab();
+    assert_close(white.l, 100.0, 1e-9);
+    assert_close(white.a, 0.0, 1e-9);
+    assert_close(white.b, 0.0, 1e-9);
+    let black = Color::RGB(0, 0, 0).to_lab();
+    assert_close(black.l, 0.0, 1e-9);
+    let red = Color::Red.to_lab();
+    assert_close(red.l, 53.24, 0.01);
+    assert_close(red.a, 80.09, 0.01);
+    assert_close(red.b, 67.20, 0.01);
+}
+
+#[test]
+fn lab_round_trips_every_grey_and_a_grid_of_colours() {
+    for v in 0..=255u8 {
+        assert_eq!(Color::from(Color::RGB(v, v, v).to_lab()).to_rgb(), (v, v, v));
+    }
+    for r in (0..=255u8).step_by(15) {
+        for g in (0..=255u8).step_by(15) {
+            for b in (0..=255u8).step_by(15) {
+                assert_eq!(Color::from(Color::RGB(r, g, b).to_lab()).to_rgb(), (r, g, b));
+            }
+        }
+    }
+}
+
+#[test]
+fn lab_outside_rgb_is_clamped() {
+    assert_eq!(Color::from(Lab::new(150.0, 0.0, 0.0)).to_rgb(), (255, 255, 255));
+    assert_eq!(Color::from(Lab::new(-10.0, 0.0, 0.0)).to_rgb(), (0, 0, 0));
+}
+
+#[test]
+fn nearest_prefers_what_looks_alike() {
+    let palette: Palette = vec![Color::Red, Color::Green, Color::Blue].into_iter().collect();
+    assert_eq!(palette.nearest_index(Color::RGB(200, 30, 40)), Some(0));
+    assert_eq!(palette.nearest_index(Color::RGB(20, 90, 230)), Some(2));
+    assert_eq!(Palette::new(Vec::new()).nearest(Color::Red), None);
+    // an empty palette leaves pixels alone
+    assert_eq!(Palette::new(Vec::new()).remap(&[Color::Red])[0].to_rgb(), (255, 0, 0));
+}
+
+#[test]
+fn quantizing_to_no_colours_is_empty() {
+    let pixels = [Color::Red, Color::Green, Color::Blue];
+    assert!(Palette::median_cut(&pixels, 0).is_empty());
+    assert!(Palette::k_means(&pixels, 0, 10).is_empty());
+    assert!(Palette::k_means(&[], 4, 10).is_empty());
+}
+
+#[test]
+fn quantizing_gives_at_most_the_distinct_colours() {
+    let pixels = [Color::Red, Color::Red, Color::Blue];
+    assert_eq!(Palette::median_cut(&pixels, 8).len(), 2);
+    assert_eq!(Palette::k_means(&pixels, 8, 10).len(), 2);
+    // with a colour each, the palette is the colours themselves
+    let mut colors: Vec<_> = Palette::k_means(&pixels, 2, 10).colors().iter().map(Color::to_rgb).collect();
+    colors.sort();
+    assert_eq!(colors, vec![(0, 0, 255), (255, 0, 0)]);
+}
+
+#[test]
+fn quantizing_finds_clusters() {
+    // two tight clusters, a dark red and a light blue
+    let mut pixels = Vec::new();
+    for i in 0..10u8 {
+        pixels.push(Color::RGB(150 + i, 10, 10 + i));
+        pixels.push(Color::RGB(120, 170 + i, 240 + i));
+    }
+    for palette in &[Palette::median_cut(&pixels, 2), Palette::k_means(&pixels, 2, 20)] {
+        assert_eq!(palette.len(), 2);
+        let reds = palette.colors().iter().filter(|c| c.to_rgb().0 > c.to_rgb().2).count();
+        assert_eq!(reds, 1);
+        for &pixel in &pixels {
+            assert!(pixel.delta_e(&palette.nearest(pixel).unwrap()) < 5.0);
+        }
+    }
+}
+
+#[test]
+fn k_means_matches_at_least_as_well_as_median_cut() {
+    let pixels: Vec<_> = (0..512u32)
+        .map(|i| Color::RGB((i * 37 % 256) as u8, (i * 91 % 256) as u8, (i * 13 % 256) as u8))
+        .collect();
+    for &n in &[1, 2, 5, 16] {
+        let median_cut = Palette::median_cut(&pixels, n);
+        let k_means = Palette::k_means(&pixels, n, 10);
+        assert_eq!(median_cut.len(), n);
+        assert_eq!(k_means.len(), n);
+        assert!(error(&k_means, &pixels) <= error(&median_cut, &pixels) * 1.01);
+    }
+}
+
+#[test]
+fn remap_replaces_each_pixel_with_its_nearest() {
+    let palette = Palette::new(vec![Color::RGB(0, 0, 0), Color::RGB(255, 255, 255)]);
+    let remapped = palette.remap(&[Color::RGB(30, 30, 30), Color::RGB(220, 220, 220), Color::RGB(30, 30, 30)]);
+    let remapped: Vec<_> = remapped.iter().map(Color::to_rgb).collect();
+    assert_eq!(remapped, vec![(0, 0, 0), (255, 255, 255), (0, 0, 0)]);
+}
+
+#[test]
+fn colours_that_look_the_same_are_equal_and_hash_alike() {
+    let black = Color::CMYK{cyan: 0, magenta: 0, yellow: 0, black: 255};
+    assert_eq!(Color::RGB(0, 0, 0), black);
+    assert_eq!(hash(Color::RGB(0, 0, 0)), hash(black));
+    let red = Color::CMYK{cyan: 0, magenta: 255, yellow: 255, black: 0};
+    assert_eq!(Color::Red, red);
+    assert_eq!(hash(Color::Red), hash(red));
+    assert_ne!(Color::Red, Color::RGB(254, 0, 0));
+
+    let set: HashSet<Color> = vec![Color::Red, Color::RGB(255, 0, 0), red, Color::Blue].into_iter().collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn rgb_to_cmyk_uses_as_much_black_as_possible() {
+    assert_eq!(Color::Red.to_cmyk(), (0, 255, 255, 0));
+    assert_eq!(Color::RGB(0, 0, 0).to_cmyk(), (0, 0, 0, 255));
+    assert_eq!(Color::RGB(255, 255, 255).to_cmyk(), (0, 0, 0, 0));
+    assert_eq!(Color::RGB(128, 64, 0).to_cmyk(), (0, 128, 255, 127));
+    assert_eq!(Color::CMYK{cyan: 1, magenta: 2, yellow: 3, black: 4}.to_cmyk(), (1, 2, 3, 4));
+    assert_eq!(Color::CMYK{cyan: 0, magenta: 128, yellow: 255, black: 127}.to_rgb(), (128, 64, 0));
+}
+
+#[test]
+fn cmyk_hsl_and_hsv_round_trip_through_rgb() {
+    for rgb in rgb_grid() {
+        let (cyan, magenta, yellow, black) = Color::from(rgb).to_cmyk();
+        assert_eq!(Color::CMYK{cyan, magenta, yellow, black}.to_rgb(), rgb);
+        assert_eq!(Color::from(Color::from(rgb).to_hsl()).to_rgb(), rgb);
+        assert_eq!(Color::from(Color::from(rgb).to_hsv()).to_rgb(), rgb);
+        assert_eq!(Color::from(Hsv::from(Color::from(rgb).to_hsl())).to_rgb(), rgb);
+        assert_eq!(Color::from(Hsl::from(Color::from(rgb).to_hsv())).to_rgb(), rgb);
+    }
+}
+
+#[test]
+fn hsl_and_hsv_of_known_colours() {
+    assert_eq!(Color::Red.to_hsl(), Hsl{hue: 0.0, saturation: 1.0, lightness: 0.5});
+    assert_eq!(Color::Blue.to_hsv(), Hsv{hue: 240.0, saturation: 1.0, value: 1.0});
+    assert_eq!(Color::RGB(128, 128, 128).to_hsl().saturation, 0.0);
+    assert_eq!(Color::RGB(0, 0, 0).to_hsv(), Hsv{hue: 0.0, saturation: 0.0, value: 0.0});
+    assert_eq!(Color::from(Hsl::new(120.0, 1.0, 0.25)).to_rgb(), (0, 128, 0));
+    assert_eq!(Color::from(Hsv::new(60.0, 1.0, 1.0)).to_rgb(), (255, 255, 0));
+    assert_eq!(Hsv::from(Hsl::new(0.0, 1.0, 0.5)), Hsv{hue: 0.0, saturation: 1.0, value: 1.0});
+    assert_eq!(Hsl::from(Hsv::new(0.0, 0.0, 1.0)), Hsl{hue: 0.0, saturation: 0.0, lightness: 1.0});
+}
+
+#[test]
+fn hsl_and_hsv_components_are_normalised() {
+    assert_eq!(Hsl::new(-30.0, 2.0, -1.0), Hsl{hue: 330.0, saturation: 1.0, lightness: 0.0});
+    assert_eq!(Hsv::new(720.0, 0.5, 1.5), Hsv{hue: 0.0, saturation: 0.5, value: 1.0});
+    assert_eq!(Color::RGB(255, 128, 0).to_hsl().to_string(), "hsl(30, 100%, 50%)");
+    assert_eq!(format!("{:.1}", Color::Blue.to_hsv()), "hsv(240.0, 100.0%, 100.0%)");
+}
+
+#[test]
+fn hex_round_trips() {
+    assert_eq!(Color::RGB(255, 128, 0).to_hex(), "#ff8000");
+    assert_eq!(Color::CMYK{cyan: 0, magenta: 0, yellow: 0, black: 255}.to_hex(), "#000000");
+    for rgb in rgb_grid() {
+        assert_eq!(Color::from_hex(&Color::from(rgb).to_hex()).unwrap().to_rgb(), rgb);
+    }
+    assert_eq!(Color::from_hex("#F80").unwrap().to_rgb(), (255, 136, 0));
+    assert_eq!(Color::from_hex("ff800080").unwrap().to_rgb(), (255, 128, 0));
+    assert_eq!(Color::from_hex("#f808").unwrap().to_rgb(), (255, 136, 0));
+}
+
+#[test]
+fn hex_errors_report_kind_and_offset() {
+    let error = |s: &str| {
+        let e = Color::from_hex(s).unwrap_err();
+        (e.kind(), e.offset())
+    };
+    assert_eq!(error("#12345"), (ParseColorErrorKind::WrongLength, 1));
+    assert_eq!(error(""), (ParseColorErrorKind::WrongLength, 0));
+    assert_eq!(error("#12g"), (ParseColorErrorKind::InvalidDigit, 3));
+    assert_eq!(error("12 4"), (ParseColorErrorKind::InvalidDigit, 2));
+    assert_eq!(Color::from_hex("#12g").unwrap_err().to_string(), "expected a hex digit at byte 3");
+}
+
+#[test]
+fn css_names_are_sorted_and_all_found() {
+    let names: Vec<_> = Color::css_names().collect();
+    assert_eq!(names.len(), 148);
+    for pair in names.windows(2) {
+        assert!(pair[0] < pair[1], "{} should come before {}", pair[1], pair[0]);
+    }
+    for name in names {
+        assert!(Color::from_name(name).is_some(), "{} not found", name);
+        assert!(Color::from_name(&name.to_ascii_uppercase()).is_some(), "{} not found in upper case", name);
+    }
+}
+
+#[test]
+fn css_names() {
+    assert_eq!(Color::from_name("RebeccaPurple").unwrap().to_rgb(), (102, 51, 153));
+    assert_eq!(Color::from_name("green").unwrap().to_rgb(), (0, 128, 0));
+    assert_eq!(Color::from_name("lime"), Some(Color::Green));
+    assert_eq!(Color::from_name("blurple"), None);
+    assert_eq!("  aliceblue ".parse(), Ok(Color::RGB(240, 248, 255)));
+}
+
+#[test]
+fn css_syntax_forms() {
+    let css = |s: &str| {
+        let (color, alpha) = Color::from_css(s).unwrap_or_else(|e| panic!("{:?} should parse, but: {}", s, e));
+        (color.to_rgb(), alpha)
+    };
+    let orange = (255, 128, 0);
+    assert_eq!(css("#f80"), ((255, 136, 0), 1.0));
+    assert_eq!(css("#FF880080"), ((255, 136, 0), 128.0 / 255.0));
+    assert_eq!(css("rgb(255, 128, 0)"), (orange, 1.0));
+    assert_eq!(css("RGBA(255,128,0,0.5)"), (orange, 0.5));
+    assert_eq!(css("rgb(255 128 0 / 50%)"), (orange, 0.5));
+    assert_eq!(css("rgb(100%, 50%, 0%)"), (orange, 1.0));
+    assert_eq!(css("rgb(300, -5, 1e1)"), ((255, 0, 10), 1.0));
+    assert_eq!(css("hsl(30, 100%, 50%)"), (orange, 1.0));
+    assert_eq!(css("hsl(0.5turn 100% 50%)"), ((0, 255, 255), 1.0));
+    assert_eq!(css("hsla(120deg, 100%, 25%, 0.3)"), ((0, 128, 0), 0.3));
+    assert_eq!(css("hsl(200grad 100 50)"), ((0, 255, 255), 1.0));
+    assert_eq!(css("cmyk(0%, 100%, 100%, 0%)"), ((255, 0, 0), 1.0));
+    assert_eq!(css("device-cmyk(0 1 1 0 / 0.5)"), ((255, 0, 0), 0.5));
+}
+
+#[test]
+fn css_errors_report_kind_and_offset() {
+    use ParseColorErrorKind::*;
+    let error = |s: &str| {
+        let e = Color::from_css(s).unwrap_err();
+        (e.kind(), e.offset())
+    };
+    assert_eq!(error(""), (Expected("a colour name, '#' or a function such as rgb()"), 0));
+    assert_eq!(error("notacolour"), (UnknownName, 0));
+    assert_eq!(error("  blurple"), (UnknownName, 2));
+    assert_eq!(error("hsv(0, 0%, 0%)"), (UnknownFunction, 0));
+    assert_eq!(error("#12345"), (WrongLength, 1));
+    assert_eq!(error("#12x"), (InvalidDigit, 3));
+    assert_eq!(error("rgb(255, 0)"), (Expected("','"), 10));
+    assert_eq!(error("rgb(255 0 0, 1)"), (Expected("'/' or ')'"), 11));
+    assert_eq!(error("rgb(10deg, 0, 0)"), (Expected("a number from 0 to 255 or a percentage"), 4));
+    assert_eq!(error("hsl(x, 0%, 0%)"), (Expected("a hue in degrees, or an angle such as 0.5turn"), 4));
+    assert_eq!(error("red blue"), (TrailingInput, 4));
+    assert_eq!(Color::from_css("rgb(255, 0)").unwrap_err().to_string(), "expected ',' at byte 10");
+}
@@ -0,0 +1,118 @@
+use std::f64::consts::{E, FRAC_PI_2, PI};
+
+use rust_programming_language::complex::Complex;
+
+const TOLERANCE: f64 = 1e-12;
+
+fn assert_close(actual: Complex<f64>, expected: Complex<f64>) {
+    assert!((actual - expected).norm() <= TOLERANCE * expected.norm().max(1.0),
+            "expected {}, got {}", expected, actual);
+}
+
+fn c(re: f64, im: f64) -> Complex<f64> {
+    Complex::new(re, im)
+}
+
+#[test]
+fn complex_operators() {
+    let (z, w) = (c(1.0, 2.0), c(3.0, -4.0));
+    assert_eq!(z + w, c(4.0, -2.0));
+    assert_eq!(z - w, c(-2.0, 6.0));
+    assert_eq!(z * w, c(11.0, 2.0));
+    assert_close(z / w, c(-0.2, 0.4));
+    assert_close(z / w * w, z);
+    assert_eq!(-z, c(-1.0, -2.0));
+    assert_eq!(z.conj(), c(1.0, -2.0));
+    assert_eq!(Complex::<f64>::i() * Complex::i(), c(-1.0, 0.0));
+
+    let mut x = z;
+    x += w;
+    x -= w;
+    x *= w;
+    x /= w;
+    assert_close(x, z);
+}
+
+#[test]
+fn scalar_operators_on_either_side() {
+    let z = c(1.0, 2.0);
+    assert_eq!(z + 1.0, c(2.0, 2.0));
+    assert_eq!(z - 1.0, c(0.0, 2.0));
+    assert_eq!(z * 2.0, c(2.0, 4.0));
+    assert_eq!(z / 2.0, c(0.5, 1.0));
+    assert_eq!(1.0 + z, c(2.0, 2.0));
+    assert_eq!(1.0 - z, c(0.0, -2.0));
+    assert_eq!(2.0 * z, c(2.0, 4.0));
+    assert_close(5.0 / z, c(1.0, -2.0));
+
+    let mut x = z;
+    x += 1.0;
+    x *= 2.0;
+    x -= 2.0;
+    x /= 2.0;
+    assert_eq!(x, z);
+}
+
+#[test]
+fn integer_division_truncates() {
+    assert_eq!(Complex::new(7, 1) / Complex::new(1, 1), Complex::new(4, -3));
+    assert_eq!(10 / Complex::new(3, 1), Complex::new(3, -1));
+}
+
+#[test]
+fn division_neither_overflows_nor_underflows_needlessly() {
+    assert_eq!(c(1e300, 1e300) / c(1e300, 1e300), c(1.0, 0.0));
+    assert_close(c(1e-300, 2e-300) / c(1e-300, 1e-300), c(1.5, 0.5));
+    assert_close(c(1e300, 0.0) / c(1e-10, 1e300), c(0.0, -1.0));
+}
+
+#[test]
+fn polar_form() {
+    let z = Complex::from_polar(2.0, FRAC_PI_2);
+    assert_close(z, c(0.0, 2.0));
+    let (r, theta) = c(-1.0, 0.0).to_polar();
+    assert_eq!((r, theta), (1.0, PI));
+    assert_eq!(c(3.0, 4.0).norm(), 5.0);
+}
+
+#[test]
+fn exp_and_ln_are_inverses() {
+    assert_close(c(1.0, 0.0).exp(), c(E, 0.0));
+    assert_close(c(0.0, PI).exp(), c(-1.0, 0.0));
+    assert_close(c(-1.0, 0.0).ln(), c(0.0, PI));
+    for &z in &[c(0.5, -1.5), c(-2.0, 0.25), c(3.0, 3.0)] {
+        assert_close(z.ln().exp(), z);
+        assert_close(z.exp().ln(), z);
+    }
+}
+
+#[test]
+fn sqrt_is_the_principal_root() {
+    assert_close(c(-4.0, 0.0).sqrt(), c(0.0, 2.0));
+    assert_close(c(-4.0, -0.0).sqrt(), c(0.0, -2.0));
+    assert_close(c(0.0, 2.0).sqrt(), c(1.0, 1.0));
+    for &z in &[c(0.5, -1.5), c(-2.0, 0.25), c(-1e-20, 1.0)] {
+        let root = z.sqrt();
+        assert!(root.re >= 0.0);
+        assert_close(root * root, z);
+    }
+}
+
+#[test]
+fn powers_of_zero() {
+    let zero = c(0.0, 0.0);
+    assert_eq!(zero.powf(2.5), zero);
+    assert_eq!(zero.powf(0.0), c(1.0, 0.0));
+    assert_eq!(zero.powf(-1.0).re, f64::INFINITY);
+    assert!(zero.powf(f64::NAN).re.is_nan());
+    assert_close(c(0.0, 1.0).powf(2.0), c(-1.0, 0.0));
+}
+
+#[test]
+fn display_puts_the_sign_between_the_parts() {
+    assert_eq!(c(1.0, 2.0).to_string(), "1 + 2i");
+    assert_eq!(c(1.0, -2.0).to_string(), "1 - 2i");
+    assert_eq!(c(-1.5, -0.0).to_string(), "-1.5 - 0i");
+    assert_eq!(format!("{:.2}", c(1.0, -1.0 / 3.0)), "1.00 - 0.33i");
+    assert_eq!(Complex::new(3, -4).to_string(), "3 - 4i");
+}
@@ -0,0 +1,87 @@
+use rust_programming_language::complex::{Complex, ParseComplexErrorKind};
+
+const TOLERANCE: f64 = 1e-9;
+
+fn parse(s: &str) -> Complex<f64> {
+    s.parse().unwrap_or_else(|e| panic!("{:?} should parse, but: {}", s, e))
+}
+
+fn assert_error(s: &str, kind: ParseComplexErrorKind, offset: usize) {
+    let e = s.parse::<Complex<f64>>().expect_err(s);
+    assert_eq!((e.kind(), e.offset()), (kind, offset), "for {:?}", s);
+}
+
+// equal, counting NaN as equal to NaN and telling -0 from 0
+fn same(x: f64, y: f64) -> bool {
+    (x.is_nan() && y.is_nan()) || (x == y && x.is_sign_negative() == y.is_sign_negative())
+}
+
+#[test]
+fn rectangular_forms() {
+    assert_eq!(parse("3+4i"), Complex::new(3.0, 4.0));
+    assert_eq!(parse(" 1 - 2i "), Complex::new(1.0, -2.0));
+    assert_eq!(parse("-2.5i"), Complex::new(0.0, -2.5));
+    assert_eq!(parse("7"), Complex::new(7.0, 0.0));
+    assert_eq!(parse("1e-3-2j"), Complex::new(0.001, -2.0));
+    assert_eq!(parse("-i"), Complex::new(0.0, -1.0));
+    assert_eq!(parse("4i + 1"), Complex::new(1.0, 4.0));
+    assert_eq!(parse("1 + -2i"), Complex::new(1.0, -2.0));
+    assert_eq!(parse(".5 + 2.i"), Complex::new(0.5, 2.0));
+}
+
+#[test]
+fn polar_forms() {
+    let z = parse("5∠53.13°");
+    assert!((z.re - 3.0).abs() < 1e-4 && (z.im - 4.0).abs() < 1e-4, "got {}", z);
+    let z = parse("2∠-1.5707963267948966");
+    assert!((z - Complex::new(0.0, -2.0)).norm() < TOLERANCE, "got {}", z);
+    assert_eq!(parse("3 ∠ 0°"), Complex::new(3.0, 0.0));
+}
+
+#[test]
+fn special_values() {
+    let z = parse("NaN - 0i");
+    assert!(z.re.is_nan() && same(z.im, -0.0));
+    assert_eq!(parse("-inf + infinityi"), Complex::new(f64::NEG_INFINITY, f64::INFINITY));
+}
+
+#[test]
+fn display_round_trips() {
+    let values = [
+        Complex::new(3.0, 4.0),
+        Complex::new(-1.25, -0.5),
+        Complex::new(0.0, -0.0),
+        Complex::new(-0.0, 0.0),
+        Complex::new(1e-300, 6.02e23),
+        Complex::new(f64::INFINITY, f64::NEG_INFINITY),
+        Complex::new(f64::NAN, 1.0),
+        Complex::new(2.0, f64::NAN)
+    ];
+    for z in &values {
+        let back = parse(&z.to_string());
+        assert!(same(back.re, z.re) && same(back.im, z.im), "{} came back as {:?}", z, back);
+    }
+}
+
+#[test]
+fn errors_report_kind_and_offset() {
+    use ParseComplexErrorKind::*;
+    assert_error("", Empty, 0);
+    assert_error("   ", Empty, 3);
+    assert_error("+", ExpectedNumber, 1);
+    assert_error("1 + ", ExpectedNumber, 4);
+    assert_error("4k", UnexpectedInput, 1);
+    assert_error("3+4i5", UnexpectedInput, 4);
+    assert_error("3i 4", UnexpectedInput, 3);
+    assert_error("1+2i+3i", UnexpectedInput, 4);
+    assert_error("1 + 2", DuplicatePart, 4);
+    assert_error("2i - 3j", DuplicatePart, 5);
+    assert_error("5∠", ExpectedNumber, 4);
+    assert_error("5∠1 x", UnexpectedInput, 6);
+}
+
+#[test]
+fn errors_display_their_offset() {
+    let e = "1 + 2".parse::<Complex<f64>>().unwrap_err();
+    assert_eq!(e.to_string(), "real or imaginary part given twice at byte 4");
+}
@@ -0,0 +1,144 @@
+use std::f64::consts::PI;
+
+use rust_programming_language::complex::Complex;
+use rust_programming_language::fft::*;
+
+const TOLERANCE: f64 = 1e-9;
+const LENGTHS: [usize; 9] = [1, 2, 3, 5, 7, 8, 12, 16, 100];
+
+fn assert_all_close(actual: &[Complex<f64>], expected: &[Complex<f64>]) {
+    assert_eq!(actual.len(), expected.len());
+    for (k, (&x, &y)) in actual.iter().zip(expected).enumerate() {
+        assert!((x - y).norm() <= TOLERANCE * y.norm().max(1.0), "at {}: expected {}, got {}", k, y, x);
+    }
+}
+
+// the same values on every run, spread over roughly -1..1
+fn signal(n: usize) -> Vec<Complex<f64>> {
+    (0..n).map(|j| {
+        let j = j as f64;
+        Complex::new((j * 1.7 + 0.3).sin(), (j * 0.9 - 1.1).cos() / 2.0)
+    }).collect()
+}
+
+fn to_f32(x: &[Complex<f64>]) -> Vec<Complex<f32>> {
+    x.iter().map(|z| Complex::new(z.re as f32, z.im as f32)).collect()
+}
+
+// X[k] = Σ x[j]·e^(-2πi·jk/n), term by term
+fn naive_dft(x: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    let n = x.len();
+    (0..n).map(|k| {
+        let mut sum = Complex::new(0.0, 0.0);
+        for (j, &x) in x.iter().enumerate() {
+            sum += x * Complex::from_polar(1.0, -2.0 * PI * (j * k) as f64 / n as f64)
+        }
+        sum
+    }).collect()
+}
+
+fn naive_convolution(a: &[Complex<f64>], b: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    let mut result = vec![Complex::new(0.0, 0.0); a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            result[i + j] += x * y
+        }
+    }
+    result
+}
+
+#[test]
+fn transforms_match_the_definition() {
+    for &n in &LENGTHS {
+        let x = signal(n);
+        let expected = naive_dft(&x);
+        assert_all_close(&dft(&x), &expected);
+        assert_all_close(&fft(&x), &expected);
+    }
+}
+
+#[test]
+fn in_place_transforms_match_the_definition() {
+    for &n in LENGTHS.iter().filter(|n| n.is_power_of_two()) {
+        let x = signal(n);
+        let mut data = x.clone();
+        fft_in_place(&mut data);
+        assert_all_close(&data, &naive_dft(&x));
+        ifft_in_place(&mut data);
+        assert_all_close(&data, &x);
+    }
+}
+
+#[test]
+#[should_panic(expected = "power-of-two length")]
+fn in_place_transforms_need_a_power_of_two() {
+    fft_in_place(&mut signal(12))
+}
+
+#[test]
+fn inverse_undoes_the_transform() {
+    for &n in &LENGTHS {
+        let x = signal(n);
+        assert_all_close(&ifft(&fft(&x)), &x);
+        assert_all_close(&fft(&ifft(&x)), &x);
+    }
+}
+
+#[test]
+fn transform_of_an_impulse_is_flat() {
+    let mut x = vec![Complex::new(0.0, 0.0); 7];
+    x[0] = Complex::new(1.0, 0.0);
+    assert_all_close(&fft(&x), &[Complex::new(1.0, 0.0); 7]);
+    assert!(fft::<f64>(&[]).is_empty());
+}
+
+#[test]
+fn convolution_matches_the_definition() {
+    for &(m, n) in &[(1, 1), (3, 5), (7, 2), (12, 16), (100, 3)] {
+        let (a, b) = (signal(m), signal(n + 1)[1..].to_vec());
+        assert_all_close(&convolve(&a, &b), &naive_convolution(&a, &b));
+    }
+    assert!(convolve(&[], &signal(3)).is_empty());
+}
+
+#[test]
+fn real_convolution_multiplies_polynomials() {
+    // (1 + 2x + 3x²)(4 - x) = 4 + 7x + 10x² - 3x³
+    let product = convolve_real(&[1.0f64, 2.0, 3.0], &[4.0, -1.0]);
+    let expected = [4.0, 7.0, 10.0, -3.0];
+    assert_eq!(product.len(), expected.len());
+    for (x, y) in product.iter().zip(&expected) {
+        assert!((x - y).abs() <= TOLERANCE, "expected {}, got {}", y, x);
+    }
+}
+
+#[test]
+fn circular_convolution_wraps_around() {
+    for &n in &[4, 5, 12] {
+        let (a, b) = (signal(n), signal(2 * n)[n..].to_vec());
+        let linear = naive_convolution(&a, &b);
+        let mut expected = linear[..n].to_vec();
+        for (k, &z) in linear[n..].iter().enumerate() {
+            expected[k] += z
+        }
+        assert_all_close(&circular_convolve(&a, &b), &expected);
+    }
+}
+
+#[test]
+fn f32_transforms_are_accurate_to_f32_precision() {
+    for &n in &LENGTHS {
+        let x = signal(n);
+        let expected = naive_dft(&x);
+        // errors grow with the size of the largest output, not with each output
+        let scale = expected.iter().map(|z| z.norm()).fold(1.0, f64::max);
+        for (k, (z, y)) in fft(&to_f32(&x)).iter().zip(&expected).enumerate() {
+            let error = (Complex::new(z.re as f64, z.im as f64) - *y).norm();
+            assert!(error <= 1e-5 * scale, "n = {}, at {}: expected {}, got {}", n, k, y, z);
+        }
+        for (z, y) in ifft(&fft(&to_f32(&x))).iter().zip(to_f32(&x)) {
+            assert!((*z - y).norm() <= 1e-5, "n = {}: expected {}, got {}", n, y, z);
+        }
+    }
+    assert_eq!(convolve_real(&[1.0f32, 2.0, 3.0], &[4.0, -1.0]).iter().map(|x| x.round()).collect::<Vec<_>>(), [4.0, 7.0, 10.0, -3.0]);
+}
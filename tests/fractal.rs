@@ -0,0 +1,72 @@
+use rust_programming_language::complex::Complex;
+use rust_programming_language::fractal::{render, Fractal, Image, Options, Palette, Viewport};
+
+fn small(threads: usize) -> Options {
+    Options{width: 64, height: 48, max_iterations: 100, threads, ..Options::default()}
+}
+
+#[test]
+fn points_in_the_set_never_escape() {
+    for &c in &[Complex::new(0.0, 0.0), Complex::new(-1.0, 0.0), Complex::new(0.25, 0.0), Complex::new(0.0, 1.0)] {
+        assert_eq!(Fractal::Mandelbrot.escape_time(c, 1000), 1000, "for {}", c);
+    }
+    // the filled Julia set for c = 0 is the closed unit disc
+    assert_eq!(Fractal::Julia(Complex::new(0.0, 0.0)).escape_time(Complex::new(0.6, -0.5), 1000), 1000);
+}
+
+#[test]
+fn points_far_outside_escape_at_once() {
+    // z₁ = c is already outside the radius 2 disc
+    assert_eq!(Fractal::Mandelbrot.escape_time(Complex::new(3.0, 3.0), 1000), 1);
+    assert_eq!(Fractal::Mandelbrot.escape_time(Complex::new(-100.0, 0.0), 1000), 1);
+    // a Julia orbit starts at the point itself
+    assert_eq!(Fractal::Julia(Complex::new(-0.8, 0.156)).escape_time(Complex::new(3.0, 0.0), 1000), 0);
+    assert_eq!(Fractal::Mandelbrot.escape_time(Complex::new(3.0, 3.0), 0), 0);
+}
+
+#[test]
+fn pixels_map_to_their_centres() {
+    let options = Options{viewport: Viewport{center: Complex::new(0.0, 0.0), width: 4.0}, width: 4, height: 2, ..Options::default()};
+    assert_eq!(options.pixel_to_point(0, 0), Complex::new(-1.5, 0.5));
+    assert_eq!(options.pixel_to_point(3, 0), Complex::new(1.5, 0.5));
+    assert_eq!(options.pixel_to_point(0, 1), Complex::new(-1.5, -0.5));
+    assert_eq!(options.pixel_to_point(3, 1), Complex::new(1.5, -0.5));
+
+    // the height follows the aspect ratio, around the viewport's centre
+    let options = Options{viewport: Viewport{center: Complex::new(-1.0, 2.0), width: 8.0}, width: 8, height: 4, ..Options::default()};
+    assert_eq!(options.pixel_to_point(0, 0), Complex::new(-4.5, 3.5));
+    assert_eq!(options.pixel_to_point(7, 3), Complex::new(2.5, 0.5));
+}
+
+#[test]
+fn ppm_and_pgm_headers() {
+    let image = Image{width: 3, height: 2, max_iterations: 10, iterations: vec![10, 0, 5, 10, 10, 1]};
+
+    let mut ppm = Vec::new();
+    image.write_ppm(&mut ppm, Palette::Fire).unwrap();
+    assert_eq!(&ppm[..11], b"P6\n3 2\n255\n");
+    assert_eq!(ppm.len(), 11 + 3 * 6);
+    // points in the set are black
+    assert_eq!(&ppm[11..14], &[0, 0, 0]);
+
+    let mut pgm = Vec::new();
+    image.write_pgm(&mut pgm, Palette::Grayscale).unwrap();
+    assert_eq!(&pgm[..11], b"P5\n3 2\n255\n");
+    assert_eq!(pgm.len(), 11 + 6);
+    assert_eq!(pgm[11], 0);
+}
+
+#[test]
+fn threads_do_not_change_the_image() {
+    let single = render(&small(1));
+    assert_eq!(single.iterations.len(), 64 * 48);
+    for &threads in &[2, 3, 8, 100] {
+        assert_eq!(render(&small(threads)), single, "with {} threads", threads);
+    }
+
+    let julia = Options{fractal: Fractal::Julia(Complex::new(-0.8, 0.156)), ..small(1)};
+    let (mut one, mut many) = (Vec::new(), Vec::new());
+    render(&julia).write_ppm(&mut one, Palette::Ocean).unwrap();
+    render(&Options{threads: 7, ..julia}).write_ppm(&mut many, Palette::Ocean).unwrap();
+    assert_eq!(one, many);
+}
@@ -0,0 +1,68 @@
+use rust_programming_language::geometry;
+use rust_programming_language::geometry::generic::{Line, Point};
+
+#[test]
+fn resize_truncates_or_pads_with_zeros() {
+    let p = Point::new([1, 2, 3]);
+    assert_eq!(p.resize::<2>(), Point::new([1, 2]));
+    assert_eq!(p.resize::<5>(), Point::new([1, 2, 3, 0, 0]));
+    assert_eq!(p.resize::<3>(), p);
+    assert_eq!(p.resize::<0>().dimension(), 0);
+    assert_eq!(Point::new([1.5f32]).resize::<3>(), Point::new([1.5, 0.0, 0.0]));
+
+    let l = Line::new(Point::new([1u8, 2]), Point::new([3, 4]));
+    assert_eq!(l.resize::<3>(), Line::new(Point::new([1, 2, 0]), Point::new([3, 4, 0])));
+    assert_eq!(l.resize::<1>(), Line::new(Point::new([1]), Point::new([3])));
+}
+
+#[test]
+fn widening_conversions_keep_every_value() {
+    let p: Point<f64, 3> = Point::new([0.1f32, f32::MAX, -0.0]).into();
+    assert_eq!(p.coords, [0.1f32 as f64, f32::MAX as f64, -0.0]);
+    assert!(p.coords[2].is_sign_negative());
+    assert_eq!(Point::<f64, 2>::from(Point::new([i32::MIN, i32::MAX])).coords, [-2147483648.0, 2147483647.0]);
+    assert_eq!(Point::<f64, 1>::from(Point::new([u32::MAX])).coords, [4294967295.0]);
+    assert_eq!(Point::<f32, 2>::from(Point::new([i16::MIN, 7])).coords, [-32768.0, 7.0]);
+    assert_eq!(Point::<f32, 2>::from(Point::new([u16::MAX, 0])).coords, [65535.0, 0.0]);
+    assert_eq!(Point::<f32, 1>::from(Point::new([-128i8])).coords, [-128.0]);
+
+    let l: Line<f64, 2> = Line::new(Point::new([1u8, 2]), Point::new([254, 255])).into();
+    assert_eq!(l, Line::new(Point::new([1.0, 2.0]), Point::new([254.0, 255.0])));
+    // any other lossless From goes through convert
+    assert_eq!(Point::new([1u8, 2]).convert::<u64>(), Point::new([1u64, 2]));
+    assert_eq!(Point::new([1.5f32, -2.0]).map(|x| x as i32), Point::new([1, -2]));
+}
+
+#[test]
+fn conversions_to_and_from_other_representations() {
+    let p: Point<i32, 2> = (3, 4).into();
+    assert_eq!(<(i32, i32)>::from(p), (3, 4));
+    assert_eq!(<[i32; 2]>::from(p), [3, 4]);
+    assert_eq!(Point::from([5, 6]), Point::new([5, 6]));
+
+    let q: Point<f64, 3> = geometry::Point::new(1.0, 2.0, 3.0).into();
+    assert_eq!(q.coords, [1.0, 2.0, 3.0]);
+    assert_eq!(geometry::Point::from(q), geometry::Point::new(1.0, 2.0, 3.0));
+    let line = geometry::Line::new(geometry::Point::origin(), geometry::Point::new(1.0, 1.0, 1.0));
+    assert_eq!(geometry::Line::from(Line::from(line)), line);
+}
+
+#[test]
+fn distances_are_exact_for_integers() {
+    let (p, q) = (Point::new([0u32, 10]), Point::new([3u32, 6]));
+    assert_eq!(p.distance_sqr(&q), 25);
+    assert_eq!(q.distance_sqr(&p), 25);
+    assert_eq!(Line::new(p, q).len_sqr(), 25);
+    assert_eq!(Point::new([0.0, 10.0]).distance(&Point::new([3.0, 6.0])), 5.0);
+    assert_eq!(Line::new(Point::new([0.0, 0.0]), Point::new([2.0, 4.0])).midpoint(), Point::new([1.0, 2.0]));
+}
+
+#[test]
+fn indexing_and_display() {
+    let mut p = Point::new([1, 2, 3, 4]);
+    p[3] = 40;
+    assert_eq!((p[0], p[3], p.dimension()), (1, 40, 4));
+    assert_eq!(p.to_string(), "(1, 2, 3, 40)");
+    assert_eq!(format!("{:.1}", Point::new([0.25, 2.0])), "(0.2, 2.0)");
+    assert_eq!(Line::new(Point::new([1]), Point::new([2])).to_string(), "[(1), (2)]");
+}
@@ -0,0 +1,151 @@
+use std::convert::TryFrom;
+
+use rust_programming_language::geometry::geojson::{FromGeoJson, GeoJsonErrorKind, ToGeoJson, MAX_DEPTH};
+use rust_programming_language::geometry::wkt::{FromWkt, ToWkt, WktErrorKind};
+use rust_programming_language::geometry::{Geometry, GeometryError, Line, Point};
+use rust_programming_language::shapes::{Polygon, RegularPolygon};
+
+// geometries with numbers that are awkward to write exactly
+fn samples() -> Vec<Geometry> {
+    let p = |x, y, z| Point::new(x, y, z);
+    vec![
+        Geometry::Point(p(1.0, 2.0, 0.0)),
+        Geometry::Point(p(0.1, -1e-300, 12345678.9)),
+        Geometry::LineString(vec![p(1.0 / 3.0, 2.0f64.sqrt(), 0.0), p(-0.0, 1e21, 0.0), p(5e-324, f64::MAX, 0.0)]),
+        Geometry::Polygon(vec![
+            vec![p(0.0, 0.0, 0.0), p(10.0, 0.0, 0.0), p(10.0, 10.0, 0.0), p(0.0, 0.0, 0.0)],
+            vec![p(1.0, 1.0, 0.0), p(2.0, 1.0, 0.0), p(2.0, 2.0, 0.0), p(1.0, 1.0, 0.0)]
+        ]),
+        Geometry::Polygon(vec![vec![p(0.0, 0.0, 1.5), p(1.0, 0.0, 2.5), p(0.0, 1.0, 3.5), p(0.0, 0.0, 1.5)]])
+    ]
+}
+
+#[test]
+fn wkt_round_trips() {
+    for geometry in samples() {
+        let wkt = geometry.to_wkt().unwrap();
+        assert_eq!(Geometry::from_wkt(&wkt).unwrap(), geometry, "{}", wkt);
+    }
+    let polygon = Polygon::new(RegularPolygon::new((1.0, 2.0), 7, 3.0).vertices());
+    assert_eq!(Polygon::from_wkt(&polygon.to_wkt().unwrap()).unwrap(), polygon);
+    let line = Line::new(Point::new(0.5, -1.0, 2.0), Point::new(3.0, 4.0, -5.0));
+    assert_eq!(Line::from_wkt(&line.to_wkt().unwrap()).unwrap(), line);
+}
+
+#[test]
+fn geojson_round_trips() {
+    for geometry in samples() {
+        let json = geometry.to_geojson().unwrap();
+        assert_eq!(Geometry::from_geojson(&json).unwrap(), geometry, "{}", json);
+    }
+    let polygon = Polygon::new(RegularPolygon::new((1.0, 2.0), 7, 3.0).vertices());
+    assert_eq!(Polygon::from_geojson(&polygon.to_geojson().unwrap()).unwrap(), polygon);
+    let line = Line::new(Point::new(0.5, -1.0, 2.0), Point::new(3.0, 4.0, -5.0));
+    assert_eq!(Line::from_geojson(&line.to_geojson().unwrap()).unwrap(), line);
+}
+
+#[test]
+fn non_finite_coordinates_are_neither_read_nor_written() {
+    for x in [f64::INFINITY, f64::NEG_INFINITY, f64::NAN] {
+        let point = Point::new(x, 2.0, 0.0);
+        assert_eq!(point.to_wkt(), Err(GeometryError::NotFinite));
+        assert_eq!(point.to_geojson(), Err(GeometryError::NotFinite));
+    }
+
+    for wkt in ["POINT (1e400 2)", "POINT (inf 2)", "POINT (NaN 2)", "POINT (-infinity 2)"] {
+        let error = Geometry::from_wkt(wkt).unwrap_err();
+        assert_eq!((error.kind(), error.offset()), (WktErrorKind::InvalidNumber, 7), "{}", wkt);
+    }
+
+    let json = r#"{"type":"Point","coordinates":[1e400,2]}"#;
+    let error = Geometry::from_geojson(json).unwrap_err();
+    assert_eq!((error.kind(), error.offset()), (GeoJsonErrorKind::InvalidNumber, json.find("1e400").unwrap()));
+}
+
+#[test]
+fn geojson_rejects_deep_nesting_without_overflowing() {
+    let error = Geometry::from_geojson(&"[".repeat(200_000)).unwrap_err();
+    assert_eq!(error.kind(), GeoJsonErrorKind::TooDeep);
+    assert_eq!(error.offset(), MAX_DEPTH);
+
+    // nesting within the limit is fine, and only fails for not being a geometry
+    let nested = format!("{}{}", "[".repeat(MAX_DEPTH), "]".repeat(MAX_DEPTH));
+    assert_eq!(Geometry::from_geojson(&nested).unwrap_err().kind(), GeoJsonErrorKind::Expected("an object"));
+}
+
+#[test]
+fn short_or_missing_rings_are_errors_not_panics() {
+    let (p, q) = (Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0));
+    assert_eq!(Polygon::try_from(Geometry::Polygon(vec![vec![p, q, p]])), Err(GeometryError::TooFewPoints{min: 4, found: 3}));
+    assert_eq!(Polygon::try_from(Geometry::Polygon(vec![])), Err(GeometryError::NoRings));
+    assert_eq!(Polygon::try_from(Geometry::Polygon(vec![vec![p, q, p]; 2])), Err(GeometryError::Holes));
+
+    // a ring left open keeps all its corners
+    let r = Point::new(0.0, 1.0, 0.0);
+    let open = Polygon::try_from(Geometry::Polygon(vec![vec![p, q, Point::new(1.0, 1.0, 0.0), r]])).unwrap();
+    assert_eq!(open.vertices.len(), 4);
+}
+
+fn assert_points_close(actual: (Point, Point), expected: (Point, Point)) {
+    assert!(actual.0.distance(expected.0) < 1e-12 && actual.1.distance(expected.1) < 1e-12,
+            "expected {:?}, got {:?}", expected, actual);
+}
+
+fn segment(a: (f64, f64, f64), b: (f64, f64, f64)) -> Line {
+    Line::new(Point::new(a.0, a.1, a.2), Point::new(b.0, b.1, b.2))
+}
+
+#[test]
+fn closest_points_of_crossing_and_skew_segments() {
+    let (x, y) = (segment((-1.0, 0.0, 0.0), (1.0, 0.0, 0.0)), segment((0.0, -1.0, 1.0), (0.0, 1.0, 1.0)));
+    assert_points_close(x.closest_points(&y), (Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 1.0)));
+    assert_eq!(x.distance(&y), 1.0);
+    let flat = segment((0.0, -1.0, 0.0), (0.0, 1.0, 0.0));
+    assert_eq!(x.distance(&flat), 0.0);
+    // nearest at an end of each
+    let (x, y) = (segment((0.0, 0.0, 0.0), (1.0, 0.0, 0.0)), segment((2.0, 1.0, 0.0), (2.0, 3.0, 0.0)));
+    assert_points_close(x.closest_points(&y), (Point::new(1.0, 0.0, 0.0), Point::new(2.0, 1.0, 0.0)));
+    assert!((x.distance(&y) - 2.0f64.sqrt()).abs() < 1e-12);
+    assert_eq!(y.distance(&x), x.distance(&y));
+}
+
+#[test]
+fn closest_points_of_parallel_segments() {
+    // side by side, overlapping: any pair straight across will do
+    let (x, y) = (segment((0.0, 0.0, 0.0), (2.0, 0.0, 0.0)), segment((1.0, 1.0, 0.0), (3.0, 1.0, 0.0)));
+    let (p, q) = x.closest_points(&y);
+    assert!((p.distance(q) - 1.0).abs() < 1e-12);
+    assert!(p.x >= 1.0 && p.x <= 2.0 && p.y == 0.0 && q.y == 1.0);
+    assert_eq!(x.distance(&y), 1.0);
+    // on the same line with a gap
+    let (x, y) = (segment((0.0, 0.0, 0.0), (1.0, 0.0, 0.0)), segment((3.0, 0.0, 0.0), (5.0, 0.0, 0.0)));
+    assert_points_close(x.closest_points(&y), (Point::new(1.0, 0.0, 0.0), Point::new(3.0, 0.0, 0.0)));
+    assert_points_close(y.closest_points(&x), (Point::new(3.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0)));
+    // pointing opposite ways, not overlapping
+    let (x, y) = (segment((0.0, 0.0, 0.0), (2.0, 0.0, 0.0)), segment((5.0, 1.0, 0.0), (3.0, 1.0, 0.0)));
+    assert_points_close(x.closest_points(&y), (Point::new(2.0, 0.0, 0.0), Point::new(3.0, 1.0, 0.0)));
+}
+
+#[test]
+fn closest_points_of_degenerate_segments() {
+    let dot = segment((1.0, 1.0, 0.0), (1.0, 1.0, 0.0));
+    let x = segment((0.0, 0.0, 0.0), (2.0, 0.0, 0.0));
+    assert_points_close(dot.closest_points(&x), (Point::new(1.0, 1.0, 0.0), Point::new(1.0, 0.0, 0.0)));
+    assert_points_close(x.closest_points(&dot), (Point::new(1.0, 0.0, 0.0), Point::new(1.0, 1.0, 0.0)));
+    assert_eq!(dot.distance(&x), 1.0);
+    // beyond an end, the end is nearest
+    let beyond = segment((4.0, 0.0, 3.0), (4.0, 0.0, 3.0));
+    assert_eq!(x.distance(&beyond), 2.0f64.hypot(3.0));
+    assert_eq!(dot.distance(&beyond), 3.0f64.hypot(1.0).hypot(3.0));
+    assert_eq!(dot.distance(&dot), 0.0);
+}
+
+#[test]
+fn closest_point_on_a_segment() {
+    let x = segment((0.0, 0.0, 0.0), (2.0, 0.0, 0.0));
+    assert_eq!(x.closest_point(Point::new(1.5, 3.0, -1.0)), Point::new(1.5, 0.0, 0.0));
+    assert_eq!(x.closest_point(Point::new(-5.0, 1.0, 0.0)), Point::new(0.0, 0.0, 0.0));
+    assert_eq!(x.closest_point(Point::new(9.0, 1.0, 0.0)), Point::new(2.0, 0.0, 0.0));
+    let dot = segment((1.0, 1.0, 1.0), (1.0, 1.0, 1.0));
+    assert_eq!(dot.closest_point(Point::new(0.0, 0.0, 0.0)), Point::new(1.0, 1.0, 1.0));
+}
@@ -0,0 +1,90 @@
+use rust_programming_language::complex::Complex;
+use rust_programming_language::num::{Float, Integer, Num, One, Real, Signed, Zero};
+use rust_programming_language::polynomial::Polynomial;
+use rust_programming_language::summable::Summable;
+use rust_programming_language::vector::Vec3;
+
+// written once against the traits, used below with integers, floats and complex numbers
+fn sum_of_squares<T: Num>(xs: &[T]) -> T {
+    xs.iter().fold(T::zero(), |sum, &x| sum + x * x)
+}
+
+fn power<T: Num>(x: T, n: u32) -> T {
+    (0..n).fold(T::one(), |product, _| product * x)
+}
+
+fn distance_from_zero<T: Signed>(xs: &[T]) -> Vec<T> {
+    xs.iter().map(|&x| x.abs()).collect()
+}
+
+fn hypotenuse<T: Float>(a: T, b: T) -> T {
+    (a * a + b * b).sqrt()
+}
+
+fn span<T: Integer>(xs: &[T]) -> Option<T> {
+    let (min, max) = xs.iter().fold((T::MAX, T::MIN), |(lo, hi), &x| (lo.min(x), hi.max(x)));
+    if xs.is_empty() { None } else { Some(max - min) }
+}
+
+#[test]
+fn num_covers_integers_floats_and_complex_numbers() {
+    assert_eq!(sum_of_squares(&[1, 2, 3]), 14);
+    assert_eq!(sum_of_squares(&[1u8, 2, 3]), 14u8);
+    assert_eq!(sum_of_squares(&[0.5, 1.5]), 2.5);
+    assert_eq!(sum_of_squares(&[Complex::new(1.0, 1.0), Complex::new(0.0, 2.0)]), Complex::new(-4.0, 2.0));
+    assert_eq!(sum_of_squares(&[Complex::new(1, 1), Complex::new(0, 2)]), Complex::new(-4, 2));
+
+    assert_eq!(power(3i64, 4), 81);
+    assert_eq!(power(0.5f32, 3), 0.125);
+    assert_eq!(power(Complex::<f64>::i(), 2), Complex::new(-1.0, 0.0));
+    assert_eq!(power(Complex::new(0, 1), 4), Complex::one());
+}
+
+#[test]
+fn identities() {
+    assert!(0u64.is_zero() && !1i8.is_zero());
+    assert!(Complex::new(0.0, 0.0).is_zero() && !Complex::new(0.0, -1.0).is_zero());
+    assert_eq!(<f32 as One>::one(), 1.0);
+    assert_eq!(<Complex<i32> as Zero>::zero(), Complex::new(0, 0));
+}
+
+#[test]
+fn signed_covers_signed_integers_and_floats() {
+    assert_eq!(distance_from_zero(&[-3i32, 0, 4]), vec![3, 0, 4]);
+    assert_eq!(distance_from_zero(&[-2.5f64, 0.0]), vec![2.5, 0.0]);
+    assert_eq!((-7i16).signum(), -1);
+    assert_eq!((-0.0f64).signum(), -1.0);
+    assert!(Signed::is_negative(&-1i64) && !Signed::is_negative(&0i64));
+    assert!(Signed::is_negative(&-0.0f32));
+}
+
+#[test]
+fn integer_and_float_specifics() {
+    assert_eq!(span(&[3u8, 250, 7]), Some(247));
+    assert_eq!(span(&[-5i128, 5]), Some(10));
+    assert_eq!(span::<i32>(&[]), None);
+    assert_eq!(Integer::checked_add(i8::MAX, 1), None);
+    assert_eq!(Integer::saturating_add(u16::MAX, 1), u16::MAX);
+    assert_eq!(Integer::wrapping_add(u8::MAX, 2), 1);
+
+    assert_eq!(hypotenuse(3.0f32, 4.0), 5.0);
+    assert_eq!(hypotenuse(5.0f64, 12.0), 13.0);
+    assert_eq!(f32::from_f64(0.1), 0.1f32);
+    assert_eq!(<f64 as Float>::PI.to_degrees(), 180.0);
+    assert_eq!(2u8.to_f64() + (-3i64).to_f64() + 0.5f32.to_f64(), -0.5);
+}
+
+#[test]
+fn generic_library_types_take_any_number() {
+    // the same polynomial x² - 2x + 1 over each kind of coefficient
+    assert_eq!(Polynomial::new(vec![1, -2, 1]).eval(3), 4);
+    assert_eq!(Polynomial::new(vec![1.0, -2.0, 1.0]).eval(0.5), 0.25);
+    let z = Polynomial::new(vec![Complex::new(1.0, 0.0), Complex::new(-2.0, 0.0), Complex::new(1.0, 0.0)])
+        .eval(Complex::new(1.0, 1.0));
+    assert_eq!(z, Complex::new(-1.0, 0.0));
+
+    assert_eq!(Vec3::new(1, 2, 3).cross(Vec3::new(4, 5, 6)), Vec3::new(-3, 6, -3));
+    assert_eq!(Vec3::new(1.0, 2.0, 2.0).norm(), 3.0);
+    assert_eq!([1u8, 2, 3].sum(), 6);
+    assert_eq!([Complex::new(1.0, 2.0), Complex::new(3.0, -2.0)].sum(), Complex::new(4.0, 0.0));
+}
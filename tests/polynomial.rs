@@ -0,0 +1,150 @@
+use rust_programming_language::complex::Complex;
+use rust_programming_language::polynomial::Polynomial;
+
+const TOLERANCE: f64 = 1e-9;
+
+#[test]
+fn integer_division_recombines_exactly() {
+    // (x² - 1)(2x + 3) + 5 divided by 2x + 3
+    let p = Polynomial::new(vec![2i64, -2, 3, 2]);
+    let d = Polynomial::new(vec![3i64, 2]);
+    let (q, r) = p.div_rem(&d).unwrap();
+    assert_eq!(q, Polynomial::new(vec![-1, 0, 1]));
+    assert_eq!(r, Polynomial::new(vec![5]));
+    assert_eq!(q * d + r, p);
+}
+
+#[test]
+fn integer_division_that_would_truncate_is_none() {
+    let p = Polynomial::new(vec![1i64, 0, 1]);
+    assert_eq!(p.div_rem(&Polynomial::new(vec![0, 2])), None);
+    // a lower degree than the divisor is all remainder, whatever the coefficients
+    let (q, r) = Polynomial::new(vec![1i64]).div_rem(&Polynomial::new(vec![0, 2])).unwrap();
+    assert!(q.is_zero());
+    assert_eq!(r, Polynomial::new(vec![1]));
+}
+
+#[test]
+fn float_division_recombines() {
+    let p = Polynomial::new(vec![1.0, 0.0, 1.0]);
+    let d = Polynomial::new(vec![0.0, 2.0]);
+    let (q, r) = p.div_rem(&d).unwrap();
+    assert_eq!(q, Polynomial::new(vec![0.0, 0.5]));
+    assert_eq!(r, Polynomial::new(vec![1.0]));
+
+    let p = Polynomial::new(vec![0.3f64, -1.7, 2.9, 0.1, 4.2]);
+    let d = Polynomial::new(vec![1.1, 0.0, 3.0]);
+    let (q, r) = p.div_rem(&d).unwrap();
+    assert!(r.degree().is_none_or(|degree| degree < 2));
+    let recombined = q * d + r;
+    for (a, b) in recombined.coefficients().iter().zip(p.coefficients()) {
+        assert!((a - b).abs() <= TOLERANCE, "expected {}, got {}", b, a);
+    }
+    assert_eq!(recombined.degree(), p.degree());
+}
+
+// a root of multiplicity m is only determined to about 1e-14^(1/m), e.g. (x - 1)² = 0 at
+// 1 ± 1e-8i, so repeated roots are compared more loosely
+const DOUBLE_ROOT_TOLERANCE: f64 = 1e-6;
+const TRIPLE_ROOT_TOLERANCE: f64 = 1e-4;
+
+fn c(re: f64, im: f64) -> Complex<f64> {
+    Complex::new(re, im)
+}
+
+// compares as multisets: both sides sorted by real part, then imaginary part, with real parts
+// that differ only by rounding counted as equal
+fn assert_roots(mut actual: Vec<Complex<f64>>, expected: &[Complex<f64>], tolerance: f64) {
+    let key = |z: &Complex<f64>| ((z.re / tolerance).round(), z.im);
+    actual.sort_by(|a, b| key(a).partial_cmp(&key(b)).unwrap());
+    let mut expected = expected.to_vec();
+    expected.sort_by(|a, b| key(a).partial_cmp(&key(b)).unwrap());
+    assert_eq!(actual.len(), expected.len(), "expected {:?}, got {:?}", expected, actual);
+    for (a, e) in actual.iter().zip(&expected) {
+        assert!((*a - *e).norm() <= tolerance, "expected {:?}, got {:?}", expected, actual);
+    }
+}
+
+#[test]
+fn distinct_real_roots() {
+    // (x - 1)(x - 2)(x - 3)
+    let p = Polynomial::new(vec![-6.0, 11.0, -6.0, 1.0]);
+    assert_roots(p.roots(), &[c(1.0, 0.0), c(2.0, 0.0), c(3.0, 0.0)], TOLERANCE);
+    // (2x + 1)(x - 4), with a leading coefficient other than one
+    assert_roots(Polynomial::new(vec![-4.0, -7.0, 2.0]).roots(), &[c(-0.5, 0.0), c(4.0, 0.0)], TOLERANCE);
+}
+
+#[test]
+fn conjugate_pairs() {
+    assert_roots(Polynomial::new(vec![1.0, 0.0, 1.0]).roots(), &[c(0.0, 1.0), c(0.0, -1.0)], TOLERANCE);
+    // the cube roots of unity
+    let half_root3 = 3f64.sqrt() / 2.0;
+    assert_roots(Polynomial::new(vec![-1.0, 0.0, 0.0, 1.0]).roots(),
+                 &[c(1.0, 0.0), c(-0.5, half_root3), c(-0.5, -half_root3)], TOLERANCE);
+}
+
+#[test]
+fn repeated_roots() {
+    assert_roots(Polynomial::new(vec![1.0, -2.0, 1.0]).roots(), &[c(1.0, 0.0); 2], DOUBLE_ROOT_TOLERANCE);
+    // (x + 1)² (x² + 4)
+    assert_roots(Polynomial::new(vec![4.0, 8.0, 5.0, 2.0, 1.0]).roots(),
+                 &[c(-1.0, 0.0), c(-1.0, 0.0), c(0.0, 2.0), c(0.0, -2.0)], DOUBLE_ROOT_TOLERANCE);
+    // (x - 2)³
+    assert_roots(Polynomial::new(vec![-8.0, 12.0, -6.0, 1.0]).roots(), &[c(2.0, 0.0); 3], TRIPLE_ROOT_TOLERANCE);
+}
+
+#[test]
+fn zero_roots_are_exact() {
+    // x²(x - 2)
+    let roots = Polynomial::new(vec![0.0, 0.0, -2.0, 1.0]).roots();
+    assert_eq!(roots.iter().filter(|&&z| z == c(0.0, 0.0)).count(), 2);
+    assert_roots(roots, &[c(0.0, 0.0), c(0.0, 0.0), c(2.0, 0.0)], TOLERANCE);
+    assert_eq!(Polynomial::new(vec![0.0, 0.0, 3.0]).roots(), vec![c(0.0, 0.0); 2]);
+}
+
+#[test]
+fn constants_have_no_roots_and_lines_have_one() {
+    assert!(Polynomial::new(vec![5.0]).roots().is_empty());
+    assert!(Polynomial::<f64>::zero().roots().is_empty());
+    assert_eq!(Polynomial::new(vec![-4.0, 2.0]).roots(), vec![c(2.0, 0.0)]);
+    assert_eq!(Polynomial::new(vec![c(0.0, 2.0), c(2.0, 0.0)]).roots(), vec![c(0.0, -1.0)]);
+}
+
+#[test]
+fn complex_coefficients() {
+    // (x - i)(x - 1 - i) = x² - (1 + 2i)x + (-1 + i)
+    let p = Polynomial::new(vec![c(-1.0, 1.0), c(-1.0, -2.0), c(1.0, 0.0)]);
+    assert_roots(p.roots(), &[c(0.0, 1.0), c(1.0, 1.0)], TOLERANCE);
+}
+
+#[test]
+fn derivatives() {
+    // 3 + 2x + 5x³
+    assert_eq!(Polynomial::new(vec![3, 2, 0, 5]).derivative(), Polynomial::new(vec![2, 0, 15]));
+    assert!(Polynomial::new(vec![7]).derivative().is_zero());
+    assert!(Polynomial::<i32>::zero().derivative().is_zero());
+    assert_eq!(Polynomial::new(vec![0.5, 0.25, 2.0]).derivative(), Polynomial::new(vec![0.25, 4.0]));
+}
+
+#[test]
+fn display_puts_the_highest_degree_first() {
+    assert_eq!(Polynomial::new(vec![1, -1, 3]).to_string(), "3x^2 - x + 1");
+    assert_eq!(Polynomial::new(vec![-1, 0, 0, -2]).to_string(), "-2x^3 - 1");
+    assert_eq!(Polynomial::new(vec![0.5, 1.0]).to_string(), "x + 0.5");
+    assert_eq!(Polynomial::<i32>::zero().to_string(), "0");
+    assert_eq!(Polynomial::new(vec![Complex::new(0, -1), Complex::new(1, 2)]).to_string(), "(1 + 2i)x + (0 - 1i)");
+}
+
+#[test]
+fn arithmetic() {
+    let (p, q) = (Polynomial::new(vec![1, 1]), Polynomial::new(vec![1, -1]));
+    assert_eq!(p.clone() + q.clone(), Polynomial::new(vec![2]));
+    assert_eq!(p.clone() - q.clone(), Polynomial::new(vec![0, 2]));
+    assert_eq!(p.clone() * q.clone(), Polynomial::new(vec![1, 0, -1]));
+    assert_eq!(-p.clone(), Polynomial::new(vec![-1, -1]));
+    // cancelling leading terms lowers the degree
+    assert_eq!((p.clone() - p.clone()).degree(), None);
+    assert_eq!((Polynomial::new(vec![1, 0, 2]) + Polynomial::new(vec![0, 3, -2])).degree(), Some(1));
+    assert!((p * Polynomial::zero()).is_zero());
+    assert_eq!(Polynomial::new(vec![1.5, 0.0]) * Polynomial::new(vec![2.0, 4.0]), Polynomial::new(vec![3.0, 6.0]));
+}
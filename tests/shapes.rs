@@ -0,0 +1,467 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI, SQRT_2};
+
+use rust_programming_language::shapes::*;
+
+const TOLERANCE: f64 = 1e-9;
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() <= TOLERANCE * expected.abs().max(1.0),
+            "expected {}, got {}", expected, actual);
+}
+
+fn assert_point_close(actual: (f64, f64), expected: (f64, f64)) {
+    assert_close(actual.0, expected.0);
+    assert_close(actual.1, expected.1);
+}
+
+fn assert_box_close(actual: BoundingBox, expected: BoundingBox) {
+    assert_point_close(actual.min, expected.min);
+    assert_point_close(actual.max, expected.max);
+}
+
+#[test]
+fn circle_measurements() {
+    let circle = Circle::new((1.0, -2.0), 3.0);
+    assert_close(circle.area(), 9.0 * PI);
+    assert_close(circle.perimeter(), 6.0 * PI);
+    assert_eq!(circle.number_of_sides(), None);
+    assert_box_close(circle.bounding_box(), BoundingBox::new((-2.0, -5.0), (4.0, 1.0)));
+    assert!(circle.contains((1.0, 1.0)));
+    assert!(!circle.contains((3.5, 0.0)));
+}
+
+#[test]
+fn circle_transforms() {
+    let mut circle = Circle::new((0.0, 0.0), 1.0);
+    circle.translate(2.0, 3.0);
+    circle.scale(-2.0);
+    circle.rotate(1.0);
+    assert_eq!(circle, Circle::new((2.0, 3.0), 2.0));
+}
+
+#[test]
+fn rectangle_measurements() {
+    let rect = Rectangle::new((1.0, 1.0), (4.0, 3.0));
+    assert_close(rect.area(), 6.0);
+    assert_close(rect.perimeter(), 10.0);
+    assert_point_close(rect.center(), (2.5, 2.0));
+    assert_eq!(rect.bounding_box(), BoundingBox::new((1.0, 1.0), (4.0, 3.0)));
+    assert!(rect.contains((4.0, 2.0)));
+    assert!(!rect.contains((4.1, 2.0)));
+}
+
+#[test]
+fn rotated_rectangle() {
+    let mut rect = Rectangle::new((-2.0, -1.0), (2.0, 1.0));
+    rect.rotate(FRAC_PI_2);
+    assert_close(rect.area(), 8.0);
+    assert_close(rect.perimeter(), 12.0);
+    assert_box_close(rect.bounding_box(), BoundingBox::new((-1.0, -2.0), (1.0, 2.0)));
+    assert!(rect.contains((0.0, 1.9)));
+    assert!(!rect.contains((1.9, 0.0)));
+}
+
+#[test]
+fn rectangle_scales_about_its_center() {
+    let mut rect = Rectangle::new((0.0, 0.0), (2.0, 4.0));
+    rect.scale(0.5);
+    assert_point_close(rect.center(), (1.0, 2.0));
+    assert_close(rect.area(), 2.0);
+}
+
+#[test]
+fn square_measurements() {
+    let square = Square::new((1.0, 2.0), 3.0);
+    assert_close(square.area(), 9.0);
+    assert_close(square.perimeter(), 12.0);
+    assert_point_close(square.center(), (2.5, 3.5));
+    assert_eq!(square.number_of_sides(), Some(4));
+}
+
+#[test]
+fn rotated_square() {
+    let mut square = Square::new((-1.0, -1.0), 2.0);
+    square.rotate(FRAC_PI_4);
+    assert_close(square.area(), 4.0);
+    assert_box_close(square.bounding_box(), BoundingBox::new((-SQRT_2, -SQRT_2), (SQRT_2, SQRT_2)));
+    assert!(square.contains((0.0, 1.4)));
+    assert!(!square.contains((0.9, 0.9)));
+    square.translate(1.0, 1.0);
+    square.scale(3.0);
+    assert_point_close(square.center(), (1.0, 1.0));
+    assert_close(square.perimeter(), 24.0);
+}
+
+#[test]
+fn triangle_measurements() {
+    let triangle = Triangle::new((0.0, 0.0), (3.0, 0.0), (0.0, 4.0));
+    assert_close(triangle.area(), 6.0);
+    assert_close(triangle.perimeter(), 12.0);
+    assert_point_close(triangle.center(), (1.0, 4.0 / 3.0));
+    assert_eq!(triangle.bounding_box(), BoundingBox::new((0.0, 0.0), (3.0, 4.0)));
+    assert!(triangle.contains((1.0, 1.0)));
+    assert!(triangle.contains((1.5, 2.0)));
+    assert!(!triangle.contains((2.0, 2.0)));
+}
+
+#[test]
+fn clockwise_triangle_contains_the_same_points() {
+    let triangle = Triangle::new((0.0, 0.0), (0.0, 4.0), (3.0, 0.0));
+    assert_close(triangle.area(), 6.0);
+    assert!(triangle.contains((1.0, 1.0)));
+    assert!(!triangle.contains((-0.1, 1.0)));
+}
+
+#[test]
+fn triangle_transforms() {
+    let mut triangle = Triangle::new((0.0, 0.0), (3.0, 0.0), (0.0, 4.0));
+    let center = triangle.center();
+    triangle.rotate(PI);
+    triangle.scale(2.0);
+    assert_point_close(triangle.center(), center);
+    assert_close(triangle.area(), 24.0);
+    assert_close(triangle.perimeter(), 24.0);
+    triangle.translate(-1.0, 1.0);
+    assert_point_close(triangle.center(), (center.0 - 1.0, center.1 + 1.0));
+}
+
+#[test]
+fn ellipse_measurements() {
+    let ellipse = Ellipse::new((0.0, 0.0), (2.0, 1.0));
+    assert_close(ellipse.area(), 2.0 * PI);
+    // 8·E(√3/2), the complete elliptic integral of the second kind
+    assert_close(ellipse.perimeter(), 9.688448220547675);
+    assert_eq!(ellipse.bounding_box(), BoundingBox::new((-2.0, -1.0), (2.0, 1.0)));
+    assert!(ellipse.contains((1.9, 0.0)));
+    assert!(!ellipse.contains((0.0, 1.1)));
+}
+
+#[test]
+fn ellipse_perimeter_matches_limiting_cases() {
+    assert_close(Ellipse::new((0.0, 0.0), (3.0, 3.0)).perimeter(), 6.0 * PI);
+    assert_close(Ellipse::new((0.0, 0.0), (0.0, 1.0)).perimeter(), 4.0);
+    assert_close(Ellipse::new((0.0, 0.0), (0.0, 0.0)).perimeter(), 0.0);
+    // 40·E(e) for eccentricity e = √0.99
+    assert_close(Ellipse::new((0.0, 0.0), (10.0, 1.0)).perimeter(), 40.63974180100896);
+}
+
+#[test]
+fn rotated_ellipse() {
+    let mut ellipse = Ellipse::new((1.0, 1.0), (2.0, 1.0));
+    ellipse.rotate(FRAC_PI_2);
+    assert_box_close(ellipse.bounding_box(), BoundingBox::new((0.0, -1.0), (2.0, 3.0)));
+    assert!(ellipse.contains((1.0, 2.9)));
+    assert!(!ellipse.contains((2.9, 1.0)));
+    ellipse.scale(2.0);
+    assert_close(ellipse.area(), 8.0 * PI);
+}
+
+#[test]
+fn regular_polygon_measurements() {
+    let hexagon = RegularPolygon::new((0.0, 0.0), 6, 1.0);
+    assert_close(hexagon.area(), 3.0 * 3f64.sqrt() / 2.0);
+    assert_close(hexagon.perimeter(), 6.0);
+    assert_eq!(hexagon.number_of_sides(), Some(6));
+    assert_box_close(hexagon.bounding_box(), BoundingBox::new((-1.0, -(3f64.sqrt()) / 2.0), (1.0, 3f64.sqrt() / 2.0)));
+    assert!(hexagon.contains((0.99, 0.0)));
+    assert!(!hexagon.contains((0.0, 0.9)));
+
+    let square = RegularPolygon::new((0.0, 0.0), 4, SQRT_2);
+    assert_close(square.area(), 4.0);
+    assert_close(square.perimeter(), 8.0);
+}
+
+#[test]
+fn regular_polygon_approaches_its_circle() {
+    let polygon = RegularPolygon::new((0.0, 0.0), 10_000, 1.0);
+    assert!((polygon.area() - PI).abs() < 1e-6);
+    assert!((polygon.perimeter() - 2.0 * PI).abs() < 1e-6);
+}
+
+#[test]
+fn regular_polygon_transforms() {
+    let mut triangle = RegularPolygon::new((0.0, 0.0), 3, 1.0);
+    triangle.scale(-1.0);
+    // reflected through the center, the first corner points left
+    assert_point_close(triangle.vertices()[0], (-1.0, 0.0));
+    triangle.rotate(PI);
+    triangle.translate(5.0, 0.0);
+    assert_point_close(triangle.vertices()[0], (6.0, 0.0));
+}
+
+#[test]
+fn polygon_measurements() {
+    // an L of three unit squares
+    let l_shape = Polygon::new(vec![(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (1.0, 1.0), (1.0, 2.0), (0.0, 2.0)]);
+    assert_close(l_shape.area(), 3.0);
+    assert_close(l_shape.perimeter(), 8.0);
+    assert_point_close(l_shape.center(), (5.0 / 6.0, 5.0 / 6.0));
+    assert_eq!(l_shape.number_of_sides(), Some(6));
+    assert_eq!(l_shape.bounding_box(), BoundingBox::new((0.0, 0.0), (2.0, 2.0)));
+    assert!(l_shape.contains((0.5, 1.5)));
+    assert!(l_shape.contains((1.0, 1.5)));
+    assert!(!l_shape.contains((1.5, 1.5)));
+}
+
+#[test]
+fn clockwise_polygon_has_the_same_area_and_center() {
+    let clockwise = Polygon::new(vec![(0.0, 0.0), (0.0, 2.0), (1.0, 2.0), (1.0, 1.0), (2.0, 1.0), (2.0, 0.0)]);
+    assert_close(clockwise.area(), 3.0);
+    assert_point_close(clockwise.center(), (5.0 / 6.0, 5.0 / 6.0));
+}
+
+#[test]
+fn polygon_transforms() {
+    let mut diamond = Polygon::new(vec![(1.0, 0.0), (0.0, 1.0), (-1.0, 0.0), (0.0, -1.0)]);
+    diamond.rotate(FRAC_PI_4);
+    assert_box_close(diamond.bounding_box(), BoundingBox::new((-SQRT_2 / 2.0, -SQRT_2 / 2.0), (SQRT_2 / 2.0, SQRT_2 / 2.0)));
+    diamond.scale(2.0);
+    diamond.translate(1.0, 1.0);
+    assert_close(diamond.area(), 8.0);
+    assert_close(diamond.perimeter(), 8.0 * SQRT_2);
+    assert_point_close(diamond.center(), (1.0, 1.0));
+}
+
+#[test]
+fn bounding_box_operations() {
+    let a = BoundingBox::new((0.0, 0.0), (2.0, 1.0));
+    let b = BoundingBox::new((2.0, 1.0), (3.0, 3.0));
+    let c = BoundingBox::new((2.5, -1.0), (3.0, 0.5));
+    assert!(a.intersects(&b));
+    assert!(!a.intersects(&c));
+    assert_eq!(a.union(&c), BoundingBox::new((0.0, -1.0), (3.0, 1.0)));
+    assert_close(a.width(), 2.0);
+    assert_close(a.height(), 1.0);
+    assert_eq!(BoundingBox::of_points(vec![]), None);
+}
+
+#[test]
+fn shapes_work_as_trait_objects() {
+    let shapes: Vec<Box<dyn Shape<f64>>> = vec![
+        Box::new(Circle::new((0.0, 0.0), 1.0)),
+        Box::new(Square::new((0.0, 0.0), 1.0)),
+        Box::new(Triangle::new((0.0, 0.0), (1.0, 0.0), (0.0, 1.0))),
+        Box::new(Polygon::new(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)]))
+    ];
+    let total: f64 = shapes.iter().map(|s| s.area()).sum();
+    assert_close(total, PI + 2.0);
+}
+
+#[test]
+fn overlapping_circles_collide() {
+    let a = Circle::new((0.0, 0.0), 1.0);
+    let b = Circle::new((1.5, 0.0), 1.0);
+    let hit = collide(&a, &b).unwrap();
+    assert_close(hit.depth, 0.5);
+    assert_point_close(hit.normal, (1.0, 0.0));
+    assert_eq!(hit.points.len(), 2);
+    assert_point_close(hit.points[0], (0.75, (1.0f64 - 0.5625).sqrt()));
+    assert!(collide(&a, &Circle::new((2.5, 0.0), 1.0)).is_none());
+}
+
+#[test]
+fn circle_and_rectangle_collide_either_way_round() {
+    let circle = Circle::new((0.0, 0.0), 1.0);
+    let rect = Rectangle::new((0.5, -0.5), (3.0, 0.5));
+    let hit = collide(&circle, &rect).unwrap();
+    assert_close(hit.depth, 0.5);
+    assert_point_close(hit.normal, (1.0, 0.0));
+    assert_eq!(hit.points.len(), 2);
+    let reversed = collide(&rect, &circle).unwrap();
+    assert_close(reversed.depth, 0.5);
+    assert_point_close(reversed.normal, (-1.0, 0.0));
+}
+
+#[test]
+fn shape_inside_another_overlaps_without_crossings() {
+    let big = Square::new((-5.0, -5.0), 10.0);
+    let small = Circle::new((0.0, 0.0), 1.0);
+    let hit = collide(&big, &small).unwrap();
+    assert!(hit.points.is_empty());
+    assert_close(hit.depth, 6.0);
+}
+
+#[test]
+fn touching_shapes_collide_with_zero_depth() {
+    let triangle = Triangle::new((0.0, 0.0), (2.0, 0.0), (0.0, 2.0));
+    let square = Square::new((1.0, 1.0), 2.0);
+    let hit = collide(&triangle, &square).unwrap();
+    assert_close(hit.depth, 0.0);
+    assert_eq!(hit.points, vec![(1.0, 1.0)]);
+}
+
+#[test]
+fn circle_in_the_notch_of_a_concave_polygon_does_not_overlap() {
+    let l_shape = Polygon::new(vec![(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (1.0, 1.0), (1.0, 2.0), (0.0, 2.0)]);
+    assert!(!overlaps(&l_shape, &Circle::new((1.7, 1.7), 0.3)));
+    assert!(overlaps(&l_shape, &Circle::new((1.5, 1.5), 0.6)));
+    assert_eq!(intersection_points(&l_shape, &Circle::new((1.7, 1.7), 0.3)), vec![]);
+}
+
+#[test]
+fn convex_hull_drops_interior_collinear_and_repeated_points() {
+    let points = [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0)];
+    assert_eq!(convex_hull(&points), vec![(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]);
+    assert_eq!(convex_hull(&[(0.0, 0.0), (2.0, 2.0), (1.0, 1.0), (3.0, 3.0)]), vec![(0.0, 0.0), (3.0, 3.0)]);
+    assert_eq!(convex_hull(&[(1.0, 1.0), (1.0, 1.0)]), vec![(1.0, 1.0)]);
+    assert_eq!(convex_hull(&[]), vec![]);
+}
+
+#[test]
+fn shoelace_area_matches_shape_area() {
+    let shapes: Vec<(Vec<(f64, f64)>, f64)> = vec![
+        (Triangle::new((0.0, 0.0), (4.0, 0.0), (0.0, 3.0)).vertices.to_vec(), 6.0),
+        (Rectangle::new((0.0, 0.0), (3.0, 2.0)).vertices().to_vec(), 6.0),
+        (RegularPolygon::new((1.0, 1.0), 7, 2.0).vertices(), RegularPolygon::new((1.0, 1.0), 7, 2.0).area()),
+        (vec![(0.0, 0.0), (0.0, 2.0), (1.0, 2.0), (1.0, 1.0), (2.0, 1.0), (2.0, 0.0)], 3.0)
+    ];
+    for (vertices, area) in shapes {
+        assert_close(shoelace_area(&vertices), area);
+        assert_close(shoelace_area(&vertices), Polygon::new(vertices.clone()).area());
+    }
+    assert_close(signed_area(&[(0.0, 0.0), (0.0, 1.0), (1.0, 0.0)]), -0.5);
+    assert_close(shoelace_area(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)]), 0.0);
+}
+
+#[test]
+fn winding_number_counts_turns() {
+    let square = [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)];
+    let clockwise: Vec<_> = square.iter().rev().copied().collect();
+    assert_eq!(winding_number(&square, (1.0, 1.0)), 1);
+    assert_eq!(winding_number(&clockwise, (1.0, 1.0)), -1);
+    assert_eq!(winding_number(&square, (3.0, 1.0)), 0);
+    assert!(point_in_polygon(&square, (2.0, 1.0)));
+    assert!(point_in_polygon(&square, (0.0, 0.0)));
+    assert!(!point_in_polygon(&square, (2.5, 1.0)));
+
+    // the middle of a pentagram is wound around twice: inside by winding, outside by even-odd
+    let pentagram: Vec<_> = (0..5).map(|i| ((4 * i) as f64 * PI / 5.0).cos()).zip((0..5).map(|i| ((4 * i) as f64 * PI / 5.0).sin())).collect();
+    assert_eq!(winding_number(&pentagram, (0.0, 0.0)), 2);
+    assert!(point_in_polygon(&pentagram, (0.0, 0.0)));
+    assert!(!Polygon::new(pentagram).contains((0.0, 0.0)));
+}
+
+#[test]
+fn triangulation_covers_the_polygon() {
+    let spiral = [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (2.0, 10.0), (2.0, 4.0), (6.0, 4.0), (6.0, 6.0),
+                  (4.0, 6.0), (4.0, 8.0), (8.0, 8.0), (8.0, 2.0), (0.0, 2.0)];
+    let triangles = triangulate(&spiral);
+    assert_eq!(triangles.len(), spiral.len() - 2);
+    for t in &triangles {
+        assert!(signed_area(&[spiral[t[0]], spiral[t[1]], spiral[t[2]]]) > 0.0);
+    }
+    let total: f64 = triangles.iter().map(|t| shoelace_area(&[spiral[t[0]], spiral[t[1]], spiral[t[2]]])).sum();
+    assert_close(total, shoelace_area(&spiral));
+
+    let clockwise: Vec<_> = spiral.iter().rev().copied().collect();
+    assert_eq!(triangulate(&clockwise).len(), spiral.len() - 2);
+}
+
+#[test]
+fn triangulation_skips_degenerate_vertices() {
+    // a 2×2 square with a repeated corner, a closing repeat and points along three sides
+    let square = [(0.0, 0.0), (0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (2.0, 2.0), (2.0, 2.0), (1.0, 2.0),
+                  (0.0, 2.0), (0.0, 1.0), (0.0, 0.0)];
+    let triangles = triangulate(&square);
+    assert_eq!(triangles.len(), 2);
+    let total: f64 = triangles.iter().map(|t| shoelace_area(&[square[t[0]], square[t[1]], square[t[2]]])).sum();
+    assert_close(total, 4.0);
+    assert!(triangulate(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)]).is_empty());
+    assert!(triangulate(&[(0.0, 0.0), (1.0, 1.0)]).is_empty());
+}
+
+#[test]
+fn quadtree_queries_match_a_linear_scan() {
+    // a grid of circles and rectangles, plus one covering everything and one far outside
+    let mut shapes: Vec<Box<dyn Shape<f64>>> = Vec::new();
+    for i in 0..40 {
+        for j in 0..40 {
+            let (x, y) = (i as f64 * 10.0, j as f64 * 10.0);
+            if (i + j) % 2 == 0 {
+                shapes.push(Box::new(Circle::new((x, y), 4.0)))
+            } else {
+                shapes.push(Box::new(Rectangle::new((x, y), (x + 12.0, y + 3.0))))
+            }
+        }
+    }
+    shapes.push(Box::new(Circle::new((200.0, 200.0), 300.0)));
+    shapes.push(Box::new(Square::new((5000.0, 5000.0), 1.0)));
+    let tree: QuadTree<&dyn Shape<f64>> = shapes.iter().map(|shape| &**shape).collect();
+    assert_eq!(tree.len(), shapes.len());
+
+    for &point in &[(0.0, 0.0), (13.0, 21.5), (201.0, 199.0), (390.5, 5.0), (450.0, 450.0), (5000.5, 5000.5)] {
+        let expected = shapes.iter().filter(|shape| shape.contains(point)).count();
+        assert_eq!(tree.query_point(point).len(), expected);
+
+        let area = BoundingBox::new(point, (point.0 + 25.0, point.1 + 15.0));
+        let expected = shapes.iter().filter(|shape| shape.bounding_box().intersects(&area)).count();
+        assert_eq!(tree.query_box(&area).len(), expected);
+
+        let mut distances: Vec<f64> = shapes.iter().map(|shape| shape.distance_to(point)).collect();
+        distances.sort_by(|a, b| a.total_cmp(b));
+        let nearest: Vec<f64> = tree.nearest(point, 7).iter().map(|(_, shape)| shape.distance_to(point)).collect();
+        assert_eq!(nearest, distances[..7]);
+    }
+}
+
+#[test]
+fn quadtree_removal() {
+    let mut tree = QuadTree::new(BoundingBox::new((0.0, 0.0), (100.0, 100.0)));
+    let ids: Vec<ItemId> = (0..50).map(|i| tree.insert(Circle::new((i as f64 * 2.0, 50.0), 0.5))).collect();
+    for &id in ids.iter().step_by(2) {
+        assert!(tree.remove(id).is_some());
+    }
+    assert_eq!(tree.remove(ids[0]), None);
+    assert_eq!(tree.len(), 25);
+    assert!(tree.query_point((0.0, 50.0)).is_empty());
+    assert_eq!(tree.query_point((2.0, 50.0)).len(), 1);
+    assert_eq!(tree.get(ids[1]).map(|circle| circle.center), Some((2.0, 50.0)));
+    assert_eq!(tree.nearest((41.0, 50.0), 1)[0].1.center, (42.0, 50.0));
+    assert_eq!(tree.query_box(&BoundingBox::new((0.0, 0.0), (100.0, 100.0))).len(), 25);
+}
+
+#[test]
+fn quadtree_nearest_matches_a_sorted_linear_scan() {
+    // boxes of many sizes, some sharing a position, scattered by a fixed linear congruential
+    // sequence so the test is the same every run
+    let mut seed = 12345u64;
+    let mut next = move || {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (seed >> 33) as f64 / (1u64 << 31) as f64
+    };
+    let mut boxes = Vec::new();
+    for _ in 0..300 {
+        let (x, y) = (next() * 1000.0, next() * 1000.0);
+        let (w, h) = (next() * next() * 200.0, next() * 20.0);
+        boxes.push(BoundingBox::new((x, y), (x + w, y + h)));
+    }
+    boxes.extend_from_within(..20);
+    let mut tree: QuadTree<BoundingBox> = boxes.iter().copied().collect();
+
+    let sorted_distances = |boxes: &[BoundingBox], point: (f64, f64)| {
+        let mut distances: Vec<f64> = boxes.iter().map(|b| b.distance_to(point)).collect();
+        distances.sort_by(|a, b| a.total_cmp(b));
+        distances
+    };
+    for _ in 0..50 {
+        let point = (next() * 1400.0 - 200.0, next() * 1400.0 - 200.0);
+        let expected = sorted_distances(&boxes, point);
+        for &k in &[0, 1, 2, 10, 100, boxes.len(), boxes.len() + 5] {
+            let nearest: Vec<f64> = tree.nearest(point, k).iter().map(|(_, b)| b.distance_to(point)).collect();
+            assert_eq!(nearest, expected[..k.min(boxes.len())]);
+        }
+    }
+
+    // and still after removing every third item
+    let ids: Vec<ItemId> = tree.iter().map(|(id, _)| id).collect();
+    for &id in ids.iter().step_by(3) {
+        tree.remove(id);
+    }
+    let remaining: Vec<BoundingBox> = tree.iter().map(|(_, &b)| b).collect();
+    for _ in 0..20 {
+        let point = (next() * 1000.0, next() * 1000.0);
+        let expected = sorted_distances(&remaining, point);
+        let nearest: Vec<f64> = tree.nearest(point, 25).iter().map(|(_, b)| b.distance_to(point)).collect();
+        assert_eq!(nearest, expected[..25]);
+    }
+}
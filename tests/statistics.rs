@@ -0,0 +1,128 @@
+use rust_programming_language::statistics::{Histogram, RunningStats, Statistics};
+
+const TOLERANCE: f64 = 1e-9;
+
+fn assert_close(actual: Option<f64>, expected: f64) {
+    let actual = actual.expect("a value");
+    assert!((actual - expected).abs() <= TOLERANCE * expected.abs().max(1.0),
+            "expected {}, got {}", expected, actual);
+}
+
+#[test]
+fn extremes_are_not_ord_min_and_max() {
+    let v = [3i32, 1, 2].to_vec();
+    assert_eq!(v.minimum(), Some(1));
+    assert_eq!(v.maximum(), Some(3));
+    assert_eq!([2.5, -1.0, 7.0].minimum(), Some(-1.0));
+    assert_eq!(Vec::<u8>::new().maximum(), None);
+}
+
+#[test]
+fn integers_have_fractional_means_and_variances() {
+    let v = [1i32, 2];
+    assert_close(v.mean(), 1.5);
+    assert_close(v.median(), 1.5);
+    assert_close(v.population_variance(), 0.25);
+    assert_close(v.sample_variance(), 0.5);
+    assert_close([1u8, 2, 3, 4].percentile(25.0), 1.75);
+    // no overflow, because the sum is taken in f64
+    assert_close([u8::MAX, u8::MAX].mean(), 255.0);
+}
+
+#[test]
+fn statistics_of_a_textbook_sample() {
+    let scores = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    assert_close(scores.mean(), 5.0);
+    assert_close(scores.median(), 4.5);
+    assert_eq!(scores.mode(), vec![4.0]);
+    assert_close(scores.population_variance(), 4.0);
+    assert_close(scores.population_std_dev(), 2.0);
+    assert_close(scores.sample_variance(), 32.0 / 7.0);
+    assert_close(scores.percentile(0.0), 2.0);
+    assert_close(scores.percentile(100.0), 9.0);
+    assert_close(scores.percentile(90.0), 7.6);
+}
+
+#[test]
+fn undefined_statistics_are_none() {
+    let empty: [f64; 0] = [];
+    assert_eq!(empty.mean(), None);
+    assert_eq!(empty.median(), None);
+    assert_eq!(empty.population_variance(), None);
+    assert_eq!([1].sample_variance(), None);
+    assert!(empty.mode().is_empty());
+    assert_eq!(empty.histogram(3), None);
+}
+
+#[test]
+fn ties_give_several_modes() {
+    assert_eq!([3, 1, 3, 1, 2].mode(), vec![1, 3]);
+}
+
+#[test]
+#[should_panic(expected = "percentile must be within 0..=100")]
+fn percentile_out_of_range_panics() {
+    [1, 2, 3].percentile(101.0);
+}
+
+#[test]
+fn histogram_counts_every_value_once() {
+    let histogram = [0.0, 1.0, 2.5, 5.0, 9.9, 10.0].histogram(2).unwrap();
+    assert_eq!(histogram, Histogram{min: 0.0, max: 10.0, counts: vec![3, 3]});
+    assert_eq!(histogram.bin_range(1), 5.0..10.0);
+    assert_eq!(histogram.bin(10.0), Some(1));
+    assert_eq!(histogram.bin(10.5), None);
+}
+
+#[test]
+fn integers_are_binned_in_f64() {
+    let histogram = [1, 2, 3, 4].histogram(2).unwrap();
+    assert_eq!(histogram, Histogram{min: 1, max: 4, counts: vec![2, 2]});
+    assert_eq!(histogram.bin_width(), 1.5);
+    assert_eq!(histogram.bin_range(1), 2.5..4.0);
+    assert_eq!([7u8, 7, 7].histogram(4).unwrap().counts, vec![3, 0, 0, 0]);
+    assert_eq!([-5i64, 5, 0, 4, -1].histogram(2).unwrap().counts, vec![2, 3]);
+}
+
+#[test]
+fn nan_sorts_last_without_panicking() {
+    // enough elements, with NaN spread throughout, to reach the sort's more thorough paths
+    let values: Vec<f64> = (0..100).map(|i| if i % 3 == 0 { f64::NAN } else { (i % 10) as f64 }).collect();
+    let numbers = values.iter().filter(|x| !x.is_nan()).count();
+    assert_eq!(values.percentile(0.0), Some(0.0));
+    let last_number = (numbers - 1) as f64 / (values.len() - 1) as f64 * 100.0;
+    assert_eq!(values.percentile(last_number), Some(9.0));
+    assert!(values.percentile(100.0).unwrap().is_nan());
+    assert!(values.median().is_some());
+    // NaN never equals itself, so it is never the most frequent value
+    assert_eq!(values.mode(), vec![1.0, 2.0, 4.0, 5.0, 7.0, 8.0]);
+
+    assert_eq!([f64::NAN, 2.0, 1.0].median(), Some(2.0));
+    assert_eq!([-f64::NAN, 2.0, 1.0].median(), Some(1.0));
+    assert!([f32::NAN; 5].median().unwrap().is_nan());
+}
+
+#[test]
+fn running_stats_agree_with_batch_statistics() {
+    let values: Vec<f64> = (0..1000).map(|i| 1e6 + ((i * 7919) % 1000) as f64 / 10.0).collect();
+    let running: RunningStats<f64> = values.iter().collect();
+    assert_eq!(running.count(), values.len());
+    assert_close(running.mean(), values.mean().unwrap());
+    assert_close(running.population_variance(), values.population_variance().unwrap());
+    assert_close(running.sample_variance(), values.sample_variance().unwrap());
+    assert_eq!(running.min(), values.minimum());
+    assert_eq!(running.max(), values.maximum());
+}
+
+#[test]
+fn merged_running_stats_agree_with_one_pass() {
+    let values: Vec<f64> = (1..=100).map(|i| (i as f64).sqrt()).collect();
+    let whole: RunningStats<f64> = values.iter().collect();
+    let mut merged: RunningStats<f64> = values[..30].iter().collect();
+    merged.merge(&values[30..].iter().collect());
+    merged.merge(&RunningStats::new());
+    assert_eq!(merged.count(), whole.count());
+    assert_close(merged.mean(), whole.mean().unwrap());
+    assert_close(merged.sample_variance(), whole.sample_variance().unwrap());
+    assert_eq!(RunningStats::<f64>::new().mean(), None);
+}
@@ -0,0 +1,57 @@
+use rust_programming_language::summable::{self, Summable};
+
+#[test]
+fn sums_of_integers() {
+    assert_eq!([1, 2, 3].sum(), 6);
+    assert_eq!(Vec::<u8>::new().sum(), 0);
+    assert_eq!(summable::sum::<u64, _>(1..=100u64), 5050);
+    assert_eq!(summable::sum::<i32, _>([-4, 4, 10].iter()), 10);
+}
+
+#[test]
+fn checked_sums_catch_overflow() {
+    assert_eq!([200u8, 50, 5].checked_sum(), Some(255));
+    assert_eq!([200u8, 50, 10].checked_sum(), None);
+    // an intermediate overflow counts, even if the total would fit
+    assert_eq!([100i8, 100, -100].checked_sum(), None);
+    assert_eq!([i64::MIN, -1].checked_sum(), None);
+    assert_eq!(summable::checked_sum(0..23u8), Some(253));
+    assert_eq!(summable::checked_sum(0..200u8), None);
+    assert_eq!(Vec::<u32>::new().checked_sum(), Some(0));
+}
+
+#[test]
+fn saturating_sums_clamp_each_step() {
+    assert_eq!([200u8, 50, 10].saturating_sum(), 255);
+    assert_eq!([-100i8, -100].saturating_sum(), -128);
+    assert_eq!([100i8, 100, -100].saturating_sum(), 27);
+    assert_eq!(summable::saturating_sum::<u16, _>([u16::MAX, 1].iter()), u16::MAX);
+}
+
+#[test]
+fn wrapping_sums_wrap_around() {
+    assert_eq!([200u8, 50, 10].wrapping_sum(), 4);
+    assert_eq!([i32::MAX, 1].wrapping_sum(), i32::MIN);
+    // wrapping is exact modulo 2⁸, so overflowing on the way doesn't matter
+    assert_eq!([100i8, 100, -100].wrapping_sum(), 100);
+}
+
+#[test]
+fn compensated_sums_of_f32_keep_the_low_bits() {
+    let readings = vec![0.1f32; 1_000_000];
+    let exact = 100_000.0;
+    assert!((readings.sum() - exact).abs() > 100.0, "plain sum {} is unexpectedly accurate", readings.sum());
+    assert!((readings.kahan_sum() - exact).abs() < 0.01, "got {}", readings.kahan_sum());
+    assert!((readings.neumaier_sum() - exact).abs() < 0.01, "got {}", readings.neumaier_sum());
+    assert!((summable::neumaier_sum::<f32, _>(readings.iter()) - exact).abs() < 0.01);
+    assert!((summable::kahan_sum::<f32, _>(readings.iter()) - exact).abs() < 0.01);
+}
+
+#[test]
+fn neumaier_survives_terms_larger_than_the_total() {
+    assert_eq!([1.0f32, 1e20, 1.0, -1e20].neumaier_sum(), 2.0);
+    assert_eq!([1.0f64, 1e100, 1.0, -1e100].neumaier_sum(), 2.0);
+    // where Kahan's compensation is itself lost
+    assert_eq!([1.0f64, 1e100, 1.0, -1e100].kahan_sum(), 0.0);
+    assert_eq!(summable::neumaier_sum::<f64, _>(Vec::<f64>::new()), 0.0);
+}
@@ -0,0 +1,108 @@
+use rust_programming_language::color::Color;
+use rust_programming_language::geometry::{Line, Point};
+use rust_programming_language::shapes::{BoundingBox, Circle, Rectangle};
+use rust_programming_language::svg::{Drawing, Options, Style};
+
+fn options(width: u32, height: u32) -> Options {
+    Options{width, height, ..Options::default()}
+}
+
+fn line(from: (f64, f64), to: (f64, f64)) -> Line<f64> {
+    Line::new(Point::new(from.0, from.1, 0.0), Point::new(to.0, to.1, 0.0))
+}
+
+// the value of the first `name="..."` attribute after `start` in the document
+fn attribute<'a>(svg: &'a str, start: &str, name: &str) -> &'a str {
+    let element = &svg[svg.find(start).unwrap_or_else(|| panic!("no {} in\n{}", start, svg))..];
+    let pattern = format!(" {}=\"", name);
+    let value = &element[element.find(&pattern).unwrap() + pattern.len()..];
+    &value[..value.find('"').unwrap()]
+}
+
+fn number(svg: &str, start: &str, name: &str) -> f64 {
+    attribute(svg, start, name).parse().unwrap()
+}
+
+fn points(svg: &str) -> Vec<(f64, f64)> {
+    attribute(svg, "<polygon", "points").split(' ').map(|point| {
+        let (x, y) = point.split_at(point.find(',').unwrap());
+        (x.parse().unwrap(), y[1..].parse().unwrap())
+    }).collect()
+}
+
+#[test]
+fn content_fills_the_image_inside_the_margin() {
+    let mut drawing = Drawing::new();
+    drawing.shape(&Rectangle{lower_left: (0.0, 0.0), upper_right: (4.0, 2.0), rotation: 0.0}, Style::default());
+    let svg = drawing.to_svg(&options(400, 300));
+    assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="400" height="300" viewBox="0 0 400 300">"#), "{}", svg);
+    assert!(svg.ends_with("</svg>\n"));
+
+    // 4 by 2 is wider than the 360 by 260 inside the margin, so it fills the width and is
+    // centred vertically
+    let points = points(&svg);
+    let (xs, ys): (Vec<f64>, Vec<f64>) = points.iter().copied().unzip();
+    let extent = |v: &[f64]| (v.iter().copied().fold(f64::INFINITY, f64::min), v.iter().copied().fold(f64::NEG_INFINITY, f64::max));
+    assert_eq!(extent(&xs), (20.0, 380.0));
+    assert_eq!(extent(&ys), (60.0, 240.0));
+
+    // a circle touching both sides of a square image
+    let mut drawing = Drawing::new();
+    drawing.shape(&Circle::new((5.0, -5.0), 2.0), Style::default());
+    let svg = drawing.to_svg(&Options{margin: 10.0, ..options(100, 100)});
+    assert_eq!((number(&svg, "<circle", "cx"), number(&svg, "<circle", "cy"), number(&svg, "<circle", "r")), (50.0, 50.0, 40.0));
+}
+
+#[test]
+fn a_viewport_overrides_the_bounds() {
+    let mut drawing = Drawing::new();
+    drawing.line(&line((0.0, 0.0), (1.0, 1.0)), Style::default());
+    let viewport = BoundingBox::new((0.0, 0.0), (2.0, 2.0));
+    let svg = drawing.to_svg(&Options{viewport: Some(viewport), margin: 0.0, ..options(100, 100)});
+    assert_eq!((number(&svg, "<line", "x1"), number(&svg, "<line", "y1")), (0.0, 100.0));
+    assert_eq!((number(&svg, "<line", "x2"), number(&svg, "<line", "y2")), (50.0, 50.0));
+}
+
+#[test]
+fn y_points_up() {
+    let mut drawing = Drawing::new();
+    drawing.line(&line((0.0, 0.0), (0.0, 1.0)), Style::default());
+    let svg = drawing.to_svg(&options(200, 200));
+    assert_eq!(number(&svg, "<line", "x1"), number(&svg, "<line", "x2"));
+    // y = 1 is above y = 0, so nearer the top of the image
+    assert_eq!((number(&svg, "<line", "y1"), number(&svg, "<line", "y2")), (180.0, 20.0));
+}
+
+#[test]
+fn colours_are_written_as_hex() {
+    let cmyk = Color::CMYK{cyan: 0, magenta: 255, yellow: 255, black: 0};
+    let mut drawing = Drawing::new();
+    drawing.shape(&Circle::new((0.0, 0.0), 1.0), Style{stroke: Some(cmyk), fill: Some(Color::RGB(200, 255, 200)), stroke_width: 2.5})
+        .line(&line((0.0, 0.0), (1.0, 1.0)), Style::fill(Color::Blue))
+        .shape(&Circle::new((1.0, 1.0), 1.0), Style::stroke(Color::Green));
+    let svg = drawing.to_svg(&Options{background: Some(Color::Blue), ..Options::default()});
+
+    assert_eq!(attribute(&svg, "<rect", "fill"), Color::Blue.to_hex());
+    assert_eq!(attribute(&svg, "<circle", "fill"), "#c8ffc8");
+    assert_eq!(attribute(&svg, "<circle", "stroke"), cmyk.to_hex());
+    assert_eq!(attribute(&svg, "<circle", "stroke-width"), "2.5");
+    // lines are never filled, and a missing colour is none
+    assert_eq!((attribute(&svg, "<line", "fill"), attribute(&svg, "<line", "stroke")), ("none", "none"));
+    let last = &svg[svg.rfind("<circle").unwrap()..];
+    assert_eq!((attribute(last, "<circle", "fill"), attribute(last, "<circle", "stroke")), ("none", "#00ff00"));
+}
+
+#[test]
+fn empty_and_degenerate_drawings_still_render() {
+    let drawing = Drawing::new();
+    assert!(drawing.is_empty() && drawing.bounds().is_none());
+    let svg = drawing.to_svg(&options(0, 0));
+    assert_eq!(svg, "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"0\" height=\"0\" viewBox=\"0 0 0 0\">\n</svg>\n");
+
+    // a single point has no width or height to scale to
+    let mut drawing = Drawing::new();
+    drawing.line(&line((3.0, 4.0), (3.0, 4.0)), Style::default());
+    let svg = drawing.to_svg(&Options::default());
+    assert!(!svg.contains("NaN") && !svg.contains("inf"), "{}", svg);
+    assert_eq!((number(&svg, "<line", "x1"), number(&svg, "<line", "y1")), (400.0, 300.0));
+}
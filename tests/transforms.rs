@@ -0,0 +1,183 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_3, PI};
+
+use rust_programming_language::geometry::Point;
+use rust_programming_language::matrix::{Mat3, Mat4};
+use rust_programming_language::quaternion::Quaternion;
+use rust_programming_language::vector::Vec3;
+
+const TOLERANCE: f64 = 1e-9;
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() <= TOLERANCE * expected.abs().max(1.0),
+            "expected {}, got {}", expected, actual);
+}
+
+fn assert_vec_close(actual: Vec3, expected: Vec3) {
+    assert!(actual.distance(expected) <= TOLERANCE, "expected {:?}, got {:?}", expected, actual);
+}
+
+fn assert_point_close(actual: Point, expected: Point) {
+    assert_vec_close(Vec3::from(actual), Vec3::from(expected));
+}
+
+fn assert_mat3_close(actual: Mat3, expected: Mat3) {
+    for (a, e) in actual.rows.iter().flatten().zip(expected.rows.iter().flatten()) {
+        assert_close(*a, *e);
+    }
+}
+
+fn assert_mat4_close(actual: Mat4, expected: Mat4) {
+    for (a, e) in actual.rows.iter().flatten().zip(expected.rows.iter().flatten()) {
+        assert_close(*a, *e);
+    }
+}
+
+fn assert_rotation_close(actual: Quaternion, expected: Quaternion) {
+    // q and -q are the same rotation
+    let sign = if actual.dot(&expected) < 0.0 { -1.0 } else { 1.0 };
+    for (a, e) in [actual.w, actual.x, actual.y, actual.z].iter().zip(&[expected.w, expected.x, expected.y, expected.z]) {
+        assert_close(sign * a, *e);
+    }
+}
+
+fn axes() -> Vec<Vec3> {
+    vec![Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 1.0), Vec3::new(1.0, -2.0, 0.5)]
+}
+
+#[test]
+fn mat3_times_its_inverse_is_the_identity() {
+    let m = Mat3::new([[2.0, -1.0, 0.5], [0.0, 3.0, 1.0], [4.0, 0.0, -2.0]]);
+    let inverse = m.inverse().unwrap();
+    assert_mat3_close(m * inverse, Mat3::identity());
+    assert_mat3_close(inverse * m, Mat3::identity());
+    assert_close(m.determinant() * inverse.determinant(), 1.0);
+    assert_eq!(Mat3::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 0.0]]).inverse(), None);
+}
+
+#[test]
+fn mat4_times_its_inverse_is_the_identity() {
+    let m = Mat4::from_translation(Vec3::new(1.0, -2.0, 3.0))
+        * Mat4::from_axis_angle(Vec3::new(1.0, 1.0, 0.0), 0.7)
+        * Mat4::from_scale(Vec3::new(2.0, 0.5, 3.0));
+    let inverse = m.inverse().unwrap();
+    assert_mat4_close(m * inverse, Mat4::identity());
+    assert_mat4_close(inverse * m, Mat4::identity());
+    let projection = Mat4::perspective(FRAC_PI_3, 1.5, 0.1, 100.0);
+    assert_mat4_close(projection * projection.inverse().unwrap(), Mat4::identity());
+    assert_eq!(Mat4::from_scale(Vec3::new(1.0, 0.0, 1.0)).inverse(), None);
+}
+
+#[test]
+fn rotations_about_the_z_axis_are_anticlockwise() {
+    let quarter = Mat3::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+    assert_vec_close(quarter * Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0));
+    let q = Quaternion::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+    assert_vec_close(q * Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn matrix_and_quaternion_rotations_agree() {
+    let v = Vec3::new(0.3, -1.2, 2.0);
+    let p = Point::new(-1.0, 0.5, 4.0);
+    for axis in axes() {
+        for &angle in &[0.0, 0.4, FRAC_PI_2, 2.5, -1.0] {
+            let q = Quaternion::from_axis_angle(axis, angle);
+            let m3 = Mat3::from_axis_angle(axis, angle);
+            let m4 = Mat4::from_axis_angle(axis, angle);
+            assert_vec_close(m3 * v, q * v);
+            assert_vec_close(m4 * v, q * v);
+            assert_point_close(m4 * p, q * p);
+            assert_mat3_close(Mat3::from(q), m3);
+            assert_mat4_close(Mat4::from(q), m4);
+        }
+    }
+}
+
+#[test]
+fn composing_quaternions_composes_rotations() {
+    let (q, r) = (Quaternion::from_axis_angle(axes()[2], 0.8), Quaternion::from_axis_angle(axes()[0], -1.3));
+    let v = Vec3::new(1.0, 2.0, 3.0);
+    assert_vec_close((q * r) * v, q * (r * v));
+    assert_mat3_close(Mat3::from(q * r), Mat3::from(q) * Mat3::from(r));
+    assert_rotation_close(q * q.inverse().unwrap(), Quaternion::identity());
+    assert_rotation_close(q.conj(), q.inverse().unwrap());
+}
+
+#[test]
+fn axis_and_angle_round_trip() {
+    for axis in axes() {
+        for &angle in &[0.1, 1.0, FRAC_PI_2, 3.0] {
+            let (a, theta) = Quaternion::from_axis_angle(axis, angle).to_axis_angle();
+            assert_vec_close(a, axis.normalize().unwrap());
+            assert_close(theta, angle);
+        }
+    }
+    let (axis, angle) = Quaternion::<f64>::identity().to_axis_angle();
+    assert_eq!((axis, angle), (Vec3::new(1.0, 0.0, 0.0), 0.0));
+    assert_eq!(Quaternion::from_axis_angle(Vec3::zero(), 1.0), Quaternion::identity());
+}
+
+#[test]
+fn slerp_runs_from_one_end_to_the_other() {
+    let axis = Vec3::new(0.0, 1.0, 0.0);
+    let (q, r) = (Quaternion::from_axis_angle(axis, 0.2), Quaternion::from_axis_angle(axis, 1.4));
+    assert_rotation_close(q.slerp(&r, 0.0), q);
+    assert_rotation_close(q.slerp(&r, 1.0), r);
+    assert_rotation_close(q.slerp(&r, 0.25), Quaternion::from_axis_angle(axis, 0.5));
+    // -r is the same rotation as r, and slerp still goes the short way
+    assert_rotation_close(q.slerp(&-r, 0.5), Quaternion::from_axis_angle(axis, 0.8));
+    // nearly equal rotations fall back to a straight line
+    let s = Quaternion::from_axis_angle(axis, 0.2001);
+    assert_rotation_close(q.slerp(&s, 0.5), Quaternion::from_axis_angle(axis, 0.20005));
+}
+
+#[test]
+fn look_at_moves_the_eye_to_the_origin_looking_down_negative_z() {
+    let eye = Point::new(1.0, 2.0, 3.0);
+    let target = Point::new(1.0, 2.0, -7.0);
+    let view = Mat4::look_at(eye, target, Vec3::new(0.0, 1.0, 0.0)).unwrap();
+    assert_point_close(view * eye, Point::new(0.0, 0.0, 0.0));
+    assert_point_close(view * target, Point::new(0.0, 0.0, -10.0));
+    assert_point_close(view * Point::new(2.0, 2.0, 3.0), Point::new(1.0, 0.0, 0.0));
+    assert_point_close(view * Point::new(1.0, 3.0, 3.0), Point::new(0.0, 1.0, 0.0));
+
+    // looking along +x from the origin, +x is forward and so maps to -z
+    let view = Mat4::look_at(Point::new(0.0, 0.0, 0.0), Point::new(5.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 1.0)).unwrap();
+    assert_point_close(view * Point::new(5.0, 0.0, 0.0), Point::new(0.0, 0.0, -5.0));
+    assert_point_close(view * Point::new(0.0, 0.0, 2.0), Point::new(0.0, 2.0, 0.0));
+
+    assert_eq!(Mat4::look_at(eye, eye, Vec3::new(0.0, 1.0, 0.0)), None);
+    assert_eq!(Mat4::look_at(eye, Point::new(1.0, 5.0, 3.0), Vec3::new(0.0, 1.0, 0.0)), None);
+}
+
+#[test]
+fn perspective_maps_the_frustum_to_the_unit_cube() {
+    let (near, far) = (1.0, 10.0);
+    let projection = Mat4::perspective(FRAC_PI_2, 2.0, near, far);
+    assert_point_close(projection * Point::new(0.0, 0.0, -near), Point::new(0.0, 0.0, -1.0));
+    assert_point_close(projection * Point::new(0.0, 0.0, -far), Point::new(0.0, 0.0, 1.0));
+    // a 90° field of view reaches as far up as it is deep, and twice as far across
+    assert_point_close(projection * Point::new(0.0, 5.0, -5.0), Point::new(0.0, 1.0, projection_depth(5.0, near, far)));
+    assert_point_close(projection * Point::new(-10.0, 0.0, -5.0), Point::new(-1.0, 0.0, projection_depth(5.0, near, far)));
+}
+
+// the depth in -1..=1 a perspective projection gives to a point `distance` in front of the eye
+fn projection_depth(distance: f64, near: f64, far: f64) -> f64 {
+    (far + near) / (far - near) - 2.0 * far * near / ((far - near) * distance)
+}
+
+#[test]
+fn orthographic_maps_the_box_to_the_unit_cube() {
+    let projection = Mat4::orthographic(-2.0, 6.0, 0.0, 4.0, 1.0, 11.0);
+    assert_point_close(projection * Point::new(-2.0, 0.0, -1.0), Point::new(-1.0, -1.0, -1.0));
+    assert_point_close(projection * Point::new(6.0, 4.0, -11.0), Point::new(1.0, 1.0, 1.0));
+    assert_point_close(projection * Point::new(2.0, 2.0, -6.0), Point::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn half_turns_reverse_directions() {
+    let half = Mat3::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), PI);
+    assert_vec_close(half * Vec3::new(1.0, 0.0, 0.0), Vec3::new(-1.0, 0.0, 0.0));
+    assert_close(half.determinant(), 1.0);
+    assert_mat3_close(half * half.transpose(), Mat3::identity());
+}